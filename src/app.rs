@@ -1,3 +1,4 @@
+pub mod alerts;
 pub mod data_farmer;
 pub mod filter;
 pub mod frozen_state;
@@ -11,25 +12,26 @@ use std::{
     time::Instant,
 };
 
-use concat_string::concat_string;
 use data_farmer::*;
 use filter::*;
 use frozen_state::FrozenState;
 use hashbrown::HashMap;
 use layout_manager::*;
 pub use states::*;
-use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
 
 use crate::{
     canvas::components::time_chart::LegendPosition,
     constants,
-    data_collection::temperature,
+    data_collection::{processes::details::ProcessDetails, temperature},
     data_conversion::ConvertedData,
     utils::{
         data_units::DataUnit,
         error::{BottomError, Result},
     },
-    widgets::{ProcWidgetColumn, ProcWidgetMode},
+    widgets::{
+        CgroupWidgetColumn, CpuGraphMode, DiskWidgetColumn, ProcGrouping, ProcWidgetColumn,
+        ProcWidgetMode,
+    },
     Pid,
 };
 
@@ -51,6 +53,7 @@ pub struct AppConfigFields {
     pub show_average_cpu: bool, // TODO: Unify this in CPU options
     pub use_current_cpu_total: bool,
     pub unnormalized_cpu: bool,
+    pub show_threads: bool,
     pub use_basic_mode: bool,
     pub default_time_value: u64,
     pub time_interval: u64,
@@ -61,6 +64,9 @@ pub struct AppConfigFields {
     pub disable_click: bool,
     pub enable_gpu: bool,
     pub enable_cache_memory: bool,
+    pub enable_paging_graph: bool,
+    pub enable_memory_extra_graphs: bool,
+    pub enable_hwmon_sensors: bool,
     pub show_table_scroll_position: bool,
     pub is_advanced_kill: bool,
     pub memory_legend_position: Option<LegendPosition>,
@@ -77,8 +83,13 @@ pub struct AppConfigFields {
 pub struct DataFilters {
     pub disk_filter: Option<Filter>,
     pub mount_filter: Option<Filter>,
+    pub fs_type_filter: Option<Filter>,
+    pub mount_options_filter: Option<Filter>,
+    pub device_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
+    pub sensor_filter: Option<Filter>,
     pub net_filter: Option<Filter>,
+    pub cgroup_filter: Option<Filter>,
 }
 
 cfg_if::cfg_if! {
@@ -113,6 +124,7 @@ pub struct App {
     pub data_collection: DataCollection,
     pub delete_dialog_state: AppDeleteDialogState,
     pub help_dialog_state: AppHelpDialogState,
+    pub process_details_state: AppProcessDetailsState,
     pub is_expanded: bool,
     pub is_force_redraw: bool,
     pub is_determining_widget_boundary: bool,
@@ -125,6 +137,11 @@ pub struct App {
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
     pub filters: DataFilters,
+    #[cfg(feature = "exporter")]
+    pub exporter: Option<crate::exporter::Exporter>,
+    pub alerts: alerts::Alerts,
+    pub recorder: Option<crate::session::Recorder>,
+    pub replay_state: Option<crate::session::replay::ReplayState>,
 }
 
 impl App {
@@ -144,6 +161,7 @@ impl App {
             data_collection: DataCollection::default(),
             delete_dialog_state: AppDeleteDialogState::default(),
             help_dialog_state: AppHelpDialogState::default(),
+            process_details_state: AppProcessDetailsState::default(),
             is_expanded,
             is_force_redraw: false,
             is_determining_widget_boundary: false,
@@ -156,6 +174,11 @@ impl App {
             current_widget,
             used_widgets,
             filters,
+            #[cfg(feature = "exporter")]
+            exporter: None,
+            alerts: alerts::Alerts::default(),
+            recorder: None,
+            replay_state: None,
         }
     }
 
@@ -166,6 +189,7 @@ impl App {
         // Reset dialog state
         self.help_dialog_state.is_showing_help = false;
         self.delete_dialog_state.is_showing_dd = false;
+        self.close_process_details();
 
         // Close all searches and reset it
         self.states
@@ -175,9 +199,42 @@ impl App {
             .for_each(|state| {
                 state.proc_search.search_state.reset();
             });
+        self.states
+            .disk_state
+            .widget_states
+            .values_mut()
+            .for_each(|state| {
+                state.search.search_state.reset();
+                state.update_query();
+            });
+        self.states
+            .temp_state
+            .widget_states
+            .values_mut()
+            .for_each(|state| {
+                state.search.search_state.reset();
+                state.update_query();
+            });
+        self.states
+            .cpu_state
+            .widget_states
+            .values_mut()
+            .for_each(|state| {
+                state.search.search_state.reset();
+                state.update_query();
+            });
+        self.states
+            .cgroup_state
+            .widget_states
+            .values_mut()
+            .for_each(|state| {
+                state.search.search_state.reset();
+                state.update_query();
+            });
 
         // Clear current delete list
         self.to_delete_process_list = None;
+        self.delete_dialog_state.thread = None;
         self.dd_err = None;
 
         // Unfreeze.
@@ -187,6 +244,8 @@ impl App {
         self.reset_cpu_zoom();
         self.reset_mem_zoom();
         self.reset_net_zoom();
+        self.reset_pressure_zoom();
+        self.reset_disk_io_zoom();
 
         // Reset data
         self.data_collection.reset();
@@ -200,6 +259,7 @@ impl App {
         self.delete_dialog_state.is_showing_dd = false;
         self.delete_dialog_state.selected_signal = KillSignal::default();
         self.delete_dialog_state.scroll_pos = 0;
+        self.delete_dialog_state.thread = None;
         self.to_delete_process_list = None;
         self.dd_err = None;
     }
@@ -210,10 +270,17 @@ impl App {
             if self.help_dialog_state.is_showing_help {
                 self.help_dialog_state.is_showing_help = false;
                 self.help_dialog_state.scroll_state.current_scroll_index = 0;
+            } else if self.process_details_state.is_showing_details {
+                self.close_process_details();
             } else {
                 self.close_dd();
             }
 
+            self.is_force_redraw = true;
+        } else if self.edit_table_search(|search| {
+            search.search_state.is_enabled = false;
+            false
+        }) {
             self.is_force_redraw = true;
         } else {
             match self.current_widget.widget_type {
@@ -276,13 +343,141 @@ impl App {
         )
     }
 
+    /// Returns the search of the disk, temperature, CPU legend, or cgroup widget with the given
+    /// type and ID, if it is one.
+    fn table_search_mut(
+        &mut self, widget_type: &BottomWidgetType, widget_id: u64,
+    ) -> Option<&mut SearchState> {
+        match widget_type {
+            BottomWidgetType::Disk => self
+                .states
+                .disk_state
+                .widget_states
+                .get_mut(&widget_id)
+                .map(|state| &mut state.search),
+            BottomWidgetType::Temp => self
+                .states
+                .temp_state
+                .widget_states
+                .get_mut(&widget_id)
+                .map(|state| &mut state.search),
+            BottomWidgetType::CpuLegend => self
+                .states
+                .cpu_state
+                .widget_states
+                .get_mut(&(widget_id - 1))
+                .map(|state| &mut state.search),
+            BottomWidgetType::Cgroup => self
+                .states
+                .cgroup_state
+                .widget_states
+                .get_mut(&widget_id)
+                .map(|state| &mut state.search),
+            _ => None,
+        }
+    }
+
+    /// Returns the search of the current widget if it is a disk, temperature, CPU legend, or
+    /// cgroup widget.
+    fn table_search(&self) -> Option<&SearchState> {
+        let widget_id = self.current_widget.widget_id;
+        match self.current_widget.widget_type {
+            BottomWidgetType::Disk => self
+                .states
+                .disk_state
+                .widget_states
+                .get(&widget_id)
+                .map(|state| &state.search),
+            BottomWidgetType::Temp => self
+                .states
+                .temp_state
+                .widget_states
+                .get(&widget_id)
+                .map(|state| &state.search),
+            BottomWidgetType::CpuLegend => self
+                .states
+                .cpu_state
+                .widget_states
+                .get(&(widget_id - 1))
+                .map(|state| &state.search),
+            BottomWidgetType::Cgroup => self
+                .states
+                .cgroup_state
+                .widget_states
+                .get(&widget_id)
+                .map(|state| &state.search),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the current widget has a table search open. Keys are typed into the search
+    /// while this is the case.
+    pub fn is_in_table_search(&self) -> bool {
+        self.table_search()
+            .is_some_and(|search| search.search_state.is_enabled)
+    }
+
+    /// Applies `edit` to the current widget's search if [`App::is_in_table_search`], returning
+    /// whether it did. If `edit` returns true, the search has changed and the table is filtered
+    /// again.
+    fn edit_table_search(&mut self, edit: impl FnOnce(&mut SearchState) -> bool) -> bool {
+        if !self.is_in_table_search() {
+            return false;
+        }
+
+        let widget_id = self.current_widget.widget_id;
+        let widget_type = self.current_widget.widget_type.clone();
+        if let Some(search) = self.table_search_mut(&widget_type, widget_id) {
+            if edit(search) {
+                match widget_type {
+                    BottomWidgetType::Disk => {
+                        if let Some(state) =
+                            self.states.disk_state.widget_states.get_mut(&widget_id)
+                        {
+                            state.update_query();
+                        }
+                    }
+                    BottomWidgetType::Temp => {
+                        if let Some(state) =
+                            self.states.temp_state.widget_states.get_mut(&widget_id)
+                        {
+                            state.update_query();
+                        }
+                    }
+                    BottomWidgetType::CpuLegend => {
+                        if let Some(state) = self
+                            .states
+                            .cpu_state
+                            .widget_states
+                            .get_mut(&(widget_id - 1))
+                        {
+                            state.update_query();
+                        }
+                    }
+                    BottomWidgetType::Cgroup => {
+                        if let Some(state) =
+                            self.states.cgroup_state.widget_states.get_mut(&widget_id)
+                        {
+                            state.update_query();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        true
+    }
+
     fn reset_multi_tap_keys(&mut self) {
         self.awaiting_second_char = false;
         self.second_char = None;
     }
 
     fn is_in_dialog(&self) -> bool {
-        self.help_dialog_state.is_showing_help || self.delete_dialog_state.is_showing_dd
+        self.help_dialog_state.is_showing_help
+            || self.delete_dialog_state.is_showing_dd
+            || self.process_details_state.is_showing_details
     }
 
     fn ignore_normal_keybinds(&self) -> bool {
@@ -305,6 +500,20 @@ impl App {
         }
     }
 
+    pub fn on_back_tab(&mut self) {
+        if !self.ignore_normal_keybinds() {
+            if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                if let Some(proc_widget_state) = self
+                    .states
+                    .proc_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    proc_widget_state.toggle_grouping(ProcGrouping::Cgroup);
+                }
+            }
+        }
+    }
+
     pub fn on_slash(&mut self) {
         if !self.ignore_normal_keybinds() {
             match &self.current_widget.widget_type {
@@ -322,6 +531,18 @@ impl App {
                         self.is_force_redraw = true;
                     }
                 }
+                BottomWidgetType::Disk
+                | BottomWidgetType::Temp
+                | BottomWidgetType::CpuLegend
+                | BottomWidgetType::Cgroup => {
+                    let widget_type = self.current_widget.widget_type.clone();
+                    if let Some(search) =
+                        self.table_search_mut(&widget_type, self.current_widget.widget_id)
+                    {
+                        search.search_state.is_enabled = true;
+                        self.is_force_redraw = true;
+                    }
+                }
                 _ => {}
             }
         }
@@ -389,7 +610,41 @@ impl App {
         }
     }
 
+    pub fn toggle_cpu_graph_mode(&mut self, mode: CpuGraphMode) {
+        let widget_id = match &self.current_widget.widget_type {
+            BottomWidgetType::Cpu => self.current_widget.widget_id,
+            BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+            _ => return,
+        };
+
+        if let Some(cpu_widget_state) = self.states.cpu_state.widget_states.get_mut(&widget_id) {
+            cpu_widget_state.toggle_graph_mode(mode);
+            self.is_force_redraw = true;
+        }
+    }
+
+    /// Switches the current CPU widget to the next way of grouping CPUs by topology.
+    pub fn cycle_cpu_grouping(&mut self) {
+        let widget_id = match &self.current_widget.widget_type {
+            BottomWidgetType::Cpu | BottomWidgetType::CpuHeatmap => self.current_widget.widget_id,
+            BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+            _ => return,
+        };
+
+        if let Some(cpu_widget_state) = self.states.cpu_state.widget_states.get_mut(&widget_id) {
+            cpu_widget_state.cycle_grouping();
+            self.is_force_redraw = true;
+        }
+    }
+
     pub fn toggle_ignore_case(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_toggle_ignore_case();
+            true
+        }) {
+            return;
+        }
+
         let is_in_search_widget = self.is_in_search_widget();
         if let Some(proc_widget_state) = self
             .states
@@ -405,6 +660,13 @@ impl App {
     }
 
     pub fn toggle_search_whole_word(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_toggle_whole_word();
+            true
+        }) {
+            return;
+        }
+
         let is_in_search_widget = self.is_in_search_widget();
         if let Some(proc_widget_state) = self
             .states
@@ -420,6 +682,13 @@ impl App {
     }
 
     pub fn toggle_search_regex(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_toggle_regex();
+            true
+        }) {
+            return;
+        }
+
         let is_in_search_widget = self.is_in_search_widget();
         if let Some(proc_widget_state) = self
             .states
@@ -434,6 +703,21 @@ impl App {
         }
     }
 
+    /// Toggles showing the threads of each process in the process widget, returning whether it
+    /// was toggled. This does nothing while replaying a session, as the threads were either
+    /// recorded or not.
+    pub fn toggle_threads(&mut self) -> bool {
+        if self.ignore_normal_keybinds()
+            || self.replay_state.is_some()
+            || !matches!(self.current_widget.widget_type, BottomWidgetType::Proc)
+        {
+            return false;
+        }
+
+        self.app_config_fields.show_threads = !self.app_config_fields.show_threads;
+        true
+    }
+
     pub fn toggle_tree_mode(&mut self) {
         if let Some(proc_widget_state) = self
             .states
@@ -452,7 +736,7 @@ impl App {
                     };
                     proc_widget_state.force_rerender_and_update();
                 }
-                ProcWidgetMode::Grouped => {}
+                ProcWidgetMode::Grouped(_) => {}
             }
         }
     }
@@ -495,94 +779,156 @@ impl App {
                     self.move_widget_selection(&WidgetDirection::Right);
                     self.is_force_redraw = true;
                 }
+            } else if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                self.show_process_details();
+            } else if let BottomWidgetType::NetTable = self.current_widget.widget_type {
+                self.toggle_graphed_interface();
+            } else if let BottomWidgetType::Disk = self.current_widget.widget_type {
+                self.toggle_graphed_disk();
+            } else if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+                if let Some(cpu_widget_state) = self
+                    .states
+                    .cpu_state
+                    .widget_states
+                    .get_mut(&(self.current_widget.widget_id - 1))
+                {
+                    cpu_widget_state.toggle_selected_group();
+                    self.is_force_redraw = true;
+                }
+            }
+        }
+    }
+
+    /// Opens a dialog with the details of the currently selected process. This is not available
+    /// while replaying, as the details are read from the live system.
+    fn show_process_details(&mut self) {
+        if self.replay_state.is_some() {
+            return;
+        }
+
+        if let Some(pws) = self
+            .states
+            .proc_state
+            .get_widget_state(self.current_widget.widget_id)
+        {
+            if let Some(current) = pws.table.current_item() {
+                let pid = current.pid;
+
+                self.process_details_state = AppProcessDetailsState {
+                    is_showing_details: true,
+                    pid,
+                    details: Some(ProcessDetails::read(pid)),
+                    ..Default::default()
+                };
+                self.is_force_redraw = true;
+            }
+        }
+    }
+
+    /// Re-reads the details of the process being shown. These are only read when asked for, as
+    /// reading things like every open file and memory map is too slow to do on every update.
+    fn refresh_process_details(&mut self) {
+        if self.process_details_state.is_showing_details {
+            self.process_details_state.details =
+                Some(ProcessDetails::read(self.process_details_state.pid));
+            self.is_force_redraw = true;
+        }
+    }
+
+    fn close_process_details(&mut self) {
+        self.process_details_state = AppProcessDetailsState::default();
+    }
+
+    /// Toggles whether the network graph only shows the interface currently selected in the
+    /// network table, or the total across all interfaces.
+    fn toggle_graphed_interface(&mut self) {
+        if let Some(net_table) = self
+            .states
+            .net_table_state
+            .get_widget_state(self.current_widget.widget_id)
+        {
+            if let Some(interface) = net_table.current_interface() {
+                let net_state = &mut self.states.net_state;
+                if net_state.selected_interface.as_deref() == Some(interface) {
+                    net_state.selected_interface = None;
+                } else {
+                    net_state.selected_interface = Some(interface.to_string());
+                }
+
+                if let Some(net_widget_id) = net_state.widget_states.keys().next() {
+                    net_state.force_update = Some(*net_widget_id);
+                }
+                self.is_force_redraw = true;
+            }
+        }
+    }
+
+    /// Toggles whether the disk I/O graph only shows the disk currently selected in the disk
+    /// table, or all disks.
+    fn toggle_graphed_disk(&mut self) {
+        if let Some(disk_table) = self
+            .states
+            .disk_state
+            .get_widget_state(self.current_widget.widget_id)
+        {
+            if let Some(disk) = disk_table.current_disk() {
+                let disk_io_state = &mut self.states.disk_io_state;
+                if disk_io_state.selected_disk.as_deref() == Some(disk) {
+                    disk_io_state.selected_disk = None;
+                } else {
+                    disk_io_state.selected_disk = Some(disk.to_string());
+                }
+
+                if let Some(disk_io_widget_id) = disk_io_state.widget_states.keys().next() {
+                    disk_io_state.force_update = Some(*disk_io_widget_id);
+                }
+                self.is_force_redraw = true;
             }
         }
     }
 
     pub fn on_delete(&mut self) {
+        if self.edit_table_search(|search| search.search_state.delete_at_cursor()) {
+            return;
+        }
+
         if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            let is_in_search_widget = self.is_in_search_widget();
             if let Some(proc_widget_state) = self
                 .states
                 .proc_state
                 .widget_states
                 .get_mut(&(self.current_widget.widget_id - 1))
             {
-                if is_in_search_widget {
-                    if proc_widget_state.proc_search.search_state.is_enabled
-                        && proc_widget_state.cursor_char_index()
-                            < proc_widget_state
-                                .proc_search
-                                .search_state
-                                .current_search_query
-                                .len()
-                    {
-                        let current_cursor = proc_widget_state.cursor_char_index();
-                        proc_widget_state.search_walk_forward();
-
-                        let _ = proc_widget_state
-                            .proc_search
-                            .search_state
-                            .current_search_query
-                            .drain(current_cursor..proc_widget_state.cursor_char_index());
-
-                        proc_widget_state.proc_search.search_state.grapheme_cursor =
-                            GraphemeCursor::new(
-                                current_cursor,
-                                proc_widget_state
-                                    .proc_search
-                                    .search_state
-                                    .current_search_query
-                                    .len(),
-                                true,
-                            );
-
-                        proc_widget_state.update_query();
-                    }
-                } else {
-                    self.start_killing_process()
+                if proc_widget_state.proc_search.search_state.is_enabled
+                    && proc_widget_state
+                        .proc_search
+                        .search_state
+                        .delete_at_cursor()
+                {
+                    proc_widget_state.update_query();
                 }
             }
         }
     }
 
     pub fn on_backspace(&mut self) {
+        if self.edit_table_search(|search| search.search_state.delete_before_cursor()) {
+            return;
+        }
+
         if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            let is_in_search_widget = self.is_in_search_widget();
             if let Some(proc_widget_state) = self
                 .states
                 .proc_state
                 .widget_states
                 .get_mut(&(self.current_widget.widget_id - 1))
             {
-                if is_in_search_widget
-                    && proc_widget_state.proc_search.search_state.is_enabled
-                    && proc_widget_state.cursor_char_index() > 0
-                {
-                    let current_cursor = proc_widget_state.cursor_char_index();
-                    proc_widget_state.search_walk_back();
-
-                    // Remove the indices in between.
-                    let _ = proc_widget_state
+                if proc_widget_state.proc_search.search_state.is_enabled
+                    && proc_widget_state
                         .proc_search
                         .search_state
-                        .current_search_query
-                        .drain(proc_widget_state.cursor_char_index()..current_cursor);
-
-                    proc_widget_state.proc_search.search_state.grapheme_cursor =
-                        GraphemeCursor::new(
-                            proc_widget_state.cursor_char_index(),
-                            proc_widget_state
-                                .proc_search
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            true,
-                        );
-
-                    proc_widget_state.proc_search.search_state.cursor_direction =
-                        CursorDirection::Left;
-
+                        .delete_before_cursor()
+                {
                     proc_widget_state.update_query();
                 }
             }
@@ -636,6 +982,8 @@ impl App {
             self.decrement_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll_state.scroll_up(1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_right_key();
@@ -657,6 +1005,8 @@ impl App {
             self.increment_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll_state.scroll_down(1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_left_key();
@@ -674,23 +1024,25 @@ impl App {
     }
 
     pub fn on_left_key(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_state.move_cursor_left();
+            false
+        }) {
+            return;
+        }
+
         if !self.is_in_dialog() {
             match self.current_widget.widget_type {
                 BottomWidgetType::ProcSearch => {
-                    let is_in_search_widget = self.is_in_search_widget();
                     if let Some(proc_widget_state) = self
                         .states
                         .proc_state
                         .get_mut_widget_state(self.current_widget.widget_id - 1)
                     {
-                        if is_in_search_widget {
-                            let prev_cursor = proc_widget_state.cursor_char_index();
-                            proc_widget_state.search_walk_back();
-                            if proc_widget_state.cursor_char_index() < prev_cursor {
-                                proc_widget_state.proc_search.search_state.cursor_direction =
-                                    CursorDirection::Left;
-                            }
-                        }
+                        proc_widget_state
+                            .proc_search
+                            .search_state
+                            .move_cursor_left();
                     }
                 }
                 BottomWidgetType::Battery => {
@@ -735,23 +1087,25 @@ impl App {
     }
 
     pub fn on_right_key(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_state.move_cursor_right();
+            false
+        }) {
+            return;
+        }
+
         if !self.is_in_dialog() {
             match self.current_widget.widget_type {
                 BottomWidgetType::ProcSearch => {
-                    let is_in_search_widget = self.is_in_search_widget();
                     if let Some(proc_widget_state) = self
                         .states
                         .proc_state
                         .get_mut_widget_state(self.current_widget.widget_id - 1)
                     {
-                        if is_in_search_widget {
-                            let prev_cursor = proc_widget_state.cursor_char_index();
-                            proc_widget_state.search_walk_forward();
-                            if proc_widget_state.cursor_char_index() > prev_cursor {
-                                proc_widget_state.proc_search.search_state.cursor_direction =
-                                    CursorDirection::Right;
-                            }
-                        }
+                        proc_widget_state
+                            .proc_search
+                            .search_state
+                            .move_cursor_right();
                     }
                 }
                 BottomWidgetType::Battery => {
@@ -815,6 +1169,9 @@ impl App {
             let current = &mut self.help_dialog_state.scroll_state.current_scroll_index;
             let amount = self.help_dialog_state.height;
             *current = current.saturating_sub(amount);
+        } else if self.process_details_state.is_showing_details {
+            let amount = self.process_details_state.height;
+            self.process_details_state.scroll_state.scroll_up(amount);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -843,6 +1200,9 @@ impl App {
             let amount = self.help_dialog_state.height;
 
             self.help_scroll_to_or_max(current + amount);
+        } else if self.process_details_state.is_showing_details {
+            let amount = self.process_details_state.height;
+            self.process_details_state.scroll_state.scroll_down(amount);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -862,6 +1222,9 @@ impl App {
             let amount = self.help_dialog_state.height / 2;
 
             *current = current.saturating_sub(amount);
+        } else if self.process_details_state.is_showing_details {
+            let amount = self.process_details_state.height / 2;
+            self.process_details_state.scroll_state.scroll_up(amount);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -881,6 +1244,9 @@ impl App {
             let amount = self.help_dialog_state.height / 2;
 
             self.help_scroll_to_or_max(current + amount);
+        } else if self.process_details_state.is_showing_details {
+            let amount = self.process_details_state.height / 2;
+            self.process_details_state.scroll_state.scroll_down(amount);
         } else if self.current_widget.widget_type.is_widget_table() {
             if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
                 &self.current_widget.top_left_corner,
@@ -895,63 +1261,59 @@ impl App {
     }
 
     pub fn skip_cursor_beginning(&mut self) {
-        if !self.ignore_normal_keybinds() {
+        if !self.ignore_normal_keybinds()
+            && !self.edit_table_search(|search| {
+                search.search_state.move_cursor_to_start();
+                false
+            })
+        {
             if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
                 if let Some(proc_widget_state) = self
                     .states
                     .proc_state
                     .widget_states
                     .get_mut(&(self.current_widget.widget_id - 1))
                 {
-                    if is_in_search_widget {
-                        proc_widget_state.proc_search.search_state.grapheme_cursor =
-                            GraphemeCursor::new(
-                                0,
-                                proc_widget_state
-                                    .proc_search
-                                    .search_state
-                                    .current_search_query
-                                    .len(),
-                                true,
-                            );
-
-                        proc_widget_state.proc_search.search_state.cursor_direction =
-                            CursorDirection::Left;
-                    }
+                    proc_widget_state
+                        .proc_search
+                        .search_state
+                        .move_cursor_to_start();
                 }
             }
         }
     }
 
     pub fn skip_cursor_end(&mut self) {
-        if !self.ignore_normal_keybinds() {
+        if !self.ignore_normal_keybinds()
+            && !self.edit_table_search(|search| {
+                search.search_state.move_cursor_to_end();
+                false
+            })
+        {
             if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
                 if let Some(proc_widget_state) = self
                     .states
                     .proc_state
                     .widget_states
                     .get_mut(&(self.current_widget.widget_id - 1))
                 {
-                    if is_in_search_widget {
-                        let query_len = proc_widget_state
-                            .proc_search
-                            .search_state
-                            .current_search_query
-                            .len();
-
-                        proc_widget_state.proc_search.search_state.grapheme_cursor =
-                            GraphemeCursor::new(query_len, query_len, true);
-                        proc_widget_state.proc_search.search_state.cursor_direction =
-                            CursorDirection::Right;
-                    }
+                    proc_widget_state
+                        .proc_search
+                        .search_state
+                        .move_cursor_to_end();
                 }
             }
         }
     }
 
     pub fn clear_search(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_state.reset();
+            true
+        }) {
+            return;
+        }
+
         if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             if let Some(proc_widget_state) = self
                 .states
@@ -965,6 +1327,13 @@ impl App {
     }
 
     pub fn clear_previous_word(&mut self) {
+        if self.edit_table_search(|search| {
+            search.search_state.delete_word_before_cursor();
+            true
+        }) {
+            return;
+        }
+
         if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             if let Some(proc_widget_state) = self
                 .states
@@ -972,51 +1341,10 @@ impl App {
                 .widget_states
                 .get_mut(&(self.current_widget.widget_id - 1))
             {
-                // Traverse backwards from the current cursor location until you hit non-whitespace characters,
-                // then continue to traverse (and delete) backwards until you hit a whitespace character.  Halt.
-
-                // So... first, let's get our current cursor position in terms of char indices.
-                let end_index = proc_widget_state.cursor_char_index();
-
-                // Then, let's crawl backwards until we hit our location, and store the "head"...
-                let query = proc_widget_state.current_search_query();
-                let mut start_index = 0;
-                let mut saw_non_whitespace = false;
-
-                for (itx, c) in query
-                    .chars()
-                    .rev()
-                    .enumerate()
-                    .skip(query.len() - end_index)
-                {
-                    if c.is_whitespace() {
-                        if saw_non_whitespace {
-                            start_index = query.len() - itx;
-                            break;
-                        }
-                    } else {
-                        saw_non_whitespace = true;
-                    }
-                }
-
-                let _ = proc_widget_state
+                proc_widget_state
                     .proc_search
                     .search_state
-                    .current_search_query
-                    .drain(start_index..end_index);
-
-                proc_widget_state.proc_search.search_state.grapheme_cursor = GraphemeCursor::new(
-                    start_index,
-                    proc_widget_state
-                        .proc_search
-                        .search_state
-                        .current_search_query
-                        .len(),
-                    true,
-                );
-
-                proc_widget_state.proc_search.search_state.cursor_direction = CursorDirection::Left;
-
+                    .delete_word_before_cursor();
                 proc_widget_state.update_query();
             }
         }
@@ -1032,6 +1360,32 @@ impl App {
             .get(&self.current_widget.widget_id)
         {
             if let Some(current) = pws.table.current_item() {
+                // Signals are sent to whole processes, so a thread's process is killed instead.
+                if current.is_thread {
+                    let Some(pid) = current.ppid else {
+                        return;
+                    };
+                    let name = self
+                        .data_collection
+                        .process_data
+                        .process_harvest
+                        .get(&pid)
+                        .map(|process| {
+                            if pws.is_using_command() {
+                                process.command.clone()
+                            } else {
+                                process.name.clone()
+                            }
+                        })
+                        .unwrap_or_default();
+
+                    self.to_delete_process_list = Some((name, vec![pid]));
+                    self.delete_dialog_state.thread = Some(current.pid);
+                    self.delete_dialog_state.is_showing_dd = true;
+                    self.is_determining_widget_boundary = true;
+                    return;
+                }
+
                 let id = current.id.to_string();
                 if let Some(pids) = pws
                     .id_pid_map
@@ -1068,36 +1422,26 @@ impl App {
             }
             self.last_key_press = current_key_press_inst;
 
+            if self.edit_table_search(|search| {
+                search.search_state.insert_char(caught_char);
+                true
+            }) {
+                return;
+            }
+
             if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
                 if let Some(proc_widget_state) = self
                     .states
                     .proc_state
                     .widget_states
                     .get_mut(&(self.current_widget.widget_id - 1))
                 {
-                    if is_in_search_widget && proc_widget_state.is_search_enabled() {
+                    if proc_widget_state.is_search_enabled() {
                         proc_widget_state
                             .proc_search
                             .search_state
-                            .current_search_query
-                            .insert(proc_widget_state.cursor_char_index(), caught_char);
-
-                        proc_widget_state.proc_search.search_state.grapheme_cursor =
-                            GraphemeCursor::new(
-                                proc_widget_state.cursor_char_index(),
-                                proc_widget_state
-                                    .proc_search
-                                    .search_state
-                                    .current_search_query
-                                    .len(),
-                                true,
-                            );
-                        proc_widget_state.search_walk_forward();
-
+                            .insert_char(caught_char);
                         proc_widget_state.update_query();
-                        proc_widget_state.proc_search.search_state.cursor_direction =
-                            CursorDirection::Right;
 
                         return;
                     }
@@ -1120,6 +1464,12 @@ impl App {
                 'j' | 'k' | 'g' | 'G' => self.handle_char(caught_char),
                 _ => {}
             }
+        } else if self.process_details_state.is_showing_details {
+            match caught_char {
+                'j' | 'k' | 'g' | 'G' => self.handle_char(caught_char),
+                'r' => self.refresh_process_details(),
+                _ => {}
+            }
         } else if self.delete_dialog_state.is_showing_dd {
             match caught_char {
                 'h' => self.on_left_key(),
@@ -1180,7 +1530,7 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(0);
+                    disk.select_column(DiskWidgetColumn::Disk);
                 }
             }
             'g' => {
@@ -1214,6 +1564,12 @@ impl App {
                     {
                         proc_widget_state.select_column(ProcWidgetColumn::Cpu);
                     }
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::Cpu);
                 }
             }
             'm' => {
@@ -1230,7 +1586,13 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(1);
+                    disk.select_column(DiskWidgetColumn::Mount);
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::Mem);
                 }
             }
             'p' => {
@@ -1247,7 +1609,13 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(5);
+                    disk.select_column(DiskWidgetColumn::UsedPercent);
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::MemPercent);
                 }
             }
             'P' => {
@@ -1275,7 +1643,19 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(3);
+                    disk.select_column(DiskWidgetColumn::Free);
+                } else if let Some(net_table) = self
+                    .states
+                    .net_table_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    net_table.set_index(0);
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::Cgroup);
                 }
             }
             #[cfg(feature = "gpu")]
@@ -1325,7 +1705,13 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(4);
+                    disk.select_column(DiskWidgetColumn::Total);
+                } else if let Some(net_table) = self
+                    .states
+                    .net_table_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    net_table.set_index(2);
                 }
             }
             '+' => self.on_plus(),
@@ -1351,7 +1737,7 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(2);
+                    disk.select_column(DiskWidgetColumn::Used);
                 }
             }
             'r' => {
@@ -1360,7 +1746,19 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(6);
+                    disk.select_column(DiskWidgetColumn::IoRead);
+                } else if let Some(net_table) = self
+                    .states
+                    .net_table_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    net_table.set_index(1);
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::IoRead);
                 }
             }
             'w' => {
@@ -1369,11 +1767,20 @@ impl App {
                     .disk_state
                     .get_mut_widget_state(self.current_widget.widget_id)
                 {
-                    disk.set_index(7);
+                    disk.select_column(DiskWidgetColumn::IoWrite);
+                } else if let Some(cgroup) = self
+                    .states
+                    .cgroup_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    cgroup.select_column(CgroupWidgetColumn::IoWrite);
                 }
             }
             'I' => self.invert_sort(),
             '%' => self.toggle_percentages(),
+            'b' => self.toggle_cpu_graph_mode(CpuGraphMode::Breakdown),
+            'F' => self.toggle_cpu_graph_mode(CpuGraphMode::Frequency),
+            'o' => self.cycle_cpu_grouping(),
             _ => {}
         }
 
@@ -1941,6 +2348,33 @@ impl App {
                         disk_widget_state.table.to_first();
                     }
                 }
+                BottomWidgetType::NetTable => {
+                    if let Some(net_table_widget_state) = self
+                        .states
+                        .net_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        net_table_widget_state.table.to_first();
+                    }
+                }
+                BottomWidgetType::MemTable => {
+                    if let Some(mem_table_widget_state) = self
+                        .states
+                        .mem_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        mem_table_widget_state.table.to_first();
+                    }
+                }
+                BottomWidgetType::Cgroup => {
+                    if let Some(cgroup_widget_state) = self
+                        .states
+                        .cgroup_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        cgroup_widget_state.table.to_first();
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .states
@@ -1956,6 +2390,8 @@ impl App {
             self.reset_multi_tap_keys();
         } else if self.help_dialog_state.is_showing_help {
             self.help_dialog_state.scroll_state.current_scroll_index = 0;
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll_state.current_scroll_index = 0;
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Cancel;
         }
@@ -2002,6 +2438,39 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::NetTable => {
+                    if let Some(net_table_widget_state) = self
+                        .states
+                        .net_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.net_interface_data.is_empty() {
+                            net_table_widget_state.table.to_last();
+                        }
+                    }
+                }
+                BottomWidgetType::MemTable => {
+                    if let Some(mem_table_widget_state) = self
+                        .states
+                        .mem_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.mem_details_data.is_empty() {
+                            mem_table_widget_state.table.to_last();
+                        }
+                    }
+                }
+                BottomWidgetType::Cgroup => {
+                    if let Some(cgroup_widget_state) = self
+                        .states
+                        .cgroup_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.converted_data.cgroup_data.is_empty() {
+                            cgroup_widget_state.table.to_last();
+                        }
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .states
@@ -2017,6 +2486,9 @@ impl App {
         } else if self.help_dialog_state.is_showing_help {
             self.help_dialog_state.scroll_state.current_scroll_index =
                 self.help_dialog_state.scroll_state.max_scroll_index;
+        } else if self.process_details_state.is_showing_details {
+            let scroll_state = &mut self.process_details_state.scroll_state;
+            scroll_state.current_scroll_index = scroll_state.max_scroll_index;
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Kill(MAX_PROCESS_SIGNAL);
         }
@@ -2039,6 +2511,9 @@ impl App {
                 BottomWidgetType::ProcSort => self.change_process_sort_position(amount),
                 BottomWidgetType::Temp => self.change_temp_position(amount),
                 BottomWidgetType::Disk => self.change_disk_position(amount),
+                BottomWidgetType::NetTable => self.change_net_table_position(amount),
+                BottomWidgetType::MemTable => self.change_mem_table_position(amount),
+                BottomWidgetType::Cgroup => self.change_cgroup_position(amount),
                 BottomWidgetType::CpuLegend => self.change_cpu_legend_position(amount),
                 _ => {}
             }
@@ -2103,6 +2578,45 @@ impl App {
         }
     }
 
+    fn change_net_table_position(&mut self, num_to_change_by: i64) {
+        if let Some(net_table_widget_state) = self
+            .states
+            .net_table_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            net_table_widget_state
+                .table
+                .increment_position(num_to_change_by);
+        }
+    }
+
+    fn change_mem_table_position(&mut self, num_to_change_by: i64) {
+        if let Some(mem_table_widget_state) = self
+            .states
+            .mem_table_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            mem_table_widget_state
+                .table
+                .increment_position(num_to_change_by);
+        }
+    }
+
+    fn change_cgroup_position(&mut self, num_to_change_by: i64) {
+        if let Some(cgroup_widget_state) = self
+            .states
+            .cgroup_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            cgroup_widget_state
+                .table
+                .increment_position(num_to_change_by);
+        }
+    }
+
     fn help_scroll_up(&mut self) {
         if self.help_dialog_state.scroll_state.current_scroll_index > 0 {
             self.help_dialog_state.scroll_state.current_scroll_index -= 1;
@@ -2136,6 +2650,8 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll_state.scroll_up(1);
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_in();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2153,6 +2669,8 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.process_details_state.is_showing_details {
+            self.process_details_state.scroll_state.scroll_down(1);
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_out();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2191,7 +2709,7 @@ impl App {
 
     fn zoom_out(&mut self) {
         match self.current_widget.widget_type {
-            BottomWidgetType::Cpu => {
+            BottomWidgetType::Cpu | BottomWidgetType::CpuHeatmap => {
                 if let Some(cpu_widget_state) = self
                     .states
                     .cpu_state
@@ -2275,13 +2793,75 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Pressure => {
+                if let Some(pressure_widget_state) = self
+                    .states
+                    .pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = pressure_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        pressure_widget_state.current_display_time = new_time;
+                        self.states.pressure_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if pressure_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        pressure_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        self.states.pressure_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .states
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        disk_io_widget_state.current_display_time = new_time;
+                        self.states.disk_io_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        disk_io_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        self.states.disk_io_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
 
     fn zoom_in(&mut self) {
         match self.current_widget.widget_type {
-            BottomWidgetType::Cpu => {
+            BottomWidgetType::Cpu | BottomWidgetType::CpuHeatmap => {
                 if let Some(cpu_widget_state) = self
                     .states
                     .cpu_state
@@ -2365,6 +2945,68 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Pressure => {
+                if let Some(pressure_widget_state) = self
+                    .states
+                    .pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = pressure_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        pressure_widget_state.current_display_time = new_time;
+                        self.states.pressure_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if pressure_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        pressure_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.states.pressure_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .states
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        disk_io_widget_state.current_display_time = new_time;
+                        self.states.disk_io_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        disk_io_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.states.disk_io_state.force_update =
+                            Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2414,11 +3056,43 @@ impl App {
         }
     }
 
+    fn reset_pressure_zoom(&mut self) {
+        if let Some(pressure_widget_state) = self
+            .states
+            .pressure_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            pressure_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.states.pressure_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                pressure_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_disk_io_zoom(&mut self) {
+        if let Some(disk_io_widget_state) = self
+            .states
+            .disk_io_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            disk_io_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.states.disk_io_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                disk_io_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
-            BottomWidgetType::Cpu => self.reset_cpu_zoom(),
+            BottomWidgetType::Cpu | BottomWidgetType::CpuHeatmap => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
+            BottomWidgetType::Pressure => self.reset_pressure_zoom(),
+            BottomWidgetType::DiskIo => self.reset_disk_io_zoom(),
             _ => {}
         }
     }
@@ -2574,7 +3248,10 @@ impl App {
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
                     | BottomWidgetType::Temp
-                    | BottomWidgetType::Disk => {
+                    | BottomWidgetType::Disk
+                    | BottomWidgetType::MemTable
+                    | BottomWidgetType::NetTable
+                    | BottomWidgetType::Cgroup => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
                         let header_offset = self.header_offset(&self.current_widget);
@@ -2670,6 +3347,51 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::NetTable => {
+                                    if let Some(net_table_widget_state) = self
+                                        .states
+                                        .net_table_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            net_table_widget_state.table.ratatui_selected()
+                                        {
+                                            self.change_net_table_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::MemTable => {
+                                    if let Some(mem_table_widget_state) = self
+                                        .states
+                                        .mem_table_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            mem_table_widget_state.table.ratatui_selected()
+                                        {
+                                            self.change_mem_table_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::Cgroup => {
+                                    if let Some(cgroup_widget_state) = self
+                                        .states
+                                        .cgroup_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            cgroup_widget_state.table.ratatui_selected()
+                                        {
+                                            self.change_cgroup_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 _ => {}
                             }
                         } else {
@@ -2710,6 +3432,28 @@ impl App {
                                             }
                                         }
                                     }
+                                    BottomWidgetType::NetTable => {
+                                        if let Some(net_table) = self
+                                            .states
+                                            .net_table_state
+                                            .get_mut_widget_state(self.current_widget.widget_id)
+                                        {
+                                            if net_table.table.try_select_location(x, y).is_some() {
+                                                net_table.force_data_update();
+                                            }
+                                        }
+                                    }
+                                    BottomWidgetType::Cgroup => {
+                                        if let Some(cgroup) = self
+                                            .states
+                                            .cgroup_state
+                                            .get_mut_widget_state(self.current_widget.widget_id)
+                                        {
+                                            if cgroup.table.try_select_location(x, y).is_some() {
+                                                cgroup.force_data_update();
+                                            }
+                                        }
+                                    }
                                     _ => (),
                                 }
                             }
@@ -2769,8 +3513,13 @@ impl App {
 
     /// A quick and dirty way to handle paste events.
     pub fn handle_paste(&mut self, paste: String) {
-        // Partially copy-pasted from the single-char variant; should probably clean up this process in the future.
-        // In particular, encapsulate this entire logic and add some tests to make it less potentially error-prone.
+        if self.edit_table_search(|search| {
+            search.search_state.insert_str(&paste);
+            true
+        }) {
+            return;
+        }
+
         let is_in_search_widget = self.is_in_search_widget();
         if let Some(proc_widget_state) = self
             .states
@@ -2778,28 +3527,12 @@ impl App {
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
         {
-            let num_runes = UnicodeSegmentation::graphemes(paste.as_str(), true).count();
-
             if is_in_search_widget && proc_widget_state.is_search_enabled() {
-                let left_bound = proc_widget_state.cursor_char_index();
-
-                let curr_query = &mut proc_widget_state
+                proc_widget_state
                     .proc_search
                     .search_state
-                    .current_search_query;
-                let (left, right) = curr_query.split_at(left_bound);
-                *curr_query = concat_string!(left, paste, right);
-
-                proc_widget_state.proc_search.search_state.grapheme_cursor =
-                    GraphemeCursor::new(left_bound, curr_query.len(), true);
-
-                for _ in 0..num_runes {
-                    proc_widget_state.search_walk_forward();
-                }
-
+                    .insert_str(&paste);
                 proc_widget_state.update_query();
-                proc_widget_state.proc_search.search_state.cursor_direction =
-                    CursorDirection::Right;
             }
         }
     }
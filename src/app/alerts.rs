@@ -0,0 +1,433 @@
+//! Alerts, which fire when a metric stays past a threshold for long enough.
+
+use std::{
+    fmt::Display,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{data_farmer::DataCollection, layout_manager::BottomWidgetType, query::Query};
+use crate::data_collection::cpu::CpuDataType;
+
+/// What an alert watches.
+pub enum AlertMetric {
+    /// The average CPU usage, or the usage of a single core.
+    Cpu(Option<usize>),
+    Memory,
+    Swap,
+
+    /// The usage of the disk at a mount point.
+    Disk(String),
+
+    /// The temperature of the sensors with a name.
+    Temperature(String),
+
+    /// The charge of a battery.
+    Battery(Option<usize>),
+
+    /// The number of processes matching a query. The original query is kept for display.
+    Processes(Query, String),
+}
+
+impl AlertMetric {
+    /// The current value of the metric, if there is one.
+    fn value(&self, data: &DataCollection) -> Option<f64> {
+        match self {
+            AlertMetric::Cpu(core) => {
+                let data_type = core.map(CpuDataType::Cpu);
+
+                data.cpu_harvest
+                    .iter()
+                    .find(|cpu| match (data_type, cpu.data_type) {
+                        (None, CpuDataType::Avg) => true,
+                        (Some(CpuDataType::Cpu(a)), CpuDataType::Cpu(b)) => a == b,
+                        _ => false,
+                    })
+                    .map(|cpu| cpu.cpu_usage)
+                    .or_else(|| {
+                        // The average may be hidden, in which case we calculate it ourselves.
+                        let cores = data
+                            .cpu_harvest
+                            .iter()
+                            .filter(|cpu| matches!(cpu.data_type, CpuDataType::Cpu(_)))
+                            .map(|cpu| cpu.cpu_usage)
+                            .collect::<Vec<_>>();
+
+                        (core.is_none() && !cores.is_empty())
+                            .then(|| cores.iter().sum::<f64>() / cores.len() as f64)
+                    })
+            }
+            AlertMetric::Memory => data.memory_harvest.use_percent,
+            AlertMetric::Swap => data.swap_harvest.use_percent,
+            AlertMetric::Disk(mount) => data
+                .disk_harvest
+                .iter()
+                .find(|disk| &disk.mount_point == mount)
+                .and_then(|disk| match (disk.used_space, disk.total_space) {
+                    (Some(used), Some(total)) if total > 0 => {
+                        Some(used as f64 / total as f64 * 100.0)
+                    }
+                    _ => None,
+                }),
+            AlertMetric::Temperature(sensor) => data
+                .temp_harvest
+                .iter()
+                .filter(|temp| &temp.name == sensor)
+                .filter_map(|temp| temp.temperature)
+                .reduce(f32::max)
+                .map(f64::from),
+            #[cfg(feature = "battery")]
+            AlertMetric::Battery(battery) => data
+                .battery_harvest
+                .get(battery.unwrap_or_default())
+                .map(|battery| battery.charge_percent),
+            #[cfg(not(feature = "battery"))]
+            AlertMetric::Battery(_) => None,
+            AlertMetric::Processes(query, _) => {
+                // If there's no process data at all, then there's nothing to count.
+                if data.process_data.process_harvest.is_empty() {
+                    None
+                } else {
+                    let count = data
+                        .process_data
+                        .process_harvest
+                        .values()
+                        .filter(|process| !process.is_thread && query.check(process, false))
+                        .count();
+
+                    Some(count as f64)
+                }
+            }
+        }
+    }
+
+    /// Whether the metric is shown by a type of widget.
+    fn is_shown_by(&self, widget_type: &BottomWidgetType) -> bool {
+        use BottomWidgetType::*;
+
+        match self {
+            AlertMetric::Cpu(_) => matches!(widget_type, Cpu | CpuLegend | CpuHeatmap | BasicCpu),
+            AlertMetric::Memory | AlertMetric::Swap => matches!(widget_type, Mem | BasicMem),
+            AlertMetric::Disk(_) => matches!(widget_type, Disk),
+            AlertMetric::Temperature(_) => matches!(widget_type, Temp),
+            AlertMetric::Battery(_) => matches!(widget_type, Battery),
+            AlertMetric::Processes(..) => matches!(widget_type, Proc | ProcSearch | ProcSort),
+        }
+    }
+}
+
+impl Display for AlertMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlertMetric::Cpu(None) => write!(f, "CPU"),
+            AlertMetric::Cpu(Some(core)) => write!(f, "CPU{core}"),
+            AlertMetric::Memory => write!(f, "RAM"),
+            AlertMetric::Swap => write!(f, "SWP"),
+            AlertMetric::Disk(mount) => write!(f, "disk {mount}"),
+            AlertMetric::Temperature(sensor) => write!(f, "{sensor}"),
+            AlertMetric::Battery(battery) => write!(f, "battery {}", battery.unwrap_or_default()),
+            AlertMetric::Processes(_, query) => write!(f, "processes matching '{query}'"),
+        }
+    }
+}
+
+/// A rule describing when an alert fires, and what happens when it does.
+pub struct AlertRule {
+    pub name: Option<String>,
+    pub metric: AlertMetric,
+    pub threshold: f64,
+
+    /// Whether the alert is for values below the threshold, rather than above it.
+    pub below: bool,
+
+    /// How long the threshold must be crossed for before the alert fires.
+    pub duration: Duration,
+
+    /// Whether to highlight the borders of widgets showing the metric while firing.
+    pub highlight: bool,
+
+    /// Whether to show a banner while firing.
+    pub banner: bool,
+
+    /// A shell command to run when the alert fires or resolves.
+    pub command: Option<String>,
+}
+
+impl AlertRule {
+    fn is_crossed(&self, value: f64) -> bool {
+        if self.below {
+            value < self.threshold
+        } else {
+            value > self.threshold
+        }
+    }
+}
+
+#[derive(Default)]
+struct AlertState {
+    /// When the threshold was first crossed, if it still is.
+    crossed_since: Option<Instant>,
+    is_firing: bool,
+    value: Option<f64>,
+}
+
+/// A rule starting or stopping firing.
+#[derive(Debug, PartialEq)]
+pub struct AlertChange {
+    pub index: usize,
+    pub is_firing: bool,
+}
+
+/// All configured alert rules and their current state.
+pub struct Alerts {
+    rules: Vec<(AlertRule, AlertState)>,
+    run_commands: bool,
+}
+
+impl Default for Alerts {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            run_commands: true,
+        }
+    }
+}
+
+impl Alerts {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules: rules
+                .into_iter()
+                .map(|rule| (rule, AlertState::default()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Stops commands from being run, such as when replaying a session.
+    pub fn disable_commands(&mut self) {
+        self.run_commands = false;
+    }
+
+    /// Checks every rule against the latest data, running the commands of any rules that
+    /// started or stopped firing.
+    pub fn evaluate(&mut self, data: &DataCollection) {
+        for change in self.update(data) {
+            let (rule, state) = &self.rules[change.index];
+
+            if let (true, Some(command)) = (self.run_commands, &rule.command) {
+                run_command(command, rule, state.value, change.is_firing);
+            }
+        }
+    }
+
+    /// Checks every rule against the latest data, returning which rules started or stopped firing.
+    fn update(&mut self, data: &DataCollection) -> Vec<AlertChange> {
+        let now = data.current_instant;
+        let mut changes = Vec::new();
+
+        for (index, (rule, state)) in self.rules.iter_mut().enumerate() {
+            state.value = rule.metric.value(data);
+
+            if state.value.is_some_and(|value| rule.is_crossed(value)) {
+                let crossed_since = *state.crossed_since.get_or_insert(now);
+
+                if !state.is_firing && now.saturating_duration_since(crossed_since) >= rule.duration
+                {
+                    state.is_firing = true;
+                    changes.push(AlertChange {
+                        index,
+                        is_firing: true,
+                    });
+                }
+            } else {
+                state.crossed_since = None;
+
+                if state.is_firing {
+                    state.is_firing = false;
+                    changes.push(AlertChange {
+                        index,
+                        is_firing: false,
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Whether a type of widget should be highlighted due to a firing alert.
+    pub fn is_highlighted(&self, widget_type: &BottomWidgetType) -> bool {
+        self.rules.iter().any(|(rule, state)| {
+            state.is_firing && rule.highlight && rule.metric.is_shown_by(widget_type)
+        })
+    }
+
+    /// The banner text of every firing alert that has one.
+    pub fn banners(&self) -> impl Iterator<Item = String> + '_ {
+        self.rules
+            .iter()
+            .filter(|(rule, state)| state.is_firing && rule.banner)
+            .map(|(rule, state)| {
+                let value = state.value.unwrap_or_default();
+                let value = match rule.metric {
+                    AlertMetric::Processes(..) => format!("{value}"),
+                    _ => format!("{value:.1}"),
+                };
+                let comparison = if rule.below { "<" } else { ">" };
+                let description = format!(
+                    "{} is {value} ({comparison} {})",
+                    rule.metric, rule.threshold
+                );
+
+                match &rule.name {
+                    Some(name) => format!("{name}: {description}"),
+                    None => description,
+                }
+            })
+    }
+}
+
+/// Runs an alert command in the background, with details about the alert in its environment.
+fn run_command(command: &str, rule: &AlertRule, value: Option<f64>, is_firing: bool) {
+    #[cfg(target_family = "windows")]
+    let mut shell = {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    };
+
+    #[cfg(not(target_family = "windows"))]
+    let mut shell = {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+
+    let spawned = shell
+        .arg(command)
+        .env(
+            "BOTTOM_ALERT_NAME",
+            rule.name.clone().unwrap_or_else(|| rule.metric.to_string()),
+        )
+        .env("BOTTOM_ALERT_METRIC", rule.metric.to_string())
+        .env(
+            "BOTTOM_ALERT_VALUE",
+            value.map(|value| value.to_string()).unwrap_or_default(),
+        )
+        .env("BOTTOM_ALERT_THRESHOLD", rule.threshold.to_string())
+        .env(
+            "BOTTOM_ALERT_STATE",
+            if is_firing { "firing" } else { "resolved" },
+        )
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    // Wait on the command elsewhere so it doesn't stick around as a zombie process.
+    if let Ok(mut child) = spawned {
+        thread::spawn(move || child.wait());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_collection::memory::MemHarvest;
+
+    fn memory_rule(duration: Duration) -> AlertRule {
+        AlertRule {
+            name: Some("Memory".to_string()),
+            metric: AlertMetric::Memory,
+            threshold: 80.0,
+            below: false,
+            duration,
+            highlight: true,
+            banner: true,
+            command: None,
+        }
+    }
+
+    fn memory_data(start: Instant, seconds: u64, use_percent: f64) -> DataCollection {
+        DataCollection {
+            current_instant: start + Duration::from_secs(seconds),
+            memory_harvest: MemHarvest {
+                used_bytes: 0,
+                total_bytes: 0,
+                use_percent: Some(use_percent),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fires_after_duration() {
+        let start = Instant::now();
+        let mut alerts = Alerts::new(vec![memory_rule(Duration::from_secs(10))]);
+
+        assert!(alerts.update(&memory_data(start, 0, 90.0)).is_empty());
+        assert!(alerts.update(&memory_data(start, 5, 90.0)).is_empty());
+        assert_eq!(
+            alerts.update(&memory_data(start, 10, 95.0)),
+            vec![AlertChange {
+                index: 0,
+                is_firing: true
+            }]
+        );
+        assert!(alerts.update(&memory_data(start, 11, 95.0)).is_empty());
+
+        assert!(alerts.is_highlighted(&BottomWidgetType::Mem));
+        assert!(!alerts.is_highlighted(&BottomWidgetType::Cpu));
+        assert_eq!(
+            alerts.banners().collect::<Vec<_>>(),
+            vec!["Memory: RAM is 95.0 (> 80)".to_string()]
+        );
+
+        assert_eq!(
+            alerts.update(&memory_data(start, 12, 50.0)),
+            vec![AlertChange {
+                index: 0,
+                is_firing: false
+            }]
+        );
+        assert!(!alerts.is_highlighted(&BottomWidgetType::Mem));
+        assert_eq!(alerts.banners().count(), 0);
+    }
+
+    #[test]
+    fn dipping_below_threshold_restarts_duration() {
+        let start = Instant::now();
+        let mut alerts = Alerts::new(vec![memory_rule(Duration::from_secs(10))]);
+
+        alerts.update(&memory_data(start, 0, 90.0));
+        alerts.update(&memory_data(start, 5, 70.0));
+        assert!(alerts.update(&memory_data(start, 10, 90.0)).is_empty());
+        assert!(alerts.update(&memory_data(start, 19, 90.0)).is_empty());
+        assert_eq!(alerts.update(&memory_data(start, 20, 90.0)).len(), 1);
+    }
+
+    #[test]
+    fn below_threshold() {
+        let start = Instant::now();
+        let mut alerts = Alerts::new(vec![AlertRule {
+            below: true,
+            threshold: 20.0,
+            ..memory_rule(Duration::ZERO)
+        }]);
+
+        assert!(alerts.update(&memory_data(start, 0, 50.0)).is_empty());
+        assert_eq!(alerts.update(&memory_data(start, 1, 10.0)).len(), 1);
+    }
+
+    #[test]
+    fn missing_data_does_not_fire() {
+        let mut alerts = Alerts::new(vec![AlertRule {
+            metric: AlertMetric::Disk("/".to_string()),
+            ..memory_rule(Duration::ZERO)
+        }]);
+
+        assert!(alerts.update(&DataCollection::default()).is_empty());
+    }
+}
@@ -13,14 +13,17 @@
 //! memory usage and higher CPU usage - you will be trying to process more and
 //! more points as this is used!
 
-use std::{collections::BTreeMap, time::Instant, vec::Vec};
+use std::{collections::BTreeMap, sync::Arc, time::Instant, vec::Vec};
 
 use hashbrown::HashMap;
 
 #[cfg(feature = "battery")]
 use crate::data_collection::batteries;
 use crate::{
-    data_collection::{cpu, disks, memory, network, processes::ProcessHarvest, temperature, Data},
+    data_collection::{
+        cgroups, cpu, disks, memory, network, pressure, processes::ProcessHarvest, temperature,
+        Data,
+    },
     utils::{data_prefixes::*, general::get_decimal_bytes},
     Pid,
 };
@@ -32,16 +35,34 @@ pub type Value = f64;
 pub struct TimedData {
     pub rx_data: Value,
     pub tx_data: Value,
+    /// Per-interface RX and TX rates, keyed by interface name. The names are shared with
+    /// [`DataCollection::interface_names`] so each time point doesn't hold its own copy.
+    pub interface_data: HashMap<Arc<str>, (Value, Value)>,
     pub cpu_data: Vec<Value>,
+    /// What each CPU spent its time on, in the same order as `cpu_data`.
+    pub cpu_breakdown_data: Vec<Option<cpu::CpuBreakdown>>,
+    /// The frequency of each CPU in MHz, in the same order as `cpu_data`.
+    pub cpu_frequency_data: Vec<Option<Value>>,
     pub load_avg_data: [f32; 3],
+    /// The full pressure records of the CPU, memory, and IO. The graph only shows `some.avg10`.
+    pub pressure_data: pressure::PressureHarvest,
     pub mem_data: Option<Value>,
     #[cfg(not(target_os = "windows"))]
     pub cache_data: Option<Value>,
     pub swap_data: Option<Value>,
+    pub paging_data: Option<memory::PagingRates>,
+    /// How much memory zram devices and the zswap pool use, as a percentage of RAM.
+    pub zram_data: Option<Value>,
+    pub zswap_data: Option<Value>,
     #[cfg(feature = "zfs")]
     pub arc_data: Option<Value>,
+    #[cfg(feature = "zfs")]
+    pub arc_hit_data: Option<Value>,
     #[cfg(feature = "gpu")]
     pub gpu_data: Vec<Option<Value>>,
+    /// Per-disk read and write rates in bytes per second, keyed by disk name. The names are
+    /// shared with [`DataCollection::disk_io_names`].
+    pub disk_io_data: HashMap<Arc<str>, (Value, Value)>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -109,22 +130,37 @@ pub struct DataCollection {
     pub current_instant: Instant,
     pub timed_data_vec: Vec<(Instant, TimedData)>,
     pub network_harvest: network::NetworkHarvest,
+    /// The names of every interface referenced by `timed_data_vec`.
+    pub interface_names: Vec<Arc<str>>,
+    /// The names of every disk referenced by `timed_data_vec`.
+    pub disk_io_names: Vec<Arc<str>>,
     pub memory_harvest: memory::MemHarvest,
     #[cfg(not(target_os = "windows"))]
     pub cache_harvest: memory::MemHarvest,
     pub swap_harvest: memory::MemHarvest,
+    pub mem_details_harvest: Option<memory::MemDetails>,
+    pub paging_harvest: Option<memory::PagingHarvest>,
+    pub zram_harvest: Vec<memory::ZramHarvest>,
+    pub zswap_harvest: Option<memory::ZswapHarvest>,
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
+    pub pressure_harvest: pressure::PressureHarvest,
     pub process_data: ProcessData,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IoHarvest,
     pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
+    pub io_details_prev: Vec<Option<disks::IoDetails>>,
+    pub io_rates: Vec<Option<disks::IoRates>>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    pub sensor_harvest: Vec<temperature::SensorHarvest>,
+    pub cgroup_harvest: Vec<cgroups::CgroupHarvest>,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryHarvest>,
     #[cfg(feature = "zfs")]
     pub arc_harvest: memory::MemHarvest,
+    #[cfg(feature = "zfs")]
+    pub arc_details_harvest: Option<memory::ArcDetails>,
     #[cfg(feature = "gpu")]
     pub gpu_harvest: Vec<(String, memory::MemHarvest)>,
 }
@@ -135,40 +171,76 @@ impl Default for DataCollection {
             current_instant: Instant::now(),
             timed_data_vec: Vec::default(),
             network_harvest: network::NetworkHarvest::default(),
+            interface_names: Vec::default(),
+            disk_io_names: Vec::default(),
             memory_harvest: memory::MemHarvest::default(),
             #[cfg(not(target_os = "windows"))]
             cache_harvest: memory::MemHarvest::default(),
             swap_harvest: memory::MemHarvest::default(),
+            mem_details_harvest: None,
+            paging_harvest: None,
+            zram_harvest: Vec::default(),
+            zswap_harvest: None,
             cpu_harvest: cpu::CpuHarvest::default(),
             load_avg_harvest: cpu::LoadAvgHarvest::default(),
+            pressure_harvest: pressure::PressureHarvest::default(),
             process_data: Default::default(),
             disk_harvest: Vec::default(),
             io_harvest: disks::IoHarvest::default(),
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
+            io_details_prev: Vec::default(),
+            io_rates: Vec::default(),
             temp_harvest: Vec::default(),
+            sensor_harvest: Vec::default(),
+            cgroup_harvest: Vec::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
             #[cfg(feature = "zfs")]
             arc_harvest: memory::MemHarvest::default(),
+            #[cfg(feature = "zfs")]
+            arc_details_harvest: None,
             #[cfg(feature = "gpu")]
             gpu_harvest: Vec::default(),
         }
     }
 }
 
+/// Returns the shared copy of `name` from `names`, adding it if it isn't there yet.
+fn intern(names: &mut Vec<Arc<str>>, name: &str) -> Arc<str> {
+    match names.iter().find(|existing| ***existing == *name) {
+        Some(existing) => existing.clone(),
+        None => {
+            let name: Arc<str> = Arc::from(name);
+            names.push(name.clone());
+            name
+        }
+    }
+}
+
 impl DataCollection {
     pub fn reset(&mut self) {
         self.timed_data_vec = Vec::default();
         self.network_harvest = network::NetworkHarvest::default();
+        self.interface_names = Vec::default();
+        self.disk_io_names = Vec::default();
         self.memory_harvest = memory::MemHarvest::default();
         self.swap_harvest = memory::MemHarvest::default();
+        self.mem_details_harvest = None;
+        self.paging_harvest = None;
+        self.zram_harvest = Vec::default();
+        self.zswap_harvest = None;
         self.cpu_harvest = cpu::CpuHarvest::default();
+        self.pressure_harvest = pressure::PressureHarvest::default();
         self.process_data = Default::default();
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IoHarvest::default();
         self.io_labels_and_prev = Vec::default();
+        self.io_details_prev = Vec::default();
+        self.io_rates = Vec::default();
         self.temp_harvest = Vec::default();
+        self.sensor_harvest = Vec::default();
+        self.cgroup_harvest = Vec::default();
         #[cfg(feature = "battery")]
         {
             self.battery_harvest = Vec::default();
@@ -176,6 +248,7 @@ impl DataCollection {
         #[cfg(feature = "zfs")]
         {
             self.arc_harvest = memory::MemHarvest::default();
+            self.arc_details_harvest = None;
         }
         #[cfg(feature = "gpu")]
         {
@@ -183,8 +256,10 @@ impl DataCollection {
         }
     }
 
+    /// Removes any data older than `max_time_millis`, relative to the most recent data. This is
+    /// usually the current time, but may not be when replaying a session.
     pub fn clean_data(&mut self, max_time_millis: u64) {
-        let current_time = Instant::now();
+        let current_time = self.current_instant;
 
         let remove_index = match self
             .timed_data_vec
@@ -201,6 +276,12 @@ impl DataCollection {
 
         self.timed_data_vec.drain(0..remove_index);
         self.timed_data_vec.shrink_to_fit();
+
+        // Forget the names of interfaces and disks that no remaining time point refers to.
+        self.interface_names
+            .retain(|name| Arc::strong_count(name) > 1);
+        self.disk_io_names
+            .retain(|name| Arc::strong_count(name) > 1);
     }
 
     pub fn eat_data(&mut self, harvested_data: Box<Data>) {
@@ -223,11 +304,30 @@ impl DataCollection {
             self.eat_cache(cache, &mut new_entry);
         }
 
+        // Paging
+        if let Some(paging) = harvested_data.paging {
+            self.eat_paging(paging, &mut new_entry);
+        }
+
+        // Compressed swap
+        if let Some(zram) = harvested_data.zram {
+            self.eat_zram(zram, &mut new_entry);
+        }
+
+        if let Some(zswap) = harvested_data.zswap {
+            self.eat_zswap(zswap, &mut new_entry);
+        }
+
         #[cfg(feature = "zfs")]
         if let Some(arc) = harvested_data.arc {
             self.eat_arc(arc, &mut new_entry);
         }
 
+        #[cfg(feature = "zfs")]
+        if let Some(arc_details) = harvested_data.arc_details {
+            self.eat_arc_details(arc_details, &mut new_entry);
+        }
+
         #[cfg(feature = "gpu")]
         if let Some(gpu) = harvested_data.gpu {
             self.eat_gpu(gpu, &mut new_entry);
@@ -243,18 +343,38 @@ impl DataCollection {
             self.eat_load_avg(load_avg, &mut new_entry);
         }
 
+        // Pressure
+        if let Some(pressure) = harvested_data.pressure {
+            self.eat_pressure(pressure, &mut new_entry);
+        }
+
         // Temp
         if let Some(temperature_sensors) = harvested_data.temperature_sensors {
             self.eat_temp(temperature_sensors);
         }
 
+        // Fans, voltages, and other sensors
+        if let Some(sensors) = harvested_data.sensors {
+            self.eat_sensors(sensors);
+        }
+
         // Disks
         if let Some(disks) = harvested_data.disks {
             if let Some(io) = harvested_data.io {
-                self.eat_disks(disks, io, harvested_time);
+                self.eat_disks(disks, io, harvested_time, &mut new_entry);
             }
         }
 
+        // Memory details
+        if let Some(mem_details) = harvested_data.mem_details {
+            self.eat_mem_details(mem_details);
+        }
+
+        // Cgroups
+        if let Some(cgroups) = harvested_data.cgroups {
+            self.eat_cgroups(cgroups);
+        }
+
         // Processes
         if let Some(list_of_processes) = harvested_data.list_of_processes {
             self.eat_proc(list_of_processes);
@@ -296,6 +416,34 @@ impl DataCollection {
         self.cache_harvest = cache;
     }
 
+    fn eat_paging(&mut self, paging: memory::PagingHarvest, new_entry: &mut TimedData) {
+        new_entry.paging_data = paging.per_sec;
+
+        self.paging_harvest = Some(paging);
+    }
+
+    /// Returns `bytes` as a percentage of RAM.
+    fn percent_of_ram(&self, bytes: u64) -> Option<Value> {
+        let total = self.memory_harvest.total_bytes;
+        if total == 0 {
+            None
+        } else {
+            Some(bytes as f64 / total as f64 * 100.0)
+        }
+    }
+
+    fn eat_zram(&mut self, zram: Vec<memory::ZramHarvest>, new_entry: &mut TimedData) {
+        new_entry.zram_data = self.percent_of_ram(zram.iter().map(|zram| zram.used_bytes).sum());
+
+        self.zram_harvest = zram;
+    }
+
+    fn eat_zswap(&mut self, zswap: memory::ZswapHarvest, new_entry: &mut TimedData) {
+        new_entry.zswap_data = self.percent_of_ram(zswap.pool_bytes);
+
+        self.zswap_harvest = Some(zswap);
+    }
+
     fn eat_network(&mut self, network: network::NetworkHarvest, new_entry: &mut TimedData) {
         // RX
         if network.rx > 0 {
@@ -307,6 +455,16 @@ impl DataCollection {
             new_entry.tx_data = network.tx as f64;
         }
 
+        // Per-interface RX/TX
+        new_entry.interface_data = network
+            .interfaces
+            .iter()
+            .map(|interface| {
+                let name = intern(&mut self.interface_names, &interface.name);
+                (name, (interface.rx as f64, interface.tx as f64))
+            })
+            .collect();
+
         // In addition copy over latest data for easy reference
         self.network_harvest = network;
     }
@@ -315,8 +473,13 @@ impl DataCollection {
         // Note this only pre-calculates the data points - the names will be
         // within the local copy of cpu_harvest.  Since it's all sequential
         // it probably doesn't matter anyways.
-        cpu.iter()
-            .for_each(|cpu| new_entry.cpu_data.push(cpu.cpu_usage));
+        cpu.iter().for_each(|cpu| {
+            new_entry.cpu_data.push(cpu.cpu_usage);
+            new_entry.cpu_breakdown_data.push(cpu.breakdown);
+            new_entry
+                .cpu_frequency_data
+                .push(cpu.frequency.as_ref().map(|freq| freq.current_mhz as Value));
+        });
 
         self.cpu_harvest = cpu;
     }
@@ -327,12 +490,31 @@ impl DataCollection {
         self.load_avg_harvest = load_avg;
     }
 
+    fn eat_pressure(&mut self, pressure: pressure::PressureHarvest, new_entry: &mut TimedData) {
+        new_entry.pressure_data = pressure;
+
+        self.pressure_harvest = pressure;
+    }
+
     fn eat_temp(&mut self, temperature_sensors: Vec<temperature::TempHarvest>) {
         self.temp_harvest = temperature_sensors;
     }
 
+    fn eat_sensors(&mut self, sensors: Vec<temperature::SensorHarvest>) {
+        self.sensor_harvest = sensors;
+    }
+
+    fn eat_mem_details(&mut self, mem_details: memory::MemDetails) {
+        self.mem_details_harvest = Some(mem_details);
+    }
+
+    fn eat_cgroups(&mut self, cgroups: Vec<cgroups::CgroupHarvest>) {
+        self.cgroup_harvest = cgroups;
+    }
+
     fn eat_disks(
         &mut self, disks: Vec<disks::DiskHarvest>, io: disks::IoHarvest, harvested_time: Instant,
+        new_entry: &mut TimedData,
     ) {
         let time_since_last_harvest = harvested_time
             .duration_since(self.current_instant)
@@ -389,6 +571,20 @@ impl DataCollection {
                     }
                 };
 
+                if self.io_rates.len() <= itx {
+                    self.io_rates.resize(itx + 1, None);
+                    self.io_details_prev.resize(itx + 1, None);
+                }
+
+                let details = io_device.and_then(|io| io.as_ref()?.details);
+                self.io_rates[itx] = match (details, self.io_details_prev[itx]) {
+                    (Some(details), Some(prev)) => {
+                        details.rates_since(&prev, time_since_last_harvest)
+                    }
+                    _ => None,
+                };
+                self.io_details_prev[itx] = details;
+
                 if let Some(io_device) = io_device {
                     let (io_r_pt, io_w_pt) = if let Some(io) = io_device {
                         (io.read_bytes, io.write_bytes)
@@ -415,6 +611,11 @@ impl DataCollection {
                         *io_curr = (r_rate, w_rate);
                         *io_prev = (io_r_pt, io_w_pt);
 
+                        new_entry.disk_io_data.insert(
+                            intern(&mut self.disk_io_names, &device.name),
+                            (r_rate as f64, w_rate as f64),
+                        );
+
                         if let Some(io_labels) = self.io_labels.get_mut(itx) {
                             let converted_read = get_decimal_bytes(r_rate);
                             let converted_write = get_decimal_bytes(w_rate);
@@ -463,6 +664,12 @@ impl DataCollection {
         self.arc_harvest = arc;
     }
 
+    #[cfg(feature = "zfs")]
+    fn eat_arc_details(&mut self, arc_details: memory::ArcDetails, new_entry: &mut TimedData) {
+        new_entry.arc_hit_data = arc_details.hit_percent;
+        self.arc_details_harvest = Some(arc_details);
+    }
+
     #[cfg(feature = "gpu")]
     fn eat_gpu(&mut self, gpu: Vec<(String, memory::MemHarvest)>, new_entry: &mut TimedData) {
         // Note this only pre-calculates the data points - the names will be
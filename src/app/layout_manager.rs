@@ -944,13 +944,19 @@ pub enum BottomWidgetType {
     Empty,
     Cpu,
     CpuLegend,
+    CpuHeatmap,
     Mem,
+    MemTable,
     Net,
+    NetTable,
     Proc,
     ProcSearch,
     ProcSort,
     Temp,
     Disk,
+    DiskIo,
+    Cgroup,
+    Pressure,
     BasicCpu,
     BasicMem,
     BasicNet,
@@ -961,23 +967,32 @@ pub enum BottomWidgetType {
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | Proc | ProcSort | Temp | CpuLegend | MemTable | NetTable | Cgroup
+        )
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(self, Cpu | CpuHeatmap | Net | Mem | DiskIo | Pressure)
     }
 
     pub fn get_pretty_name(&self) -> &str {
         use BottomWidgetType::*;
         match self {
             Cpu => "CPU",
+            CpuHeatmap => "CPU Heatmap",
             Mem => "Memory",
+            MemTable => "Memory Details",
             Net => "Network",
+            NetTable => "Interfaces",
             Proc => "Processes",
             Temp => "Temperature",
             Disk => "Disks",
+            DiskIo => "Disk I/O",
+            Cgroup => "Cgroups",
+            Pressure => "Pressure",
             Battery => "Battery",
             _ => "",
         }
@@ -991,11 +1006,17 @@ impl std::str::FromStr for BottomWidgetType {
         let lower_case = s.to_lowercase();
         match lower_case.as_str() {
             "cpu" => Ok(BottomWidgetType::Cpu),
+            "cpu_heatmap" | "heatmap" => Ok(BottomWidgetType::CpuHeatmap),
             "mem" | "memory" => Ok(BottomWidgetType::Mem),
+            "mem_table" | "memory_table" => Ok(BottomWidgetType::MemTable),
             "net" | "network" => Ok(BottomWidgetType::Net),
+            "net_table" | "network_table" => Ok(BottomWidgetType::NetTable),
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
             "disk" => Ok(BottomWidgetType::Disk),
+            "disk_io" | "io" => Ok(BottomWidgetType::DiskIo),
+            "cgroup" | "cgroups" => Ok(BottomWidgetType::Cgroup),
+            "pressure" | "psi" => Ok(BottomWidgetType::Pressure),
             "empty" => Ok(BottomWidgetType::Empty),
             #[cfg(feature = "battery")]
             "battery" | "batt" => Ok(BottomWidgetType::Battery),
@@ -1009,16 +1030,28 @@ Supported widget names:
 +--------------------------+
 |            cpu           |
 +--------------------------+
+|   cpu_heatmap, heatmap   |
++--------------------------+
 |        mem, memory       |
 +--------------------------+
+| mem_table, memory_table  |
++--------------------------+
 |       net, network       |
 +--------------------------+
+| net_table, network_table |
++--------------------------+
 | proc, process, processes |
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
 |           disk           |
 +--------------------------+
+|        disk_io, io       |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
 |       batt, battery      |
 +--------------------------+
 |           empty          |
@@ -1035,16 +1068,28 @@ Supported widget names:
 +--------------------------+
 |            cpu           |
 +--------------------------+
+|   cpu_heatmap, heatmap   |
++--------------------------+
 |        mem, memory       |
 +--------------------------+
+| mem_table, memory_table  |
++--------------------------+
 |       net, network       |
 +--------------------------+
+| net_table, network_table |
++--------------------------+
 | proc, process, processes |
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
 |           disk           |
 +--------------------------+
+|        disk_io, io       |
++--------------------------+
+|      cgroup, cgroups     |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
 |           empty          |
 +--------------------------+
                 ",
@@ -1064,6 +1109,10 @@ pub struct UsedWidgets {
     pub use_net: bool,
     pub use_proc: bool,
     pub use_disk: bool,
+    pub use_disk_io: bool,
     pub use_temp: bool,
+    pub use_sensors: bool,
     pub use_battery: bool,
+    pub use_cgroup: bool,
+    pub use_pressure: bool,
 }
@@ -25,6 +25,79 @@ const COMPARISON_LIST: [&str; 3] = [">", "=", "<"];
 const OR_LIST: [&str; 2] = ["or", "||"];
 const AND_LIST: [&str; 2] = ["and", "&&"];
 
+type PrefixParser<'a> = &'a dyn Fn(&str) -> Result<PrefixType>;
+
+/// The kind of values in a [`QueryColumn`], which decides how its search terms are parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// Text, which can use regex, match word, or case, like process names.
+    Text,
+    /// A number, which can be compared.
+    Number,
+    /// An amount of bytes, which can be compared and followed by a unit like `gb`.
+    Bytes,
+}
+
+/// A column of a [`QueryRow`] that can be searched by a prefix.
+pub struct QueryColumn<C: 'static> {
+    /// The prefixes for this column. These are case-insensitive.
+    pub prefixes: &'static [&'static str],
+    pub column: C,
+    pub kind: ColumnKind,
+}
+
+impl<C> QueryColumn<C> {
+    pub const fn new(prefixes: &'static [&'static str], column: C, kind: ColumnKind) -> Self {
+        Self {
+            prefixes,
+            column,
+            kind,
+        }
+    }
+
+    pub fn matches(&self, prefix: &str) -> bool {
+        self.prefixes
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(prefix))
+    }
+}
+
+/// A row of a table other than the process table that can be filtered by a [`Query`] from
+/// [`parse_table_query`].
+pub trait QueryRow {
+    type Column: 'static;
+
+    /// The columns that can be searched by a prefix, like `used%` in `used% > 90`.
+    const QUERY_COLUMNS: &'static [QueryColumn<Self::Column>];
+
+    /// The columns that search terms without a prefix are matched against. A row matches if any
+    /// of them do.
+    const NAME_COLUMNS: &'static [Self::Column];
+
+    /// Returns the text of a column. This is only called for [`ColumnKind::Text`] columns and
+    /// [`QueryRow::NAME_COLUMNS`].
+    fn text(&self, column: &Self::Column) -> Cow<'_, str>;
+
+    /// Returns the value of a column, if there is one. This is only called for
+    /// [`ColumnKind::Number`] and [`ColumnKind::Bytes`] columns.
+    fn value(&self, column: &Self::Column) -> Option<f64>;
+}
+
+fn matches_condition<I: Into<f64>, J: Into<f64>>(
+    condition: &QueryComparison, lhs: I, rhs: J,
+) -> bool {
+    let lhs: f64 = lhs.into();
+    let rhs: f64 = rhs.into();
+
+    match condition {
+        QueryComparison::Equal => (lhs - rhs).abs() < f64::EPSILON,
+        QueryComparison::Less => lhs < rhs,
+        QueryComparison::Greater => lhs > rhs,
+        QueryComparison::LessOrEqual => lhs <= rhs,
+        QueryComparison::GreaterOrEqual => lhs >= rhs,
+    }
+}
+
 /// In charge of parsing the given query.
 /// We are defining the following language for a query (case-insensitive prefixes):
 ///
@@ -36,6 +109,8 @@ const AND_LIST: [&str; 2] = ["and", "&&"];
 /// - MEM: Use prefix `mem`, cannot use r/m/c.  Can compare.
 /// - STATE: Use prefix `state`, can use regex, match word, or case.
 /// - USER: Use prefix `user`, can use regex, match word, or case.
+/// - Cgroup, container, and unit: Use prefixes `cgroup`, `container`, and `unit`, can use regex,
+///   match word, or case.
 /// - Read/s: Use prefix `r`.  Can compare.
 /// - Write/s: Use prefix `w`.  Can compare.
 /// - Total read: Use prefix `read`.  Can compare.
@@ -48,26 +123,66 @@ pub fn parse_query(
     search_query: &str, is_searching_whole_word: bool, is_ignoring_case: bool,
     is_searching_with_regex: bool,
 ) -> Result<Query> {
-    fn process_string_to_filter(query: &mut VecDeque<String>) -> Result<Query> {
-        let lhs = process_or(query)?;
+    parse_query_with(
+        search_query,
+        is_searching_whole_word,
+        is_ignoring_case,
+        is_searching_with_regex,
+        &|prefix| prefix.parse::<PrefixType>(),
+    )
+}
+
+/// Parses a query for a table other than the process table. This uses the same language as
+/// [`parse_query`], but the prefixes are the ones in [`QueryRow::QUERY_COLUMNS`], and anything
+/// else is matched against [`QueryRow::NAME_COLUMNS`].
+pub fn parse_table_query<T: QueryRow>(
+    search_query: &str, is_searching_whole_word: bool, is_ignoring_case: bool,
+    is_searching_with_regex: bool,
+) -> Result<Query> {
+    parse_query_with(
+        search_query,
+        is_searching_whole_word,
+        is_ignoring_case,
+        is_searching_with_regex,
+        &|prefix| {
+            Ok(T::QUERY_COLUMNS
+                .iter()
+                .position(|column| column.matches(prefix))
+                .map(|index| PrefixType::Column {
+                    index,
+                    kind: T::QUERY_COLUMNS[index].kind,
+                })
+                .unwrap_or(PrefixType::Name))
+        },
+    )
+}
+
+fn parse_query_with(
+    search_query: &str, is_searching_whole_word: bool, is_ignoring_case: bool,
+    is_searching_with_regex: bool, parse_prefix: &dyn Fn(&str) -> Result<PrefixType>,
+) -> Result<Query> {
+    fn process_string_to_filter(
+        query: &mut VecDeque<String>, parse_prefix: PrefixParser<'_>,
+    ) -> Result<Query> {
+        let lhs = process_or(query, parse_prefix)?;
         let mut list_of_ors = vec![lhs];
 
         while query.front().is_some() {
-            list_of_ors.push(process_or(query)?);
+            list_of_ors.push(process_or(query, parse_prefix)?);
         }
 
         Ok(Query { query: list_of_ors })
     }
 
-    fn process_or(query: &mut VecDeque<String>) -> Result<Or> {
-        let mut lhs = process_and(query)?;
+    fn process_or(query: &mut VecDeque<String>, parse_prefix: PrefixParser<'_>) -> Result<Or> {
+        let mut lhs = process_and(query, parse_prefix)?;
         let mut rhs: Option<Box<And>> = None;
 
         while let Some(queue_top) = query.front() {
             let current_lowercase = queue_top.to_lowercase();
             if OR_LIST.contains(&current_lowercase.as_str()) {
                 query.pop_front();
-                rhs = Some(Box::new(process_and(query)?));
+                rhs = Some(Box::new(process_and(query, parse_prefix)?));
 
                 if let Some(queue_next) = query.front() {
                     if OR_LIST.contains(&queue_next.to_lowercase().as_str()) {
@@ -95,8 +210,8 @@ pub fn parse_query(
         Ok(Or { lhs, rhs })
     }
 
-    fn process_and(query: &mut VecDeque<String>) -> Result<And> {
-        let mut lhs = process_prefix(query, false)?;
+    fn process_and(query: &mut VecDeque<String>, parse_prefix: PrefixParser<'_>) -> Result<And> {
+        let mut lhs = process_prefix(query, false, parse_prefix)?;
         let mut rhs: Option<Box<Prefix>> = None;
 
         while let Some(queue_top) = query.front() {
@@ -104,7 +219,7 @@ pub fn parse_query(
             if AND_LIST.contains(&current_lowercase.as_str()) {
                 query.pop_front();
 
-                rhs = Some(Box::new(process_prefix(query, false)?));
+                rhs = Some(Box::new(process_prefix(query, false, parse_prefix)?));
 
                 if let Some(next_queue_top) = query.front() {
                     if AND_LIST.contains(&next_queue_top.to_lowercase().as_str()) {
@@ -172,7 +287,9 @@ pub fn parse_query(
         }
     }
 
-    fn process_prefix(query: &mut VecDeque<String>, inside_quotation: bool) -> Result<Prefix> {
+    fn process_prefix(
+        query: &mut VecDeque<String>, inside_quotation: bool, parse_prefix: PrefixParser<'_>,
+    ) -> Result<Prefix> {
         if let Some(queue_top) = query.pop_front() {
             if inside_quotation {
                 if queue_top == "\"" {
@@ -213,7 +330,7 @@ pub fn parse_query(
 
                 while let Some(in_paren_query_top) = query.front() {
                     if in_paren_query_top != ")" {
-                        list_of_ors.push_back(process_or(query)?);
+                        list_of_ors.push_back(process_or(query, parse_prefix)?);
                     } else {
                         break;
                     }
@@ -271,7 +388,7 @@ pub fn parse_query(
                 // Similar to parentheses, trap and check for missing closing quotes.  Note, however, that we
                 // will DIRECTLY call another process_prefix call...
 
-                let prefix = process_prefix(query, true)?;
+                let prefix = process_prefix(query, true, parse_prefix)?;
                 if let Some(close_paren) = query.pop_front() {
                     if close_paren == "\"" {
                         return Ok(prefix);
@@ -283,7 +400,7 @@ pub fn parse_query(
                 }
             } else {
                 //  Get prefix type...
-                let prefix_type = queue_top.parse::<PrefixType>()?;
+                let prefix_type = parse_prefix(&queue_top)?;
                 let content = if let PrefixType::Name = prefix_type {
                     Some(queue_top)
                 } else {
@@ -299,7 +416,17 @@ pub fn parse_query(
                                 compare_prefix: None,
                             })
                         }
-                        PrefixType::Pid | PrefixType::State | PrefixType::User => {
+                        PrefixType::Pid
+                        | PrefixType::State
+                        | PrefixType::User
+                        | PrefixType::Policy
+                        | PrefixType::Cgroup
+                        | PrefixType::Container
+                        | PrefixType::Unit
+                        | PrefixType::Column {
+                            kind: ColumnKind::Text,
+                            ..
+                        } => {
                             // We have to check if someone put an "="...
                             if content == "=" {
                                 // Check next string if possible
@@ -430,10 +557,19 @@ pub fn parse_query(
 
                                     match prefix_type {
                                         PrefixType::MemBytes
+                                        | PrefixType::Rss
+                                        | PrefixType::Virtual
+                                        | PrefixType::Shared
+                                        | PrefixType::Private
+                                        | PrefixType::Swap
                                         | PrefixType::Rps
                                         | PrefixType::Wps
                                         | PrefixType::TRead
-                                        | PrefixType::TWrite => {
+                                        | PrefixType::TWrite
+                                        | PrefixType::Column {
+                                            kind: ColumnKind::Bytes,
+                                            ..
+                                        } => {
                                             process_prefix_units(query, &mut value);
                                         }
                                         #[cfg(feature = "gpu")]
@@ -487,7 +623,7 @@ pub fn parse_query(
         }
     });
 
-    let mut process_filter = process_string_to_filter(&mut split_query)?;
+    let mut process_filter = process_string_to_filter(&mut split_query, parse_prefix)?;
     process_filter.process_regexes(
         is_searching_whole_word,
         is_ignoring_case,
@@ -523,6 +659,10 @@ impl Query {
             .iter()
             .all(|ok| ok.check(process, is_using_command))
     }
+
+    pub fn check_row<T: QueryRow>(&self, row: &T) -> bool {
+        self.query.iter().all(|ok| ok.check_row(row))
+    }
 }
 
 impl Debug for Query {
@@ -565,6 +705,14 @@ impl Or {
             self.lhs.check(process, is_using_command)
         }
     }
+
+    pub fn check_row<T: QueryRow>(&self, row: &T) -> bool {
+        if let Some(rhs) = &self.rhs {
+            self.lhs.check_row(row) || rhs.check_row(row)
+        } else {
+            self.lhs.check_row(row)
+        }
+    }
 }
 
 impl Debug for Or {
@@ -610,6 +758,14 @@ impl And {
             self.lhs.check(process, is_using_command)
         }
     }
+
+    pub fn check_row<T: QueryRow>(&self, row: &T) -> bool {
+        if let Some(rhs) = &self.rhs {
+            self.lhs.check_row(row) && rhs.check_row(row)
+        } else {
+            self.lhs.check_row(row)
+        }
+    }
 }
 
 impl Debug for And {
@@ -627,6 +783,18 @@ pub enum PrefixType {
     PCpu,
     MemBytes,
     PMem,
+    Rss,
+    Virtual,
+    Shared,
+    Private,
+    Swap,
+    Threads,
+    Nice,
+    Priority,
+    Policy,
+    Processor,
+    MinorFaults,
+    MajorFaults,
     Rps,
     Wps,
     TRead,
@@ -635,12 +803,20 @@ pub enum PrefixType {
     State,
     User,
     Time,
+    Cgroup,
+    Container,
+    Unit,
     #[cfg(feature = "gpu")]
     PGpu,
     #[cfg(feature = "gpu")]
     GMem,
     #[cfg(feature = "gpu")]
     PGMem,
+    /// A column of a [`QueryRow`], by its index in [`QueryRow::QUERY_COLUMNS`].
+    Column {
+        index: usize,
+        kind: ColumnKind,
+    },
     __Nonexhaustive,
 }
 
@@ -660,6 +836,30 @@ impl std::str::FromStr for PrefixType {
             result = PMem;
         } else if multi_eq_ignore_ascii_case!(s, "memb") {
             result = MemBytes;
+        } else if multi_eq_ignore_ascii_case!(s, "rss") {
+            result = Rss;
+        } else if multi_eq_ignore_ascii_case!(s, "virt" | "virtual") {
+            result = Virtual;
+        } else if multi_eq_ignore_ascii_case!(s, "shared" | "shr") {
+            result = Shared;
+        } else if multi_eq_ignore_ascii_case!(s, "private" | "priv") {
+            result = Private;
+        } else if multi_eq_ignore_ascii_case!(s, "swap") {
+            result = Swap;
+        } else if multi_eq_ignore_ascii_case!(s, "threads") {
+            result = Threads;
+        } else if multi_eq_ignore_ascii_case!(s, "nice" | "ni") {
+            result = Nice;
+        } else if multi_eq_ignore_ascii_case!(s, "priority" | "prio" | "pri") {
+            result = Priority;
+        } else if multi_eq_ignore_ascii_case!(s, "policy" | "sched") {
+            result = Policy;
+        } else if multi_eq_ignore_ascii_case!(s, "processor" | "psr") {
+            result = Processor;
+        } else if multi_eq_ignore_ascii_case!(s, "minflt" | "minflt/s") {
+            result = MinorFaults;
+        } else if multi_eq_ignore_ascii_case!(s, "majflt" | "majflt/s") {
+            result = MajorFaults;
         } else if multi_eq_ignore_ascii_case!(s, "read" | "r/s" | "rps") {
             result = Rps;
         } else if multi_eq_ignore_ascii_case!(s, "write" | "w/s" | "wps") {
@@ -676,6 +876,12 @@ impl std::str::FromStr for PrefixType {
             result = User;
         } else if multi_eq_ignore_ascii_case!(s, "time") {
             result = Time;
+        } else if multi_eq_ignore_ascii_case!(s, "cgroup") {
+            result = Cgroup;
+        } else if multi_eq_ignore_ascii_case!(s, "container") {
+            result = Container;
+        } else if multi_eq_ignore_ascii_case!(s, "unit" | "pod") {
+            result = Unit;
         }
         #[cfg(feature = "gpu")]
         {
@@ -713,7 +919,18 @@ impl Prefix {
         } else if let Some((prefix_type, StringQuery::Value(regex_string))) = &mut self.regex_prefix
         {
             match prefix_type {
-                PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::User => {
+                PrefixType::Pid
+                | PrefixType::Name
+                | PrefixType::State
+                | PrefixType::User
+                | PrefixType::Policy
+                | PrefixType::Cgroup
+                | PrefixType::Container
+                | PrefixType::Unit
+                | PrefixType::Column {
+                    kind: ColumnKind::Text,
+                    ..
+                } => {
                     let escaped_regex: String;
                     let final_regex_string = &format!(
                         "{}{}{}{}",
@@ -744,21 +961,6 @@ impl Prefix {
     }
 
     pub fn check(&self, process: &ProcessHarvest, is_using_command: bool) -> bool {
-        fn matches_condition<I: Into<f64>, J: Into<f64>>(
-            condition: &QueryComparison, lhs: I, rhs: J,
-        ) -> bool {
-            let lhs: f64 = lhs.into();
-            let rhs: f64 = rhs.into();
-
-            match condition {
-                QueryComparison::Equal => (lhs - rhs).abs() < f64::EPSILON,
-                QueryComparison::Less => lhs < rhs,
-                QueryComparison::Greater => lhs > rhs,
-                QueryComparison::LessOrEqual => lhs <= rhs,
-                QueryComparison::GreaterOrEqual => lhs >= rhs,
-            }
-        }
-
         fn matches_duration(condition: &QueryComparison, lhs: Duration, rhs: Duration) -> bool {
             match condition {
                 QueryComparison::Equal => lhs == rhs,
@@ -782,6 +984,10 @@ impl Prefix {
                     PrefixType::Pid => r.is_match(process.pid.to_string().as_str()),
                     PrefixType::State => r.is_match(process.process_state.0.as_str()),
                     PrefixType::User => r.is_match(process.user.as_ref()),
+                    PrefixType::Policy => r.is_match(process.policy.as_ref()),
+                    PrefixType::Cgroup => r.is_match(process.cgroup.as_deref().unwrap_or("")),
+                    PrefixType::Container => r.is_match(process.container.as_deref().unwrap_or("")),
+                    PrefixType::Unit => r.is_match(process.unit.as_deref().unwrap_or("")),
                     _ => true,
                 }
             } else {
@@ -800,11 +1006,61 @@ impl Prefix {
                         process.mem_usage_percent,
                         numerical_query.value,
                     ),
-                    PrefixType::MemBytes => matches_condition(
+                    PrefixType::MemBytes | PrefixType::Rss => matches_condition(
                         &numerical_query.condition,
                         process.mem_usage_bytes as f64,
                         numerical_query.value,
                     ),
+                    PrefixType::Virtual => matches_condition(
+                        &numerical_query.condition,
+                        process.virtual_bytes as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Shared => matches_condition(
+                        &numerical_query.condition,
+                        process.shared_bytes as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Private => matches_condition(
+                        &numerical_query.condition,
+                        process.private_bytes as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Swap => matches_condition(
+                        &numerical_query.condition,
+                        process.swap_bytes as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Threads => matches_condition(
+                        &numerical_query.condition,
+                        process.num_threads as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Nice => matches_condition(
+                        &numerical_query.condition,
+                        process.nice,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Priority => matches_condition(
+                        &numerical_query.condition,
+                        process.priority,
+                        numerical_query.value,
+                    ),
+                    PrefixType::Processor => matches_condition(
+                        &numerical_query.condition,
+                        process.processor,
+                        numerical_query.value,
+                    ),
+                    PrefixType::MinorFaults => matches_condition(
+                        &numerical_query.condition,
+                        process.minor_faults_per_sec as f64,
+                        numerical_query.value,
+                    ),
+                    PrefixType::MajorFaults => matches_condition(
+                        &numerical_query.condition,
+                        process.major_faults_per_sec as f64,
+                        numerical_query.value,
+                    ),
                     PrefixType::Rps => matches_condition(
                         &numerical_query.condition,
                         process.read_bytes_per_sec as f64,
@@ -857,6 +1113,37 @@ impl Prefix {
             true
         }
     }
+
+    pub fn check_row<T: QueryRow>(&self, row: &T) -> bool {
+        let query_column = |index: &usize| T::QUERY_COLUMNS.get(*index).map(|c| &c.column);
+
+        if let Some(or) = &self.or {
+            or.check_row(row)
+        } else if let Some((prefix_type, StringQuery::Regex(r))) = &self.regex_prefix {
+            match prefix_type {
+                PrefixType::Name => T::NAME_COLUMNS
+                    .iter()
+                    .any(|column| r.is_match(&row.text(column))),
+                PrefixType::Column { index, .. } => {
+                    query_column(index).map_or(true, |column| r.is_match(&row.text(column)))
+                }
+                _ => true,
+            }
+        } else if let Some((
+            PrefixType::Column { index, .. },
+            ComparableQuery::Numerical(numerical_query),
+        )) = &self.compare_prefix
+        {
+            // Rows without a value never match a comparison.
+            query_column(index)
+                .and_then(|column| row.value(column))
+                .is_some_and(|value| {
+                    matches_condition(&numerical_query.condition, value, numerical_query.value)
+                })
+        } else {
+            true
+        }
+    }
 }
 
 impl Debug for Prefix {
@@ -905,3 +1192,259 @@ pub struct TimeQuery {
     pub condition: QueryComparison,
     pub duration: Duration,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::SearchState;
+
+    struct Row {
+        name: &'static str,
+        kind: &'static str,
+        size: Option<u64>,
+    }
+
+    enum Column {
+        Name,
+        Kind,
+        Size,
+    }
+
+    impl QueryRow for Row {
+        type Column = Column;
+
+        const QUERY_COLUMNS: &'static [QueryColumn<Column>] = &[
+            QueryColumn::new(&["name"], Column::Name, ColumnKind::Text),
+            QueryColumn::new(&["kind", "type"], Column::Kind, ColumnKind::Text),
+            QueryColumn::new(&["size"], Column::Size, ColumnKind::Bytes),
+        ];
+        const NAME_COLUMNS: &'static [Column] = &[Column::Name, Column::Kind];
+
+        fn text(&self, column: &Column) -> Cow<'_, str> {
+            match column {
+                Column::Name => self.name.into(),
+                Column::Kind => self.kind.into(),
+                Column::Size => self
+                    .size
+                    .map(|size| size.to_string())
+                    .unwrap_or_default()
+                    .into(),
+            }
+        }
+
+        fn value(&self, column: &Column) -> Option<f64> {
+            match column {
+                Column::Size => self.size.map(|size| size as f64),
+                _ => None,
+            }
+        }
+    }
+
+    const ROWS: [Row; 3] = [
+        Row {
+            name: "sda1",
+            kind: "ext4",
+            size: Some(2_000_000),
+        },
+        Row {
+            name: "sdb1",
+            kind: "xfs",
+            size: Some(500),
+        },
+        Row {
+            name: "tmpfs",
+            kind: "tmpfs",
+            size: None,
+        },
+    ];
+
+    /// Returns the indices of the rows that match `query`.
+    fn matching_rows<T: QueryRow>(rows: &[T], query: &str) -> Vec<usize> {
+        let query = parse_table_query::<T>(query, false, true, false).unwrap();
+        rows.iter()
+            .enumerate()
+            .filter(|(_, row)| query.check_row(*row))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn matching(query: &str) -> Vec<&'static str> {
+        matching_rows(&ROWS, query)
+            .into_iter()
+            .map(|index| ROWS[index].name)
+            .collect()
+    }
+
+    #[test]
+    fn table_query_names() {
+        assert_eq!(matching("sd"), vec!["sda1", "sdb1"]);
+        assert_eq!(matching("XFS"), vec!["sdb1"]);
+        assert_eq!(matching("name = tmp"), vec!["tmpfs"]);
+        assert_eq!(matching("type = ext4 or type = xfs"), vec!["sda1", "sdb1"]);
+    }
+
+    #[test]
+    fn table_query_comparisons() {
+        assert_eq!(matching("size > 1 mb"), vec!["sda1"]);
+        assert_eq!(matching("size <= 500"), vec!["sdb1"]);
+        assert_eq!(matching("sd and size < 1 kb"), vec!["sdb1"]);
+
+        // Rows without a value never match.
+        assert_eq!(matching("size >= 0"), vec!["sda1", "sdb1"]);
+    }
+
+    #[test]
+    fn table_query_invalid_search() {
+        let mut search = SearchState::default();
+
+        search.search_state.current_search_query = "size >".to_string();
+        search.update_table_query::<Row>();
+        assert!(search.search_state.is_invalid_search);
+
+        // An invalid search filters nothing rather than everything.
+        assert!(search.query().is_none());
+    }
+
+    #[test]
+    fn disk_query() {
+        use crate::widgets::DiskWidgetData;
+
+        let disk = |name: &str, used_bytes: u64| DiskWidgetData {
+            name: name.to_string().into(),
+            mount_point: "/".into(),
+            free_bytes: Some(100 - used_bytes),
+            used_bytes: Some(used_bytes),
+            total_bytes: Some(100),
+            summed_total_bytes: Some(100),
+            io_read: "0B/s".into(),
+            io_write: "0B/s".into(),
+            io_bytes_per_sec: None,
+            io_rates: None,
+            free_inodes: None,
+            used_inodes: None,
+            total_inodes: None,
+            fs_type: "ext4".into(),
+            mount_options: "rw".into(),
+            device: "/dev/sda".into(),
+        };
+        let disks = [
+            disk("/dev/sda1", 95),
+            disk("/dev/sda2", 90),
+            disk("/dev/sdb1", 10),
+        ];
+
+        assert_eq!(matching_rows(&disks, "used% > 90"), vec![0]);
+        assert_eq!(matching_rows(&disks, "used% >= 90"), vec![0, 1]);
+        assert_eq!(matching_rows(&disks, "sdb"), vec![2]);
+    }
+
+    #[test]
+    fn temperature_query() {
+        use crate::{
+            data_collection::temperature::{SensorKind, TemperatureType},
+            widgets::TempWidgetData,
+        };
+
+        let sensor = |name: &str, temperature, reading| TempWidgetData {
+            sensor: name.to_string().into(),
+            temperature_value: temperature,
+            temperature_type: TemperatureType::Celsius,
+            reading,
+        };
+        let sensors = [
+            sensor("CPU", Some(85), None),
+            sensor("GPU", Some(80), None),
+            sensor("NVMe", Some(40), None),
+            sensor("CPU Fan", None, Some((SensorKind::Fan, 1200.0))),
+        ];
+
+        assert_eq!(matching_rows(&sensors, "temp > 80"), vec![0]);
+        assert_eq!(matching_rows(&sensors, "temp >= 80"), vec![0, 1]);
+        assert_eq!(matching_rows(&sensors, "cpu"), vec![0, 3]);
+
+        // Only `value` matches sensors that aren't temperature sensors.
+        assert_eq!(matching_rows(&sensors, "value > 80"), vec![0, 3]);
+    }
+
+    #[test]
+    fn cpu_legend_query() {
+        use crate::{data_collection::cpu::CpuDataType, widgets::CpuWidgetTableData};
+
+        let entry = |data_type, last_entry| CpuWidgetTableData::Entry {
+            data_type,
+            last_entry,
+            last_breakdown: None,
+            last_frequency: None,
+        };
+        let cpus = [
+            entry(CpuDataType::Avg, 40.0),
+            entry(CpuDataType::Cpu(0), 10.0),
+            entry(CpuDataType::Cpu(1), 70.0),
+        ];
+
+        assert_eq!(matching_rows(&cpus, "use > 30"), vec![0, 2]);
+        assert_eq!(matching_rows(&cpus, "avg"), vec![0]);
+        assert_eq!(matching_rows(&cpus, "cpu1"), vec![2]);
+    }
+
+    #[test]
+    fn cgroup_query() {
+        use crate::widgets::CgroupWidgetData;
+
+        let cgroup = |path: &str, cpu_usage_percent, memory: Option<(u64, u64)>| CgroupWidgetData {
+            path: path.to_string().into(),
+            cpu_usage_percent,
+            memory_bytes: memory.map(|(used, _)| used),
+            memory_max_bytes: memory.map(|(_, max)| max),
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 2048,
+            pids: Some(3),
+        };
+        let cgroups = [
+            cgroup("/system.slice/sshd.service", 1.0, None),
+            cgroup("/system.slice/docker.service", 40.0, Some((900, 1000))),
+            cgroup("/user.slice", 10.0, Some((100, 1000))),
+        ];
+
+        assert_eq!(matching_rows(&cgroups, "system.slice"), vec![0, 1]);
+        assert_eq!(matching_rows(&cgroups, "cpu > 5"), vec![1, 2]);
+        assert_eq!(matching_rows(&cgroups, "mem% >= 90"), vec![1]);
+        assert_eq!(matching_rows(&cgroups, "mem < 1 kb"), vec![1, 2]);
+        assert_eq!(
+            matching_rows(&cgroups, "write = 2 kib and pids = 3"),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn process_query_policy() {
+        let processes =
+            [("init", "normal"), ("rtkit", "FIFO"), ("audio", "RR")].map(|(name, policy)| {
+                ProcessHarvest {
+                    name: name.to_string(),
+                    policy: policy.into(),
+                    ..Default::default()
+                }
+            });
+        let matching = |query: &str| {
+            let query = parse_query(query, false, true, false).unwrap();
+            processes
+                .iter()
+                .filter(|process| query.check(process, false))
+                .map(|process| process.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matching("policy=FIFO"), vec!["rtkit"]);
+        assert_eq!(
+            matching("policy = fifo or sched = rr"),
+            vec!["rtkit", "audio"]
+        );
+    }
+
+    #[test]
+    fn table_query_errors() {
+        assert!(parse_table_query::<Row>("size > ", false, true, false).is_err());
+        assert!(parse_table_query::<Row>("size > abc", false, true, false).is_err());
+    }
+}
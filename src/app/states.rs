@@ -7,20 +7,28 @@ use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete, UnicodeSegmentati
 use crate::{
     app::{layout_manager::BottomWidgetType, query::*},
     constants,
+    data_collection::processes::details::ProcessDetails,
     utils::general::str_width,
     widgets::{
-        BatteryWidgetState, CpuWidgetState, DiskTableWidget, MemWidgetState, NetWidgetState,
+        BatteryWidgetState, CgroupTableWidget, CpuWidgetState, DiskIoWidgetState, DiskTableWidget,
+        MemTableWidget, MemWidgetState, NetTableWidget, NetWidgetState, PressureWidgetState,
         ProcWidgetState, TempWidgetState,
     },
+    Pid,
 };
 
 pub struct AppWidgetStates {
     pub cpu_state: CpuState,
     pub mem_state: MemState,
+    pub mem_table_state: MemTableState,
     pub net_state: NetState,
+    pub net_table_state: NetTableState,
     pub proc_state: ProcState,
     pub temp_state: TempState,
     pub disk_state: DiskState,
+    pub disk_io_state: DiskIoState,
+    pub cgroup_state: CgroupState,
+    pub pressure_state: PressureState,
     pub battery_state: BatteryState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
 }
@@ -57,6 +65,8 @@ pub struct AppDeleteDialogState {
     pub keyboard_signal_select: usize,
     pub last_number_press: Option<Instant>,
     pub scroll_pos: usize,
+    /// The thread that was selected, if the process to kill is the one that owns it.
+    pub thread: Option<Pid>,
 }
 
 pub struct AppHelpDialogState {
@@ -77,6 +87,16 @@ impl Default for AppHelpDialogState {
     }
 }
 
+/// The state of the dialog showing the details of a single process.
+#[derive(Default)]
+pub struct AppProcessDetailsState {
+    pub is_showing_details: bool,
+    pub pid: Pid,
+    pub details: Option<anyhow::Result<ProcessDetails>>,
+    pub height: u16,
+    pub scroll_state: ParagraphScrollState,
+}
+
 /// AppSearchState deals with generic searching (I might do this in the future).
 pub struct AppSearchState {
     pub is_enabled: bool,
@@ -260,6 +280,195 @@ impl AppSearchState {
 
         self.size_mappings.shrink_to_fit();
     }
+
+    fn cursor_char_index(&self) -> usize {
+        self.grapheme_cursor.cur_cursor()
+    }
+
+    fn set_cursor(&mut self, index: usize) {
+        self.grapheme_cursor = GraphemeCursor::new(index, self.current_search_query.len(), true);
+    }
+
+    /// Inserts a character at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        self.current_search_query
+            .insert(self.cursor_char_index(), c);
+        self.set_cursor(self.cursor_char_index());
+        self.walk_forward();
+        self.cursor_direction = CursorDirection::Right;
+    }
+
+    /// Inserts a string at the cursor, like when pasting.
+    pub fn insert_str(&mut self, s: &str) {
+        let left_bound = self.cursor_char_index();
+        self.current_search_query.insert_str(left_bound, s);
+        self.set_cursor(left_bound);
+
+        for _ in 0..UnicodeSegmentation::graphemes(s, true).count() {
+            self.walk_forward();
+        }
+        self.cursor_direction = CursorDirection::Right;
+    }
+
+    /// Deletes the character at the cursor, returning whether there was one.
+    pub fn delete_at_cursor(&mut self) -> bool {
+        let current_cursor = self.cursor_char_index();
+        if current_cursor >= self.current_search_query.len() {
+            return false;
+        }
+
+        self.walk_forward();
+        let _ = self
+            .current_search_query
+            .drain(current_cursor..self.cursor_char_index());
+        self.set_cursor(current_cursor);
+
+        true
+    }
+
+    /// Deletes the character behind the cursor, returning whether there was one.
+    pub fn delete_before_cursor(&mut self) -> bool {
+        let current_cursor = self.cursor_char_index();
+        if current_cursor == 0 {
+            return false;
+        }
+
+        self.walk_backward();
+        let _ = self
+            .current_search_query
+            .drain(self.cursor_char_index()..current_cursor);
+        self.set_cursor(self.cursor_char_index());
+        self.cursor_direction = CursorDirection::Left;
+
+        true
+    }
+
+    /// Deletes the word behind the cursor, along with any whitespace between it and the cursor.
+    pub fn delete_word_before_cursor(&mut self) {
+        let end_index = self.cursor_char_index();
+        let query = &self.current_search_query;
+        let mut start_index = 0;
+        let mut saw_non_whitespace = false;
+
+        for (itx, c) in query
+            .chars()
+            .rev()
+            .enumerate()
+            .skip(query.len() - end_index)
+        {
+            if c.is_whitespace() {
+                if saw_non_whitespace {
+                    start_index = query.len() - itx;
+                    break;
+                }
+            } else {
+                saw_non_whitespace = true;
+            }
+        }
+
+        let _ = self.current_search_query.drain(start_index..end_index);
+        self.set_cursor(start_index);
+        self.cursor_direction = CursorDirection::Left;
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        let prev_cursor = self.cursor_char_index();
+        self.walk_backward();
+        if self.cursor_char_index() < prev_cursor {
+            self.cursor_direction = CursorDirection::Left;
+        }
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        let prev_cursor = self.cursor_char_index();
+        self.walk_forward();
+        if self.cursor_char_index() > prev_cursor {
+            self.cursor_direction = CursorDirection::Right;
+        }
+    }
+
+    pub fn move_cursor_to_start(&mut self) {
+        self.set_cursor(0);
+        self.cursor_direction = CursorDirection::Left;
+    }
+
+    pub fn move_cursor_to_end(&mut self) {
+        self.set_cursor(self.current_search_query.len());
+        self.cursor_direction = CursorDirection::Right;
+    }
+}
+
+/// The state of a search bar, along with its settings.
+pub struct SearchState {
+    pub search_state: AppSearchState,
+    pub is_ignoring_case: bool,
+    pub is_searching_whole_word: bool,
+    pub is_searching_with_regex: bool,
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        SearchState {
+            search_state: AppSearchState::default(),
+            is_ignoring_case: true,
+            is_searching_whole_word: false,
+            is_searching_with_regex: false,
+        }
+    }
+}
+
+impl SearchState {
+    pub fn search_toggle_ignore_case(&mut self) {
+        self.is_ignoring_case = !self.is_ignoring_case;
+    }
+
+    pub fn search_toggle_whole_word(&mut self) {
+        self.is_searching_whole_word = !self.is_searching_whole_word;
+    }
+
+    pub fn search_toggle_regex(&mut self) {
+        self.is_searching_with_regex = !self.is_searching_with_regex;
+    }
+
+    /// Returns the query to filter by, if the search is neither blank nor invalid.
+    pub fn query(&self) -> Option<&Query> {
+        if self.search_state.is_invalid_or_blank_search() {
+            None
+        } else {
+            self.search_state.query.as_ref()
+        }
+    }
+
+    /// Parses the current search for a table whose rows are `T`.
+    pub fn update_table_query<T: QueryRow>(&mut self) {
+        let search_state = &mut self.search_state;
+        if search_state.current_search_query.is_empty() {
+            search_state.is_blank_search = true;
+            search_state.is_invalid_search = false;
+            search_state.error_message = None;
+        } else {
+            match parse_table_query::<T>(
+                &search_state.current_search_query,
+                self.is_searching_whole_word,
+                self.is_ignoring_case,
+                self.is_searching_with_regex,
+            ) {
+                Ok(parsed_query) => {
+                    search_state.query = Some(parsed_query);
+                    search_state.is_blank_search = false;
+                    search_state.is_invalid_search = false;
+                    search_state.error_message = None;
+                }
+                Err(err) => {
+                    search_state.is_blank_search = false;
+                    search_state.is_invalid_search = true;
+                    search_state.error_message = Some(err.to_string());
+                }
+            }
+        }
+
+        search_state.update_sizes();
+    }
 }
 
 pub struct ProcState {
@@ -283,6 +492,8 @@ impl ProcState {
 pub struct NetState {
     pub force_update: Option<u64>,
     pub widget_states: HashMap<u64, NetWidgetState>,
+    /// The interface to graph, if only a single interface should be shown.
+    pub selected_interface: Option<String>,
 }
 
 impl NetState {
@@ -290,6 +501,7 @@ impl NetState {
         NetState {
             force_update: None,
             widget_states,
+            selected_interface: None,
         }
     }
 
@@ -381,6 +593,108 @@ impl DiskState {
         self.widget_states.get(&widget_id)
     }
 }
+
+pub struct DiskIoState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, DiskIoWidgetState>,
+    /// The disk to graph, if only a single disk should be shown.
+    pub selected_disk: Option<String>,
+}
+
+impl DiskIoState {
+    pub fn init(widget_states: HashMap<u64, DiskIoWidgetState>) -> Self {
+        DiskIoState {
+            force_update: None,
+            widget_states,
+            selected_disk: None,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut DiskIoWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&DiskIoWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct NetTableState {
+    pub widget_states: HashMap<u64, NetTableWidget>,
+}
+
+impl NetTableState {
+    pub fn init(widget_states: HashMap<u64, NetTableWidget>) -> Self {
+        NetTableState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut NetTableWidget> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&NetTableWidget> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct PressureState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, PressureWidgetState>,
+}
+
+impl PressureState {
+    pub fn init(widget_states: HashMap<u64, PressureWidgetState>) -> Self {
+        PressureState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut PressureWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&PressureWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct MemTableState {
+    pub widget_states: HashMap<u64, MemTableWidget>,
+}
+
+impl MemTableState {
+    pub fn init(widget_states: HashMap<u64, MemTableWidget>) -> Self {
+        MemTableState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut MemTableWidget> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&MemTableWidget> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct CgroupState {
+    pub widget_states: HashMap<u64, CgroupTableWidget>,
+}
+
+impl CgroupState {
+    pub fn init(widget_states: HashMap<u64, CgroupTableWidget>) -> Self {
+        CgroupState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut CgroupTableWidget> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&CgroupTableWidget> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct BasicTableWidgetState {
     // Since this is intended (currently) to only be used for ONE widget, that's
     // how it's going to be written.  If we want to allow for multiple of these,
@@ -418,6 +732,19 @@ pub struct ParagraphScrollState {
     pub max_scroll_index: u16,
 }
 
+impl ParagraphScrollState {
+    pub fn scroll_up(&mut self, amount: u16) {
+        self.current_scroll_index = self.current_scroll_index.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: u16) {
+        self.current_scroll_index = self
+            .current_scroll_index
+            .saturating_add(amount)
+            .min(self.max_scroll_index);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -20,8 +20,10 @@ use bottom::{
     create_or_get_config,
     data_conversion::*,
     handle_key_event_or_break, handle_mouse_event,
-    options::{get_color_scheme, get_widget_layout, init_app},
-    panic_hook, read_config, try_drawing, update_data, BottomEvent,
+    options::{get_color_scheme, get_output_mode, get_replay_session, get_widget_layout, init_app},
+    output, panic_hook, read_config,
+    session::replay::{create_replay_thread, ReplayEvent, ReplayState},
+    try_drawing, update_data, BottomEvent,
 };
 use crossterm::{
     event::{EnableBracketedPaste, EnableMouseCapture},
@@ -69,6 +71,9 @@ fn main() -> Result<()> {
         CanvasStyling::new(colour_scheme, &config)?
     };
 
+    let output_mode = get_output_mode(&matches, &config);
+    let replay_session = get_replay_session(&matches)?;
+
     // Create an "app" struct, which will control most of the program and store settings/state
     let mut app = init_app(
         matches,
@@ -79,6 +84,11 @@ fn main() -> Result<()> {
         &styling,
     )?;
 
+    // If we're only printing data, then we don't want to touch the terminal at all.
+    if let Some(output_mode) = output_mode {
+        return output::run(output_mode, &app);
+    }
+
     // Create painter and set colours.
     let mut painter = canvas::Painter::init(widget_layout, styling)?;
 
@@ -92,17 +102,32 @@ fn main() -> Result<()> {
 
     let (sender, receiver) = mpsc::channel();
 
-    // Set up the event loop thread; we set this up early to speed up first-time-to-data.
+    // Set up the event loop thread; we set this up early to speed up first-time-to-data. When
+    // replaying, recorded data is sent instead.
     let (collection_thread_ctrl_sender, collection_thread_ctrl_receiver) = mpsc::channel();
-    let _collection_thread = create_collection_thread(
-        sender.clone(),
-        collection_thread_ctrl_receiver,
-        termination_lock.clone(),
-        termination_cvar.clone(),
-        &app.app_config_fields,
-        app.filters.clone(),
-        app.used_widgets,
-    );
+    let _collection_thread = if let Some(session) = replay_session {
+        app.replay_state = Some(ReplayState::new(&session));
+
+        // Recorded alerts shouldn't cause anything to happen on this system.
+        app.alerts.disable_commands();
+        create_replay_thread(
+            sender.clone(),
+            collection_thread_ctrl_receiver,
+            termination_lock.clone(),
+            session,
+            app.app_config_fields.retention_ms,
+        )
+    } else {
+        create_collection_thread(
+            sender.clone(),
+            collection_thread_ctrl_receiver,
+            termination_lock.clone(),
+            termination_cvar.clone(),
+            &app.app_config_fields,
+            app.filters.clone(),
+            app.used_widgets,
+        )
+    };
 
     // Set up the input handling loop thread.
     let _input_thread = create_input_thread(sender.clone(), termination_lock.clone());
@@ -167,6 +192,7 @@ fn main() -> Result<()> {
     })?;
 
     let mut first_run = true;
+    let mut recording_error = None;
 
     // Draw once first to initialize the canvas, so it doesn't feel like it's frozen.
     try_drawing(&mut terminal, &mut app, &mut painter)?;
@@ -198,7 +224,20 @@ fn main() -> Result<()> {
                     try_drawing(&mut terminal, &mut app, &mut painter)?;
                 }
                 BottomEvent::Update(data) => {
+                    #[cfg(feature = "exporter")]
+                    if let Some(exporter) = &app.exporter {
+                        exporter.update(&data);
+                    }
+
+                    if let Some(recorder) = &mut app.recorder {
+                        if let Err(err) = recorder.record(&data) {
+                            recording_error = Some(err);
+                            break;
+                        }
+                    }
+
                     app.data_collection.eat_data(data);
+                    app.alerts.evaluate(&app.data_collection);
 
                     // This thing is required as otherwise, some widgets can't draw correctly w/o
                     // some data (or they need to be re-drawn).
@@ -214,6 +253,7 @@ fn main() -> Result<()> {
                         if app.used_widgets.use_net {
                             let network_data = convert_network_data_points(
                                 &app.data_collection,
+                                app.states.net_state.selected_interface.as_deref(),
                                 app.app_config_fields.use_basic_mode
                                     || app.app_config_fields.use_old_network_legend,
                                 &app.app_config_fields.network_scale_type,
@@ -230,6 +270,16 @@ fn main() -> Result<()> {
                             if let Some(total_tx_display) = network_data.total_tx_display {
                                 app.converted_data.total_tx_display = total_tx_display;
                             }
+
+                            app.converted_data.ingest_net_interface_data(
+                                &app.data_collection,
+                                &app.app_config_fields.network_unit_type,
+                                app.app_config_fields.network_use_binary_prefix,
+                            );
+
+                            for net_table in app.states.net_table_state.widget_states.values_mut() {
+                                net_table.force_data_update();
+                            }
                         }
 
                         // Disk
@@ -241,11 +291,29 @@ fn main() -> Result<()> {
                             }
                         }
 
+                        // Disk I/O
+                        if app.used_widgets.use_disk_io {
+                            app.converted_data.disk_io_data = convert_disk_io_data_points(
+                                &app.data_collection,
+                                app.states.disk_io_state.selected_disk.as_deref(),
+                            );
+                        }
+
+                        // Cgroups
+                        if app.used_widgets.use_cgroup {
+                            app.converted_data.ingest_cgroup_data(&app.data_collection);
+
+                            for cgroup in app.states.cgroup_state.widget_states.values_mut() {
+                                cgroup.force_data_update();
+                            }
+                        }
+
                         // Temperatures
                         if app.used_widgets.use_temp {
                             app.converted_data.ingest_temp_data(
                                 &app.data_collection,
                                 app.app_config_fields.temperature_type,
+                                app.app_config_fields.enable_hwmon_sensors,
                             );
 
                             for temp in app.states.temp_state.widget_states.values_mut() {
@@ -264,6 +332,14 @@ fn main() -> Result<()> {
                             }
                             app.converted_data.swap_data =
                                 convert_swap_data_points(&app.data_collection);
+                            app.converted_data.ingest_paging_data(&app.data_collection);
+                            app.converted_data
+                                .ingest_memory_extras_data(&app.data_collection);
+                            app.converted_data
+                                .ingest_mem_details_data(&app.data_collection);
+                            for mem_table in app.states.mem_table_state.widget_states.values_mut() {
+                                mem_table.force_data_update();
+                            }
                             #[cfg(feature = "zfs")]
                             {
                                 app.converted_data.arc_data =
@@ -293,6 +369,23 @@ fn main() -> Result<()> {
                             }
                         }
 
+                        // Pressure
+                        if app.used_widgets.use_pressure {
+                            app.converted_data.pressure = app.data_collection.pressure_harvest;
+                            app.converted_data.cpu_pressure_data =
+                                convert_timed_data_points(&app.data_collection, |data| {
+                                    data.pressure_data.cpu.map(|cpu| cpu.some.avg10)
+                                });
+                            app.converted_data.mem_pressure_data =
+                                convert_timed_data_points(&app.data_collection, |data| {
+                                    data.pressure_data.memory.map(|memory| memory.some.avg10)
+                                });
+                            app.converted_data.io_pressure_data =
+                                convert_timed_data_points(&app.data_collection, |data| {
+                                    data.pressure_data.io.map(|io| io.some.avg10)
+                                });
+                        }
+
                         // CPU
                         if app.used_widgets.use_cpu {
                             app.converted_data.ingest_cpu_data(&app.data_collection);
@@ -319,6 +412,19 @@ fn main() -> Result<()> {
                         try_drawing(&mut terminal, &mut app, &mut painter)?;
                     }
                 }
+                BottomEvent::Replay(event) => match event {
+                    ReplayEvent::Seeked(history) => {
+                        app.data_collection.reset();
+                        for data in history {
+                            app.data_collection.eat_data(Box::new(data));
+                        }
+                    }
+                    ReplayEvent::Position(position) => {
+                        if let Some(replay_state) = &mut app.replay_state {
+                            replay_state.position = position;
+                        }
+                    }
+                },
                 BottomEvent::Clean => {
                     app.data_collection
                         .clean_data(app.app_config_fields.retention_ms);
@@ -332,5 +438,9 @@ fn main() -> Result<()> {
     termination_cvar.notify_all();
     cleanup_terminal(&mut terminal)?;
 
+    if let Some(err) = recording_error {
+        return Err(err).context("Stopped early, as the recording could not be written.");
+    }
+
     Ok(())
 }
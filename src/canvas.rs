@@ -4,14 +4,14 @@ mod drawing_utils;
 pub mod styling;
 mod widgets;
 
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
-use itertools::izip;
+use itertools::{izip, Itertools};
 use styling::*;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    text::Span,
+    text::{Line, Span},
     widgets::Paragraph,
     Frame, Terminal,
 };
@@ -189,12 +189,50 @@ impl Painter {
         }
     }
 
-    fn draw_frozen_indicator(&self, f: &mut Frame<'_>, draw_loc: Rect) {
-        f.render_widget(
-            Paragraph::new(Span::styled(
-                "Frozen, press 'f' to unfreeze",
+    /// Draws a line showing any firing alerts, and whether the data is frozen and/or being
+    /// replayed.
+    fn draw_status_indicator(&self, f: &mut Frame<'_>, app_state: &App, draw_loc: Rect) {
+        let mut spans = app_state
+            .alerts
+            .banners()
+            .map(|banner| Span::styled(banner, self.colours.alert_style))
+            .collect::<Vec<_>>();
+        let mut statuses = Vec::with_capacity(2);
+
+        if let Some(replay_state) = &app_state.replay_state {
+            let playback = if replay_state.is_finished() {
+                "finished"
+            } else if replay_state.is_paused {
+                "paused"
+            } else {
+                "playing"
+            };
+
+            statuses.push(format!(
+                "Replay {} / {}, {playback} at {}x (space to pause, [ ] to seek, < > for speed)",
+                format_replay_time(replay_state.position),
+                format_replay_time(replay_state.duration),
+                replay_state.speed(),
+            ));
+        }
+
+        if app_state.frozen_state.is_frozen() {
+            statuses.push("Frozen, press 'f' to unfreeze".to_string());
+        }
+
+        if !statuses.is_empty() {
+            spans.push(Span::styled(
+                statuses.join(" | "),
                 self.colours.currently_selected_text_style,
-            )),
+            ));
+        }
+
+        let separator = Span::raw(" | ");
+        let line =
+            Line::from(Itertools::intersperse(spans.into_iter(), separator).collect::<Vec<_>>());
+
+        f.render_widget(
+            Paragraph::new(line),
             Layout::default()
                 .horizontal_margin(1)
                 .constraints([Constraint::Length(1)])
@@ -202,13 +240,42 @@ impl Painter {
         )
     }
 
+    /// Recolours the border of a widget, to show that an alert for something it shows is firing.
+    fn draw_alert_border(&self, f: &mut Frame<'_>, draw_loc: Rect) {
+        let buffer = f.buffer_mut();
+        let area = draw_loc.intersection(buffer.area);
+        if area.is_empty() {
+            return;
+        }
+
+        for x in area.left()..area.right() {
+            buffer
+                .get_mut(x, area.top())
+                .set_style(self.colours.alert_style);
+            buffer
+                .get_mut(x, area.bottom() - 1)
+                .set_style(self.colours.alert_style);
+        }
+        for y in area.top()..area.bottom() {
+            buffer
+                .get_mut(area.left(), y)
+                .set_style(self.colours.alert_style);
+            buffer
+                .get_mut(area.right() - 1, y)
+                .set_style(self.colours.alert_style);
+        }
+    }
+
     pub fn draw_data<B: Backend>(
         &mut self, terminal: &mut Terminal<B>, app_state: &mut App,
     ) -> error::Result<()> {
         use BottomWidgetType::*;
 
         terminal.draw(|f| {
-            let (terminal_size, frozen_draw_loc) = if app_state.frozen_state.is_frozen() {
+            let (terminal_size, status_draw_loc) = if app_state.frozen_state.is_frozen()
+                || app_state.replay_state.is_some()
+                || app_state.alerts.banners().next().is_some()
+            {
                 // TODO: Remove built-in cache?
                 let split_loc = Layout::default()
                     .constraints([Constraint::Min(0), Constraint::Length(1)])
@@ -276,6 +343,35 @@ impl Painter {
                     .split(vertical_dialog_chunk[1]);
 
                 self.draw_help_dialog(f, app_state, middle_dialog_chunk[1]);
+            } else if app_state.process_details_state.is_showing_details {
+                let vertical_bordering = terminal_height / 10;
+                let vertical_dialog_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(vertical_bordering),
+                        Constraint::Min(0),
+                        Constraint::Length(vertical_bordering),
+                    ])
+                    .split(terminal_size);
+
+                let middle_dialog_chunk = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(if terminal_width < 100 {
+                        [
+                            Constraint::Percentage(0),
+                            Constraint::Percentage(100),
+                            Constraint::Percentage(0),
+                        ]
+                    } else {
+                        [
+                            Constraint::Percentage(10),
+                            Constraint::Percentage(80),
+                            Constraint::Percentage(10),
+                        ]
+                    })
+                    .split(vertical_dialog_chunk[1]);
+
+                self.draw_process_details_dialog(f, app_state, middle_dialog_chunk[1]);
             } else if app_state.delete_dialog_state.is_showing_dd {
                 let dd_text = self.get_dd_spans(app_state);
 
@@ -317,8 +413,8 @@ impl Painter {
                 app_state.delete_dialog_state.is_showing_dd =
                     self.draw_dd_dialog(f, dd_text, app_state, middle_dialog_chunk[1]);
             } else if app_state.is_expanded {
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
                 let rect = Layout::default()
@@ -327,6 +423,12 @@ impl Painter {
                     .split(terminal_size);
                 match &app_state.current_widget.widget_type {
                     Cpu => self.draw_cpu(f, app_state, rect[0], app_state.current_widget.widget_id),
+                    CpuHeatmap => self.draw_cpu_heatmap(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     CpuLegend => self.draw_cpu(
                         f,
                         app_state,
@@ -345,6 +447,36 @@ impl Painter {
                         rect[0],
                         app_state.current_widget.widget_id,
                     ),
+                    NetTable => self.draw_network_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    MemTable => self.draw_mem_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    Cgroup => self.draw_cgroup_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    Pressure => self.draw_pressure_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    DiskIo => self.draw_disk_io_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     Temp => self.draw_temp_table(
                         f,
                         app_state,
@@ -377,14 +509,23 @@ impl Painter {
                     ),
                     _ => {}
                 }
+
+                if app_state
+                    .alerts
+                    .is_highlighted(&app_state.current_widget.widget_type)
+                {
+                    self.draw_alert_border(f, rect[0]);
+                }
             } else if app_state.app_config_fields.use_basic_mode {
                 // Basic mode.  This basically removes all graphs but otherwise
                 // the same info.
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
-                let actual_cpu_data_len = app_state.converted_data.cpu_data.len().saturating_sub(1);
+                // The CPU pressure is shown as an extra gauge after the CPUs.
+                let actual_cpu_data_len = app_state.converted_data.cpu_data.len().saturating_sub(1)
+                    + usize::from(app_state.converted_data.pressure.cpu.is_some());
 
                 // This fixes #397, apparently if the height is 1, it can't render the CPU bars...
                 let cpu_height = {
@@ -404,6 +545,10 @@ impl Painter {
                     mem_rows += 1; // add row for swap
                 }
 
+                if app_state.converted_data.pressure.memory.is_some() {
+                    mem_rows += 1;
+                }
+
                 #[cfg(feature = "zfs")]
                 {
                     if app_state.converted_data.arc_labels.is_some() {
@@ -494,8 +639,8 @@ impl Painter {
                 }
             } else {
                 // Draws using the passed in (or default) layout.
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
                 if self.derived_widget_draw_locs.is_empty() || app_state.is_force_redraw {
@@ -752,15 +897,40 @@ impl Painter {
             if draw_loc.width >= 2 && draw_loc.height >= 2 {
                 match &widget.widget_type {
                     Cpu => self.draw_cpu(f, app_state, *draw_loc, widget.widget_id),
+                    CpuHeatmap => self.draw_cpu_heatmap(f, app_state, *draw_loc, widget.widget_id),
                     Mem => self.draw_memory_graph(f, app_state, *draw_loc, widget.widget_id),
                     Net => self.draw_network(f, app_state, *draw_loc, widget.widget_id),
+                    NetTable => self.draw_network_table(f, app_state, *draw_loc, widget.widget_id),
                     Temp => self.draw_temp_table(f, app_state, *draw_loc, widget.widget_id),
                     Disk => self.draw_disk_table(f, app_state, *draw_loc, widget.widget_id),
+                    MemTable => self.draw_mem_table(f, app_state, *draw_loc, widget.widget_id),
+                    Cgroup => self.draw_cgroup_table(f, app_state, *draw_loc, widget.widget_id),
+                    Pressure => self.draw_pressure_graph(f, app_state, *draw_loc, widget.widget_id),
+                    DiskIo => self.draw_disk_io_graph(f, app_state, *draw_loc, widget.widget_id),
                     Proc => self.draw_process(f, app_state, *draw_loc, true, widget.widget_id),
                     Battery => self.draw_battery(f, app_state, *draw_loc, true, widget.widget_id),
                     _ => {}
                 }
+
+                if app_state.alerts.is_highlighted(&widget.widget_type) {
+                    self.draw_alert_border(f, *draw_loc);
+                }
             }
         }
     }
 }
+
+/// Formats a position in a replay as `m:ss`, or `h:mm:ss` if it is long enough.
+fn format_replay_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -1,6 +1,7 @@
 //! Lower-level components used throughout bottom.
 
 pub mod data_table;
+pub mod search_bar;
 pub mod time_graph;
 mod tui_widget;
 pub mod widget_carousel;
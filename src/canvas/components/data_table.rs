@@ -144,6 +144,11 @@ impl<DataType: DataToCell<H>, H: ColumnHeader, S: SortType, C: DataTableColumn<H
         self.data.get(self.state.current_index)
     }
 
+    /// Returns all of the data currently in the table, in display order.
+    pub fn data(&self) -> &[DataType] {
+        &self.data
+    }
+
     /// Returns ratatui's internal selection.
     pub fn ratatui_selected(&self) -> Option<usize> {
         self.state.table_state.selected()
@@ -1,2 +1,3 @@
 pub mod dd_dialog;
 pub mod help_dialog;
+pub mod process_details_dialog;
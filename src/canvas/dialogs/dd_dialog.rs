@@ -11,7 +11,7 @@ use tui::{
 use crate::{
     app::{App, KillSignal, MAX_PROCESS_SIGNAL},
     canvas::Painter,
-    widgets::ProcWidgetMode,
+    widgets::{ProcGrouping, ProcWidgetMode},
 };
 
 const DD_BASE: &str = " Confirm Kill Process ── Esc to close ";
@@ -172,26 +172,38 @@ impl Painter {
             if let Some(first_pid) = to_kill_processes.1.first() {
                 return Some(Text::from(vec![
                     Line::from(""),
-                    if app_state
+                    if let Some(grouping) = app_state
                         .states
                         .proc_state
                         .widget_states
                         .get(&app_state.current_widget.widget_id)
-                        .map(|p| matches!(p.mode, ProcWidgetMode::Grouped))
-                        .unwrap_or(false)
+                        .and_then(|p| match p.mode {
+                            ProcWidgetMode::Grouped(grouping) => Some(grouping),
+                            _ => None,
+                        })
                     {
+                        let group = match grouping {
+                            ProcGrouping::Name => "with the name",
+                            ProcGrouping::Cgroup => "in",
+                        };
+
                         if to_kill_processes.1.len() != 1 {
                             Line::from(format!(
-                                "Kill {} processes with the name \"{}\"?  Press ENTER to confirm.",
+                                "Kill {} processes {group} \"{}\"?  Press ENTER to confirm.",
                                 to_kill_processes.1.len(),
                                 to_kill_processes.0
                             ))
                         } else {
                             Line::from(format!(
-                                "Kill 1 process with the name \"{}\"?  Press ENTER to confirm.",
+                                "Kill 1 process {group} \"{}\"?  Press ENTER to confirm.",
                                 to_kill_processes.0
                             ))
                         }
+                    } else if let Some(thread) = app_state.delete_dialog_state.thread {
+                        Line::from(format!(
+                            "Kill process \"{}\" with PID {}, which thread {} belongs to?  Press ENTER to confirm.",
+                            to_kill_processes.0, first_pid, thread
+                        ))
                     } else {
                         Line::from(format!(
                             "Kill process \"{}\" with PID {}?  Press ENTER to confirm.",
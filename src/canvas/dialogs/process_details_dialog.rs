@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+use tui::{
+    layout::{Alignment, Rect},
+    terminal::Frame,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::{
+    app::App, canvas::Painter, data_collection::processes::details::ProcessDetails,
+    data_conversion::binary_byte_string, utils::general::str_width,
+};
+
+/// The part of the title after the process name, not including the padding in between.
+const TITLE_END: &str = "── r to refresh, Esc to close ";
+
+/// The width of the labels of single-line fields.
+const LABEL_WIDTH: usize = 13;
+
+/// Formats a Unix timestamp as a date and time in UTC.
+fn format_timestamp(timestamp: u64) -> String {
+    time::OffsetDateTime::from_unix_timestamp(timestamp as i64)
+        .ok()
+        .and_then(|time| {
+            time.format(time::macros::format_description!(
+                "[year]-[month]-[day] [hour]:[minute]:[second] UTC"
+            ))
+            .ok()
+        })
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+impl Painter {
+    fn field_line(&self, label: &str, value: impl Display) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(
+                format!("{label:LABEL_WIDTH$}"),
+                self.colours.table_header_style,
+            ),
+            Span::styled(value.to_string(), self.colours.text_style),
+        ])
+    }
+
+    /// Adds a section with a header and a line per item, or the reason the section couldn't be
+    /// read.
+    fn push_section<T>(
+        &self, lines: &mut Vec<Line<'static>>, title: &str, items: &anyhow::Result<Vec<T>>,
+        to_line: impl Fn(&T) -> String,
+    ) {
+        lines.push(Line::default());
+
+        match items {
+            Ok(items) => {
+                lines.push(Line::from(Span::styled(
+                    format!("{title} ({})", items.len()),
+                    self.colours.table_header_style,
+                )));
+                lines.extend(items.iter().map(|item| {
+                    Line::styled(format!("  {}", to_line(item)), self.colours.text_style)
+                }));
+            }
+            Err(err) => {
+                lines.push(Line::from(Span::styled(
+                    title.to_string(),
+                    self.colours.table_header_style,
+                )));
+                lines.push(Line::styled(
+                    format!("  Unavailable: {err}"),
+                    self.colours.invalid_query_style,
+                ));
+            }
+        }
+    }
+
+    fn process_details_lines(&self, details: &ProcessDetails) -> Vec<Line<'static>> {
+        let unavailable = |err: &anyhow::Error| format!("Unavailable: {err}");
+
+        let mut lines = vec![
+            self.field_line("Command", details.command.join(" ")),
+            self.field_line(
+                "Executable",
+                details
+                    .exe
+                    .as_ref()
+                    .map_or_else(unavailable, |exe| exe.display().to_string()),
+            ),
+            self.field_line(
+                "Directory",
+                details
+                    .cwd
+                    .as_ref()
+                    .map_or_else(unavailable, |cwd| cwd.display().to_string()),
+            ),
+            self.field_line(
+                "Started",
+                details
+                    .start_time
+                    .map_or_else(|| "Unknown".to_string(), format_timestamp),
+            ),
+            self.field_line(
+                "Parents",
+                details
+                    .parents
+                    .iter()
+                    .map(|(pid, name)| format!("{name} ({pid})"))
+                    .collect::<Vec<_>>()
+                    .join(" → "),
+            ),
+        ];
+
+        self.push_section(&mut lines, "Threads", &details.threads, |thread| {
+            format!("{:<8} {} {}", thread.tid, thread.state, thread.name)
+        });
+        self.push_section(&mut lines, "Open files", &details.open_files, |file| {
+            format!("{:<5} {}", file.fd, file.target)
+        });
+        self.push_section(&mut lines, "Memory maps", &details.memory_maps, |mapping| {
+            format!(
+                "{:>8} {:>5} {}",
+                binary_byte_string(mapping.size),
+                mapping.count,
+                mapping.name
+            )
+        });
+        self.push_section(&mut lines, "Cgroups", &details.cgroups, |cgroup| {
+            cgroup.clone()
+        });
+        self.push_section(
+            &mut lines,
+            "Namespaces",
+            &details.namespaces,
+            |(name, id)| format!("{name:<8} {id}"),
+        );
+        self.push_section(&mut lines, "Limits", &details.limits, |limit| {
+            format!(
+                "{:<26}{:<21}{:<21}{}",
+                limit.name, limit.soft, limit.hard, limit.units
+            )
+        });
+        self.push_section(&mut lines, "Environment", &details.environment, |var| {
+            var.clone()
+        });
+
+        lines
+    }
+
+    pub fn draw_process_details_dialog(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let state = &mut app_state.process_details_state;
+
+        let (name, lines) = match &state.details {
+            Some(Ok(details)) => (
+                format!(" {} ({}) ", details.name, details.pid),
+                self.process_details_lines(details),
+            ),
+            Some(Err(err)) => (
+                format!(" Process {} ", state.pid),
+                vec![Line::styled(
+                    err.to_string(),
+                    self.colours.invalid_query_style,
+                )],
+            ),
+            None => (format!(" Process {} ", state.pid), vec![]),
+        };
+
+        let title = Line::from(vec![
+            Span::styled(name.clone(), self.colours.widget_title_style),
+            Span::styled(
+                format!(
+                    "─{}─ r to refresh, Esc to close ",
+                    "─".repeat(
+                        usize::from(draw_loc.width)
+                            .saturating_sub(str_width(&name) + TITLE_END.chars().count() + 2)
+                    )
+                ),
+                self.colours.border_style,
+            ),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style);
+
+        // Work out how far we can scroll, taking into account lines that wrap. This can be
+        // slightly off as wrapping is done by word, but it's close enough.
+        let inner = block.inner(draw_loc);
+        let width = usize::from(inner.width.max(1));
+        let num_rows: usize = lines
+            .iter()
+            .map(|line| line.width().max(1).div_ceil(width))
+            .sum();
+
+        state.height = inner.height;
+        state.scroll_state.max_scroll_index = (num_rows as u16).saturating_sub(inner.height);
+        state.scroll_state.current_scroll_index = state
+            .scroll_state
+            .current_scroll_index
+            .min(state.scroll_state.max_scroll_index);
+
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .style(self.colours.text_style)
+                .alignment(Alignment::Left)
+                .wrap(Wrap { trim: false })
+                .scroll((state.scroll_state.current_scroll_index, 0)),
+            draw_loc,
+        );
+    }
+}
@@ -30,11 +30,40 @@ pub fn should_hide_x_label(
     }
 }
 
+/// Returns the upper bound of a graph with no fixed maximum, rounded up to 1, 2, or 5 times a
+/// power of ten. The bound is never below `min`, so that a mostly idle graph doesn't make small
+/// values look like a spike.
+pub fn round_graph_max(max_entry: f64, min: f64) -> f64 {
+    let mut magnitude = min;
+    loop {
+        if let Some(y_max) = [1.0, 2.0, 5.0]
+            .into_iter()
+            .map(|step| step * magnitude)
+            .find(|y_max| max_entry <= *y_max)
+        {
+            return y_max;
+        }
+
+        magnitude *= 10.0;
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
 
+    #[test]
+    fn test_round_graph_max() {
+        assert_eq!(round_graph_max(0.0, 10.0), 10.0);
+        assert_eq!(round_graph_max(10.0, 10.0), 10.0);
+        assert_eq!(round_graph_max(10.5, 10.0), 20.0);
+        assert_eq!(round_graph_max(350.0, 10.0), 500.0);
+        assert_eq!(round_graph_max(5001.0, 10.0), 10000.0);
+        assert_eq!(round_graph_max(0.0, 1000.0), 1000.0);
+        assert_eq!(round_graph_max(1500.0, 1000.0), 2000.0);
+    }
+
     #[test]
     fn test_calculate_basic_use_bars() {
         // Testing various breakpoints and edge cases.
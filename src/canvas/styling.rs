@@ -18,6 +18,9 @@ pub struct CanvasStyling {
     pub cache_style: Style,
     pub swap_style: Style,
     pub arc_style: Style,
+    pub arc_hit_style: Style,
+    pub zram_style: Style,
+    pub zswap_style: Style,
     pub gpu_colour_styles: Vec<Style>,
     pub rx_style: Style,
     pub tx_style: Style,
@@ -26,6 +29,8 @@ pub struct CanvasStyling {
     pub all_colour_style: Style,
     pub avg_colour_style: Style,
     pub cpu_colour_styles: Vec<Style>,
+    /// The stops of the gradient used by the CPU heatmap, from 0% to 100% usage.
+    pub cpu_heatmap_colours: Vec<Color>,
     pub border_style: Style,
     pub highlighted_border_style: Style,
     pub text_style: Style,
@@ -35,6 +40,7 @@ pub struct CanvasStyling {
     pub medium_battery_colour: Style,
     pub low_battery_colour: Style,
     pub invalid_query_style: Style,
+    pub alert_style: Style,
     pub disabled_text_style: Style,
 }
 
@@ -56,6 +62,9 @@ impl Default for CanvasStyling {
             cache_style: Style::default().fg(FIFTH_COLOUR),
             swap_style: Style::default().fg(SECOND_COLOUR),
             arc_style: Style::default().fg(THIRD_COLOUR),
+            arc_hit_style: Style::default().fg(Color::Green),
+            zram_style: Style::default().fg(FOURTH_COLOUR),
+            zswap_style: Style::default().fg(Color::Cyan),
             gpu_colour_styles: vec![
                 Style::default().fg(FOURTH_COLOUR),
                 Style::default().fg(Color::LightBlue),
@@ -81,6 +90,14 @@ impl Default for CanvasStyling {
                 Style::default().fg(Color::Green),
                 Style::default().fg(Color::Blue),
             ],
+            // These are RGB rather than named colours so that the heatmap can blend between them.
+            cpu_heatmap_colours: vec![
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(0, 95, 215),
+                Color::Rgb(0, 175, 95),
+                Color::Rgb(215, 175, 0),
+                Color::Rgb(215, 0, 0),
+            ],
             border_style: Style::default().fg(text_colour),
             highlighted_border_style: Style::default().fg(HIGHLIGHT_COLOUR),
             text_style: Style::default().fg(text_colour),
@@ -90,6 +107,7 @@ impl Default for CanvasStyling {
             medium_battery_colour: Style::default().fg(Color::Yellow),
             low_battery_colour: Style::default().fg(Color::Red),
             invalid_query_style: Style::default().fg(Color::Red),
+            alert_style: Style::default().fg(Color::Red),
             disabled_text_style: Style::default().fg(Color::DarkGray),
         }
     }
@@ -123,6 +141,38 @@ macro_rules! try_set_colour_list {
     };
 }
 
+/// Returns the colour at `position`, from 0.0 to 1.0, along a gradient with evenly spaced `stops`.
+/// Only RGB colours can be blended, so if either neighbouring stop is not one, the nearest stop is
+/// used instead.
+pub fn gradient_colour(stops: &[Color], position: f64) -> Color {
+    match stops {
+        [] => Color::Reset,
+        [only] => *only,
+        _ => {
+            let scaled = position.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+            let index = (scaled.floor() as usize).min(stops.len() - 2);
+            let offset = scaled - index as f64;
+
+            match (stops[index], stops[index + 1]) {
+                (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                    let blend = |low: u8, high: u8| {
+                        (f64::from(low) + (f64::from(high) - f64::from(low)) * offset).round() as u8
+                    };
+
+                    Color::Rgb(blend(r1, r2), blend(g1, g2), blend(b1, b2))
+                }
+                (low, high) => {
+                    if offset < 0.5 {
+                        low
+                    } else {
+                        high
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl CanvasStyling {
     pub fn new(colour_scheme: ColourScheme, config: &Config) -> anyhow::Result<Self> {
         let mut canvas_colours = Self::default();
@@ -159,13 +209,33 @@ impl CanvasStyling {
         try_set_colour!(self.avg_colour_style, colours, avg_cpu_color);
         try_set_colour!(self.all_colour_style, colours, all_cpu_color);
         try_set_colour_list!(self.cpu_colour_styles, colours, cpu_core_colors);
+        if let Some(heatmap_colours) = &colours.cpu_heatmap_colors {
+            if heatmap_colours.is_empty() {
+                return Err(error::BottomError::ConfigError(
+                    "at least one colour is needed".to_string(),
+                ))
+                .context("update 'cpu_heatmap_colors' in your config file");
+            }
+
+            self.cpu_heatmap_colours = heatmap_colours
+                .iter()
+                .map(|s| str_to_colour(s))
+                .collect::<error::Result<Vec<Color>>>()
+                .context("update 'cpu_heatmap_colors' in your config file")?;
+        }
 
         // Memory
         #[cfg(not(target_os = "windows"))]
         try_set_colour!(self.cache_style, colours, cache_color);
 
         #[cfg(feature = "zfs")]
-        try_set_colour!(self.arc_style, colours, arc_color);
+        {
+            try_set_colour!(self.arc_style, colours, arc_color);
+            try_set_colour!(self.arc_hit_style, colours, arc_hit_color);
+        }
+
+        try_set_colour!(self.zram_style, colours, zram_color);
+        try_set_colour!(self.zswap_style, colours, zswap_color);
 
         #[cfg(feature = "gpu")]
         try_set_colour_list!(self.gpu_colour_styles, colours, gpu_core_colors);
@@ -235,7 +305,7 @@ impl CanvasStyling {
 mod test {
     use tui::style::{Color, Style};
 
-    use super::{CanvasStyling, ColourScheme};
+    use super::{gradient_colour, CanvasStyling, ColourScheme};
     use crate::options::Config;
 
     #[test]
@@ -290,4 +360,39 @@ mod test {
         CanvasStyling::new(ColourScheme::Nord, &config).unwrap();
         CanvasStyling::new(ColourScheme::NordLight, &config).unwrap();
     }
+
+    #[test]
+    fn gradient_colours() {
+        let rgb = [Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)];
+        assert_eq!(gradient_colour(&rgb, 0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient_colour(&rgb, 0.5), Color::Rgb(100, 50, 0));
+        assert_eq!(gradient_colour(&rgb, 1.0), Color::Rgb(200, 100, 0));
+        assert_eq!(gradient_colour(&rgb, 1.5), Color::Rgb(200, 100, 0));
+
+        let named = [Color::Reset, Color::Blue, Color::Red];
+        assert_eq!(gradient_colour(&named, 0.2), Color::Reset);
+        assert_eq!(gradient_colour(&named, 0.3), Color::Blue);
+        assert_eq!(gradient_colour(&named, 0.9), Color::Red);
+
+        assert_eq!(gradient_colour(&[Color::Green], 0.7), Color::Green);
+    }
+
+    #[test]
+    fn default_heatmap_colours_blend() {
+        let config = Config::default();
+        for scheme in [ColourScheme::Default, ColourScheme::DefaultLight] {
+            let name = format!("{scheme:?}");
+            let stops = CanvasStyling::new(scheme, &config)
+                .unwrap()
+                .cpu_heatmap_colours;
+            assert!(stops.iter().all(|stop| matches!(stop, Color::Rgb(..))));
+
+            // Halfway between two stops is a blend of both, rather than either of them.
+            let step = 1.0 / (stops.len() - 1) as f64;
+            for (index, pair) in stops.windows(2).enumerate() {
+                let between = gradient_colour(&stops, step * (index as f64 + 0.5));
+                assert!(!pair.contains(&between), "{name} does not blend at {index}");
+            }
+        }
+    }
 }
@@ -1,10 +1,16 @@
 pub mod battery_display;
+pub mod cgroup_table;
 pub mod cpu_basic;
 pub mod cpu_graph;
+pub mod cpu_heatmap;
+pub mod disk_io_graph;
 pub mod disk_table;
 pub mod mem_basic;
 pub mod mem_graph;
+pub mod mem_table;
 pub mod network_basic;
 pub mod network_graph;
+pub mod network_table;
+pub mod pressure_graph;
 pub mod process_table;
 pub mod temperature_table;
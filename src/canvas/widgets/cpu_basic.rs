@@ -56,12 +56,17 @@ impl Painter {
                     .direction(Direction::Horizontal)
                     .split(draw_loc);
 
-                let mut gauge_info = cpu_data.iter().map(|cpu| match cpu {
+                let gauge_info = cpu_data.iter().map(|cpu| match cpu {
                     CpuWidgetData::All => unreachable!(),
                     CpuWidgetData::Entry {
                         data_type,
                         data: _,
                         last_entry,
+                        breakdown_data: _,
+                        last_breakdown: _,
+                        frequency_data: _,
+                        last_frequency: _,
+                        topology: _,
                     } => {
                         let (outer, style) = match data_type {
                             CpuDataType::Avg => ("AVG".to_string(), self.colours.avg_colour_style),
@@ -78,6 +83,17 @@ impl Painter {
                     }
                 });
 
+                // Pressure is shown after the CPUs, as the share of time that tasks were stalled.
+                let pressure = app_state.converted_data.pressure.cpu.map(|pressure| {
+                    (
+                        "PSI".to_string(),
+                        format!("{:.1}%", pressure.some.avg10),
+                        pressure.some.avg10 / 100.0,
+                        self.colours.avg_colour_style,
+                    )
+                });
+                let mut gauge_info = gauge_info.chain(pressure);
+
                 // Very ugly way to sync the gauge limit across all gauges.
                 let hide_parts = columns
                     .first()
@@ -92,7 +108,8 @@ impl Painter {
                     })
                     .unwrap_or_default();
 
-                let num_entries = cpu_data.len();
+                let num_entries =
+                    cpu_data.len() + usize::from(app_state.converted_data.pressure.cpu.is_some());
                 let mut row_counter = num_entries;
                 for (itx, column) in columns.iter().enumerate() {
                     if REQUIRED_COLUMNS > itx {
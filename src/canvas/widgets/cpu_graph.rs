@@ -11,21 +11,79 @@ use crate::{
     canvas::{
         components::{
             data_table::{DrawInfo, SelectionState},
+            time_chart::{LegendPosition, Point},
             time_graph::{GraphData, TimeGraph},
         },
         drawing_utils::should_hide_x_label,
         Painter,
     },
-    data_conversion::CpuWidgetData,
-    widgets::CpuWidgetState,
+    data_collection::cpu::{CpuBreakdown, CpuDataType},
+    data_conversion::{frequency_string, CpuWidgetData},
+    widgets::{CpuGraphMode, CpuGroup, CpuLegendRow, CpuWidgetState},
 };
 
 const AVG_POSITION: usize = 1;
 const ALL_POSITION: usize = 0;
 
+/// The states drawn in a breakdown graph, from bottom to top. Idle time is left as the gap
+/// between the top line and 100%.
+const BREAKDOWN_STATES: [(&str, fn(&CpuBreakdown) -> f64); 8] = [
+    ("User", |b| b.user),
+    ("Nice", |b| b.nice),
+    ("System", |b| b.system),
+    ("IRQ", |b| b.irq),
+    ("SoftIRQ", |b| b.softirq),
+    ("IOWait", |b| b.iowait),
+    ("Steal", |b| b.steal),
+    ("Guest", |b| b.guest),
+];
+
+/// The frequency graph's upper bound is rounded up to a multiple of this, in MHz.
+const FREQUENCY_STEP_MHZ: f64 = 500.0;
+
+/// Returns what the selected legend row shows.
+fn selected_row(cpu_widget_state: &CpuWidgetState) -> CpuLegendRow {
+    let current_index = cpu_widget_state.table.state.current_index;
+
+    cpu_widget_state
+        .rows
+        .get(current_index)
+        .copied()
+        .unwrap_or(CpuLegendRow::Entry(current_index))
+}
+
+/// Stacks the states of a breakdown on top of each other, so each line is the sum of its state
+/// and all the states below it.
+fn stack_breakdown(breakdown_data: &[(f64, CpuBreakdown)]) -> Vec<Vec<Point>> {
+    let mut stacked = vec![Vec::with_capacity(breakdown_data.len()); BREAKDOWN_STATES.len()];
+    for (time, breakdown) in breakdown_data {
+        let mut total = 0.0;
+        for (line, (_, get_value)) in stacked.iter_mut().zip(BREAKDOWN_STATES) {
+            total += get_value(breakdown);
+            line.push((*time, total));
+        }
+    }
+
+    stacked
+}
+
 impl Painter {
     pub fn draw_cpu(&self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64) {
-        let legend_width = (draw_loc.width as f64 * 0.15) as u16;
+        let graph_mode = app_state
+            .states
+            .cpu_state
+            .widget_states
+            .get(&widget_id)
+            .map(|state| state.graph_mode)
+            .unwrap_or(CpuGraphMode::Usage);
+
+        // The legend needs to be wider to fit the extra columns of the other modes.
+        let legend_ratio = match graph_mode {
+            CpuGraphMode::Usage => 0.15,
+            CpuGraphMode::Breakdown => 0.45,
+            CpuGraphMode::Frequency => 0.4,
+        };
+        let legend_width = (draw_loc.width as f64 * legend_ratio) as u16;
 
         if legend_width < 6 {
             // Skip drawing legend
@@ -120,58 +178,113 @@ impl Painter {
     }
 
     fn generate_points<'a>(
-        &self, cpu_widget_state: &CpuWidgetState, cpu_data: &'a [CpuWidgetData], show_avg_cpu: bool,
+        &self, cpu_widget_state: &'a CpuWidgetState, cpu_data: &'a [CpuWidgetData],
+        show_avg_cpu: bool, show_frequency: bool,
     ) -> Vec<GraphData<'a>> {
         let show_avg_offset = if show_avg_cpu { AVG_POSITION } else { 0 };
+        let select_points = |data: &'a [Point], frequency_data: &'a [Point]| {
+            if show_frequency {
+                frequency_data
+            } else {
+                data
+            }
+        };
+        let group_graph_data = |index: usize, group: &'a CpuGroup| GraphData {
+            points: select_points(&group.data, &group.frequency_data),
+            style: self.colours.cpu_colour_styles[index % self.colours.cpu_colour_styles.len()],
+            name: None,
+        };
+
+        match selected_row(cpu_widget_state) {
+            CpuLegendRow::Entry(ALL_POSITION) if !cpu_widget_state.groups.is_empty() => {
+                // When grouped, "All" shows each group rather than each CPU.
+                let groups = cpu_widget_state
+                    .groups
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(itx, group)| group_graph_data(itx, group));
+
+                let avg = cpu_data.iter().find_map(|cpu| match cpu {
+                    CpuWidgetData::Entry {
+                        data_type: CpuDataType::Avg,
+                        data,
+                        frequency_data,
+                        ..
+                    } => Some(GraphData {
+                        points: select_points(data, frequency_data),
+                        style: self.colours.avg_colour_style,
+                        name: None,
+                    }),
+                    _ => None,
+                });
 
-        let current_scroll_position = cpu_widget_state.table.state.current_index;
-        if current_scroll_position == ALL_POSITION {
-            // This case ensures the other cases cannot have the position be equal to 0.
-            cpu_data
-                .iter()
-                .enumerate()
-                .rev()
-                .filter_map(|(itx, cpu)| {
-                    match &cpu {
-                        CpuWidgetData::All => None,
-                        CpuWidgetData::Entry { data, .. } => {
-                            let style = if show_avg_cpu && itx == AVG_POSITION {
-                                self.colours.avg_colour_style
-                            } else if itx == ALL_POSITION {
-                                self.colours.all_colour_style
-                            } else {
-                                let offset_position = itx - 1; // Because of the all position
-                                self.colours.cpu_colour_styles[(offset_position - show_avg_offset)
-                                    % self.colours.cpu_colour_styles.len()]
-                            };
-
-                            Some(GraphData {
-                                points: &data[..],
-                                style,
-                                name: None,
-                            })
+                groups.chain(avg).collect()
+            }
+            CpuLegendRow::Entry(ALL_POSITION) => {
+                // This case ensures the other cases cannot have the position be equal to 0.
+                cpu_data
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter_map(|(itx, cpu)| {
+                        match &cpu {
+                            CpuWidgetData::All => None,
+                            CpuWidgetData::Entry {
+                                data,
+                                frequency_data,
+                                ..
+                            } => {
+                                let style = if show_avg_cpu && itx == AVG_POSITION {
+                                    self.colours.avg_colour_style
+                                } else if itx == ALL_POSITION {
+                                    self.colours.all_colour_style
+                                } else {
+                                    let offset_position = itx - 1; // Because of the all position
+                                    self.colours.cpu_colour_styles[(offset_position
+                                        - show_avg_offset)
+                                        % self.colours.cpu_colour_styles.len()]
+                                };
+
+                                Some(GraphData {
+                                    points: select_points(data, frequency_data),
+                                    style,
+                                    name: None,
+                                })
+                            }
                         }
-                    }
-                })
-                .collect::<Vec<_>>()
-        } else if let Some(CpuWidgetData::Entry { data, .. }) =
-            cpu_data.get(current_scroll_position)
-        {
-            let style = if show_avg_cpu && current_scroll_position == AVG_POSITION {
-                self.colours.avg_colour_style
-            } else {
-                let offset_position = current_scroll_position - 1; // Because of the all position
-                self.colours.cpu_colour_styles
-                    [(offset_position - show_avg_offset) % self.colours.cpu_colour_styles.len()]
-            };
+                    })
+                    .collect::<Vec<_>>()
+            }
+            CpuLegendRow::Entry(current_position) => {
+                if let Some(CpuWidgetData::Entry {
+                    data,
+                    frequency_data,
+                    ..
+                }) = cpu_data.get(current_position)
+                {
+                    let style = if show_avg_cpu && current_position == AVG_POSITION {
+                        self.colours.avg_colour_style
+                    } else {
+                        let offset_position = current_position - 1; // Because of the all position
+                        self.colours.cpu_colour_styles[(offset_position - show_avg_offset)
+                            % self.colours.cpu_colour_styles.len()]
+                    };
 
-            vec![GraphData {
-                points: &data[..],
-                style,
-                name: None,
-            }]
-        } else {
-            vec![]
+                    vec![GraphData {
+                        points: select_points(data, frequency_data),
+                        style,
+                        name: None,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            CpuLegendRow::Group(index) => cpu_widget_state
+                .groups
+                .get(index)
+                .map(|group| vec![group_graph_data(index, group)])
+                .unwrap_or_default(),
         }
     }
 
@@ -193,11 +306,60 @@ impl Painter {
                 draw_loc,
             );
 
-            let points = self.generate_points(
-                cpu_widget_state,
-                cpu_data,
-                app_state.app_config_fields.show_average_cpu,
-            );
+            let cpu_widget_state = &*cpu_widget_state;
+            let stacked = if cpu_widget_state.graph_mode == CpuGraphMode::Breakdown {
+                match selected_row(cpu_widget_state) {
+                    CpuLegendRow::Entry(index) => match cpu_data.get(index) {
+                        Some(CpuWidgetData::Entry { breakdown_data, .. }) => {
+                            Some(stack_breakdown(breakdown_data))
+                        }
+                        _ => None,
+                    },
+                    CpuLegendRow::Group(index) => cpu_widget_state
+                        .groups
+                        .get(index)
+                        .map(|group| stack_breakdown(&group.breakdown_data)),
+                }
+            } else {
+                None
+            };
+
+            let points = match &stacked {
+                Some(stacked) => stacked
+                    .iter()
+                    .zip(BREAKDOWN_STATES)
+                    .enumerate()
+                    .rev()
+                    .map(|(itx, (points, (name, _)))| GraphData {
+                        points,
+                        style: self.colours.cpu_colour_styles
+                            [itx % self.colours.cpu_colour_styles.len()],
+                        name: Some(name.into()),
+                    })
+                    .collect(),
+                None => self.generate_points(
+                    cpu_widget_state,
+                    cpu_data,
+                    app_state.app_config_fields.show_average_cpu,
+                    cpu_widget_state.graph_mode == CpuGraphMode::Frequency,
+                ),
+            };
+
+            let (y_bounds, y_labels) = if cpu_widget_state.graph_mode == CpuGraphMode::Frequency {
+                // Scale to the highest frequency shown, rounded up so the line is not at the top.
+                let max_entry = points
+                    .iter()
+                    .flat_map(|graph_data| graph_data.points.iter().map(|(_, value)| *value))
+                    .fold(0.0, f64::max);
+                let y_max = ((max_entry / FREQUENCY_STEP_MHZ).floor() + 1.0) * FREQUENCY_STEP_MHZ;
+
+                let max_label = frequency_string(y_max as u64);
+                let min_label = format!("{:>width$}", "0MHz", width = max_label.len());
+
+                ([0.0, y_max], [Cow::Owned(min_label), Cow::Owned(max_label)])
+            } else {
+                (Y_BOUNDS, Y_LABELS)
+            };
 
             // TODO: Maybe hide load avg if too long? Or maybe the CPU part.
             let title = {
@@ -208,8 +370,11 @@ impl Painter {
                         "─ {:.2} {:.2} {:.2} ",
                         load_avg[0], load_avg[1], load_avg[2]
                     );
+                    let pressure_str = crate::canvas::widgets::pressure_graph::pressure_title(
+                        &app_state.converted_data.pressure.cpu,
+                    );
 
-                    concat_string::concat_string!(" CPU ", load_avg_str).into()
+                    concat_string::concat_string!(" CPU ", load_avg_str, pressure_str).into()
                 }
                 #[cfg(not(target_family = "unix"))]
                 {
@@ -226,15 +391,17 @@ impl Painter {
             TimeGraph {
                 x_bounds,
                 hide_x_labels,
-                y_bounds: Y_BOUNDS,
-                y_labels: &Y_LABELS,
+                y_bounds,
+                y_labels: &y_labels,
                 graph_style: self.colours.graph_style,
                 border_style,
                 title,
                 is_expanded: app_state.is_expanded,
                 title_style: self.colours.widget_title_style,
-                legend_position: None,
-                legend_constraints: None,
+                legend_position: stacked.as_ref().map(|_| LegendPosition::default()),
+                legend_constraints: stacked
+                    .as_ref()
+                    .map(|_| (Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
                 marker,
             }
             .draw_time_graph(f, draw_loc, &points);
@@ -255,9 +422,17 @@ impl Painter {
             cpu_widget_state.is_legend_hidden = false;
 
             let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let table_draw_loc = self.draw_table_search_bar(
+                f,
+                &mut cpu_widget_state.search,
+                draw_loc,
+                true,
+                is_on_widget,
+                app_state.is_force_redraw,
+            );
 
             let draw_info = DrawInfo {
-                loc: draw_loc,
+                loc: table_draw_loc,
                 force_redraw: app_state.is_force_redraw,
                 recalculate_column_widths,
                 selection_state: SelectionState::new(app_state.is_expanded, is_on_widget),
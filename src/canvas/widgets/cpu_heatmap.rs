@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+
+use tui::{
+    layout::Rect,
+    style::Style,
+    terminal::Frame,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    app::App,
+    canvas::{
+        components::time_chart::Point, drawing_utils::should_hide_x_label,
+        styling::gradient_colour, Painter,
+    },
+    data_collection::cpu::CpuDataType,
+    data_conversion::CpuWidgetData,
+    widgets::{CpuLegendRow, CpuWidgetState},
+};
+
+const TITLE: &str = " CPU Heatmap ";
+
+/// Returns the label and usage points of each row in the heatmap. These are the same as the
+/// rows in a CPU legend, except for "All".
+fn heatmap_rows<'a>(
+    cpu_widget_state: &'a CpuWidgetState, cpu_data: &'a [CpuWidgetData],
+) -> Vec<(Cow<'a, str>, &'a [Point])> {
+    cpu_widget_state
+        .rows
+        .iter()
+        .filter_map(|row| match row {
+            CpuLegendRow::Entry(index) => match cpu_data.get(*index)? {
+                CpuWidgetData::All => None,
+                CpuWidgetData::Entry {
+                    data_type, data, ..
+                } => {
+                    let label = match data_type {
+                        CpuDataType::Avg => Cow::Borrowed("AVG"),
+                        CpuDataType::Cpu(index) => Cow::Owned(format!("CPU{index}")),
+                    };
+
+                    Some((label, &data[..]))
+                }
+            },
+            CpuLegendRow::Group(index) => cpu_widget_state
+                .groups
+                .get(*index)
+                .map(|group| (Cow::Borrowed(group.name.as_str()), &group.data[..])),
+        })
+        .collect()
+}
+
+/// Splits the last `display_time` milliseconds into `width` equal buckets, and returns the
+/// average of the points in each. Buckets without any points take the value before them, so
+/// zooming in past the update rate does not leave gaps.
+fn bucket_values(points: &[Point], display_time: f64, width: usize) -> Vec<Option<f64>> {
+    let bucket_time = display_time / width as f64;
+    let mut points = points.iter().peekable();
+    let mut last_value = None;
+
+    (0..width)
+        .map(|bucket| {
+            let end = -display_time + (bucket + 1) as f64 * bucket_time;
+            let is_last_bucket = bucket + 1 == width;
+            let (mut sum, mut count) = (0.0, 0);
+
+            while let Some((time, value)) =
+                points.next_if(|(time, _)| *time < end || is_last_bucket)
+            {
+                if *time >= -display_time {
+                    sum += value;
+                    count += 1;
+                }
+                last_value = Some(*value);
+            }
+
+            if count > 0 {
+                Some(sum / f64::from(count))
+            } else {
+                last_value
+            }
+        })
+        .collect()
+}
+
+impl Painter {
+    pub fn draw_cpu_heatmap(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(cpu_widget_state) = app_state.states.cpu_state.widget_states.get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut cpu_widget_state.autohide_timer,
+                draw_loc,
+            );
+            let cpu_widget_state = &*cpu_widget_state;
+
+            let title = if app_state.is_expanded {
+                const TITLE_BASE: &str = " CPU Heatmap ── Esc to go back ";
+                Line::from(vec![
+                    Span::styled(TITLE, self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
+                            ))
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Line::from(Span::styled(TITLE, self.colours.widget_title_style))
+            };
+
+            let block = Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(border_style);
+            let inner = block.inner(draw_loc);
+            f.render_widget(block, draw_loc);
+
+            let rows = heatmap_rows(cpu_widget_state, &app_state.converted_data.cpu_data);
+            let label_width = rows
+                .iter()
+                .map(|(label, _)| UnicodeSegmentation::graphemes(label.as_ref(), true).count())
+                .max()
+                .unwrap_or(0)
+                .min(usize::from(inner.width) / 3);
+            let cells_width = usize::from(inner.width).saturating_sub(label_width + 1);
+            let cells_height = if hide_x_labels {
+                usize::from(inner.height)
+            } else {
+                usize::from(inner.height).saturating_sub(1)
+            };
+
+            if rows.is_empty() || cells_width == 0 || cells_height == 0 {
+                return;
+            }
+
+            // If there are more rows than lines, each line shows the busiest of several rows.
+            let rows_per_line = rows.len().div_ceil(cells_height);
+            let display_time = cpu_widget_state.current_display_time as f64;
+            let stops = &self.colours.cpu_heatmap_colours;
+
+            let mut lines: Vec<Line<'_>> = rows
+                .chunks(rows_per_line)
+                .map(|chunk| {
+                    let mut values = vec![None; cells_width];
+                    for (_, points) in chunk {
+                        let row_values = bucket_values(points, display_time, cells_width);
+                        for (value, row_value) in values.iter_mut().zip(row_values) {
+                            *value = match (*value, row_value) {
+                                (Some(a), Some(b)) => Some(f64::max(a, b)),
+                                (a, b) => a.or(b),
+                            };
+                        }
+                    }
+
+                    let label = format!("{:<label_width$.label_width$} ", chunk[0].0);
+                    let mut spans = Vec::with_capacity(cells_width + 1);
+                    spans.push(Span::styled(label, self.colours.text_style));
+                    spans.extend(values.into_iter().map(|value| match value {
+                        Some(value) => Span::styled(
+                            " ",
+                            Style::default().bg(gradient_colour(stops, value / 100.0)),
+                        ),
+                        None => Span::raw(" "),
+                    }));
+
+                    Line::from(spans)
+                })
+                .collect();
+
+            if !hide_x_labels {
+                lines.resize(cells_height, Line::default());
+
+                let start_label = format!("{}s", cpu_widget_state.current_display_time / 1000);
+                let end_label = "0s";
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "{:label_width$} {start_label}{end_label:>width$}",
+                        "",
+                        width = cells_width.saturating_sub(start_label.len()),
+                    ),
+                    self.colours.graph_style,
+                )));
+            }
+
+            f.render_widget(Paragraph::new(lines), inner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn heatmap_buckets() {
+        let points = [
+            (-5000.0, 10.0),
+            (-3000.0, 20.0),
+            (-2500.0, 40.0),
+            (0.0, 50.0),
+        ];
+
+        // Each bucket covers one second.
+        assert_eq!(
+            bucket_values(&points, 4000.0, 4),
+            vec![Some(10.0), Some(30.0), Some(40.0), Some(50.0)]
+        );
+
+        assert_eq!(
+            bucket_values(&[(-1000.0, 5.0)], 4000.0, 2),
+            vec![None, Some(5.0)]
+        );
+        assert_eq!(bucket_values(&[], 4000.0, 2), vec![None, None]);
+    }
+}
@@ -0,0 +1,115 @@
+use std::borrow::Cow;
+
+use tui::{
+    layout::{Constraint, Rect},
+    symbols::Marker,
+    terminal::Frame,
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        components::{
+            time_chart::LegendPosition,
+            time_graph::{GraphData, TimeGraph},
+        },
+        drawing_utils::{round_graph_max, should_hide_x_label},
+        Painter,
+    },
+    data_conversion::dec_bytes_per_second_string,
+};
+
+impl Painter {
+    pub fn draw_disk_io_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(disk_io_widget_state) = app_state
+            .states
+            .disk_io_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, disk_io_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut disk_io_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let selected_disk = &app_state.states.disk_io_state.selected_disk;
+            let lines = &app_state.converted_data.disk_io_data;
+            let points = lines
+                .iter()
+                .enumerate()
+                .map(|(itx, (name, points))| {
+                    let style = if selected_disk.is_some() {
+                        if itx == 0 {
+                            self.colours.rx_style
+                        } else {
+                            self.colours.tx_style
+                        }
+                    } else {
+                        self.colours.cpu_colour_styles[itx % self.colours.cpu_colour_styles.len()]
+                    };
+
+                    GraphData {
+                        points,
+                        style,
+                        name: Some(name.into()),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let max_entry = points
+                .iter()
+                .flat_map(|graph_data| graph_data.points.iter().map(|(_, value)| *value))
+                .fold(0.0, f64::max);
+            // Start at 1kB/s, as anything less is just noise.
+            let y_max = round_graph_max(max_entry, 1000.0);
+            let top_label = dec_bytes_per_second_string(y_max as u64);
+            let y_labels = [
+                Cow::Owned(format!("{:>width$}", "0B/s", width = top_label.len())),
+                Cow::Owned(top_label),
+            ];
+
+            let title = match selected_disk {
+                Some(disk) => format!(" Disk I/O ({disk}) ").into(),
+                None => " Disk I/O ".into(),
+            };
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_bounds,
+                hide_x_labels,
+                y_bounds: [0.0, y_max],
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title,
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_position: Some(LegendPosition::default()),
+                // There can be a line per disk, so let the legend use the full height.
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(1, 1))),
+                marker,
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
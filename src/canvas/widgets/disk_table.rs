@@ -20,9 +20,17 @@ impl Painter {
             .get_mut(&widget_id)
         {
             let is_on_widget = app_state.current_widget.widget_id == widget_id;
+            let table_draw_loc = self.draw_table_search_bar(
+                f,
+                &mut disk_widget_state.search,
+                draw_loc,
+                !disk_widget_state.table.props.is_basic,
+                is_on_widget,
+                app_state.is_force_redraw,
+            );
 
             let draw_info = DrawInfo {
-                loc: draw_loc,
+                loc: table_draw_loc,
                 force_redraw: app_state.is_force_redraw,
                 recalculate_column_widths,
                 selection_state: SelectionState::new(app_state.is_expanded, is_on_widget),
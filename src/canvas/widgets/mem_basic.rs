@@ -105,6 +105,17 @@ impl Painter {
             );
         }
 
+        if let Some(pressure) = &app_state.converted_data.pressure.memory {
+            draw_widgets.push(
+                PipeGauge::default()
+                    .ratio(pressure.some.avg10 / 100.0)
+                    .start_label("PSI")
+                    .inner_label(format!("{:.1}%", pressure.some.avg10))
+                    .label_style(self.colours.ram_style)
+                    .gauge_style(self.colours.ram_style),
+            );
+        }
+
         #[cfg(feature = "zfs")]
         {
             let arc_data = &app_state.converted_data.arc_data;
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use tui::{
-    layout::{Constraint, Rect},
+    layout::{Constraint, Direction, Layout, Rect},
     symbols::Marker,
     terminal::Frame,
 };
@@ -9,12 +9,40 @@ use tui::{
 use crate::{
     app::App,
     canvas::{
-        components::time_graph::{GraphData, TimeGraph},
-        drawing_utils::should_hide_x_label,
+        components::{
+            time_chart::{LegendPosition, Point},
+            time_graph::{GraphData, TimeGraph},
+        },
+        drawing_utils::{round_graph_max, should_hide_x_label},
+        widgets::pressure_graph::pressure_title,
         Painter,
     },
+    data_collection::memory::PagingRates,
+    utils::general::get_decimal_prefix,
 };
 
+/// Returns the points of a dataset that is only graphed if `show` is set.
+fn extra_points(points: &[Point], show: bool) -> &[Point] {
+    if show {
+        points
+    } else {
+        &[]
+    }
+}
+
+/// The memory widget must be at least this tall to also fit the paging graph.
+const PAGING_MIN_HEIGHT: u16 = 14;
+
+/// Formats a rate like `1.5K/s`.
+fn rate_string(value: f64) -> String {
+    let (value, unit) = get_decimal_prefix(value.round() as u64, "");
+    if unit.is_empty() {
+        format!("{value:.0}/s")
+    } else {
+        format!("{value:.1}{unit}/s")
+    }
+}
+
 impl Painter {
     pub fn draw_memory_graph(
         &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
@@ -22,6 +50,20 @@ impl Painter {
         const Y_BOUNDS: [f64; 2] = [0.0, 100.5];
         const Y_LABELS: [Cow<'static, str>; 2] = [Cow::Borrowed("  0%"), Cow::Borrowed("100%")];
 
+        // Swap and paging activity gets its own graph below, since it isn't a percentage.
+        let (graph_loc, paging_loc) = if app_state.app_config_fields.enable_paging_graph
+            && app_state.converted_data.paging.is_some()
+            && draw_loc.height >= PAGING_MIN_HEIGHT
+        {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Ratio(3, 5), Constraint::Ratio(2, 5)])
+                .split(draw_loc);
+            (chunks[0], Some(chunks[1]))
+        } else {
+            (draw_loc, None)
+        };
+
         if let Some(mem_widget_state) = app_state.states.mem_state.widget_states.get_mut(&widget_id)
         {
             let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
@@ -30,8 +72,11 @@ impl Painter {
                 app_state.app_config_fields.hide_time,
                 app_state.app_config_fields.autohide_time,
                 &mut mem_widget_state.autohide_timer,
-                draw_loc,
+                graph_loc,
             );
+            // Without the extra graphs, zram, zswap, and ARC details are only shown in the legend.
+            let show_extra_graphs = app_state.app_config_fields.enable_memory_extra_graphs;
+
             let points = {
                 let mut size = 1;
                 if app_state.converted_data.swap_labels.is_some() {
@@ -76,6 +121,26 @@ impl Painter {
                         name: Some(swap_label.into()),
                     });
                 }
+                if let Some(zram_label) = &app_state.converted_data.zram_label {
+                    points.push(GraphData {
+                        points: extra_points(
+                            &app_state.converted_data.zram_data,
+                            show_extra_graphs,
+                        ),
+                        style: self.colours.zram_style,
+                        name: Some(zram_label.into()),
+                    });
+                }
+                if let Some(zswap_label) = &app_state.converted_data.zswap_label {
+                    points.push(GraphData {
+                        points: extra_points(
+                            &app_state.converted_data.zswap_data,
+                            show_extra_graphs,
+                        ),
+                        style: self.colours.zswap_style,
+                        name: Some(zswap_label.into()),
+                    });
+                }
                 #[cfg(feature = "zfs")]
                 if let Some((label_percent, label_frac)) = &app_state.converted_data.arc_labels {
                     let arc_label = format!("ARC:{label_percent}{label_frac}");
@@ -85,6 +150,17 @@ impl Painter {
                         name: Some(arc_label.into()),
                     });
                 }
+                #[cfg(feature = "zfs")]
+                if let Some(arc_hit_label) = &app_state.converted_data.arc_hit_label {
+                    points.push(GraphData {
+                        points: extra_points(
+                            &app_state.converted_data.arc_hit_data,
+                            show_extra_graphs,
+                        ),
+                        style: self.colours.arc_hit_style,
+                        name: Some(arc_hit_label.into()),
+                    });
+                }
                 #[cfg(feature = "gpu")]
                 {
                     if let Some(gpu_data) = &app_state.converted_data.gpu_data {
@@ -130,14 +206,29 @@ impl Painter {
                 y_labels: &Y_LABELS,
                 graph_style: self.colours.graph_style,
                 border_style,
-                title: " Memory ".into(),
+                title: concat_string::concat_string!(
+                    " Memory ",
+                    pressure_title(&app_state.converted_data.pressure.memory)
+                )
+                .into(),
                 is_expanded: app_state.is_expanded,
                 title_style: self.colours.widget_title_style,
                 legend_position: app_state.app_config_fields.memory_legend_position,
                 legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
                 marker,
             }
-            .draw_time_graph(f, draw_loc, &points);
+            .draw_time_graph(f, graph_loc, &points);
+
+            if let Some(paging_loc) = paging_loc {
+                self.draw_paging_graph(
+                    f,
+                    app_state,
+                    paging_loc,
+                    widget_id,
+                    x_bounds,
+                    hide_x_labels,
+                );
+            }
         }
 
         if app_state.should_get_widget_bounds() {
@@ -149,4 +240,92 @@ impl Painter {
             }
         }
     }
+
+    /// Draws how quickly pages are being swapped, faulted in, and reclaimed.
+    fn draw_paging_graph(
+        &self, f: &mut Frame<'_>, app_state: &App, draw_loc: Rect, widget_id: u64,
+        x_bounds: [u64; 2], hide_x_labels: bool,
+    ) {
+        let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+        let marker = if app_state.app_config_fields.use_dot {
+            Marker::Dot
+        } else {
+            Marker::Braille
+        };
+
+        let converted_data = &app_state.converted_data;
+        let label = |name: &str, get_value: fn(&PagingRates) -> f64| match &converted_data.paging {
+            Some(paging) => format!("{name}:{}", rate_string(get_value(paging))),
+            None => format!("{name}:N/A"),
+        };
+
+        let points = [
+            GraphData {
+                points: &converted_data.swap_in_data,
+                style: self.colours.rx_style,
+                name: Some(label("SWAP IN", |paging| paging.swap_in).into()),
+            },
+            GraphData {
+                points: &converted_data.swap_out_data,
+                style: self.colours.tx_style,
+                name: Some(label("SWAP OUT", |paging| paging.swap_out).into()),
+            },
+            GraphData {
+                points: &converted_data.major_fault_data,
+                style: self.colours.total_rx_style,
+                name: Some(label("MAJ FAULT", |paging| paging.major_faults).into()),
+            },
+            GraphData {
+                points: &converted_data.page_scan_data,
+                style: self.colours.total_tx_style,
+                name: Some(label("SCAN", |paging| paging.scanned).into()),
+            },
+            GraphData {
+                points: &converted_data.page_steal_data,
+                style: self.colours.avg_colour_style,
+                name: Some(label("STEAL", |paging| paging.stolen).into()),
+            },
+        ];
+
+        let max_entry = points
+            .iter()
+            .flat_map(|graph_data| graph_data.points.iter().map(|(_, value)| *value))
+            .fold(0.0, f64::max);
+        // Start at 10 so that an idle system doesn't make a few faults look like a spike.
+        let y_max = round_graph_max(max_entry, 10.0);
+        let top_label = rate_string(y_max);
+        let y_labels = [
+            Cow::Owned(format!("{:>width$}", "0/s", width = top_label.len())),
+            Cow::Owned(top_label),
+        ];
+
+        TimeGraph {
+            x_bounds,
+            hide_x_labels,
+            y_bounds: [0.0, y_max],
+            y_labels: &y_labels,
+            graph_style: self.colours.graph_style,
+            border_style,
+            title: " Paging (pages/s) ".into(),
+            is_expanded: false,
+            title_style: self.colours.widget_title_style,
+            legend_position: Some(LegendPosition::default()),
+            // There are several series, so let the legend use the full height.
+            legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(1, 1))),
+            marker,
+        }
+        .draw_time_graph(f, draw_loc, &points);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paging_rates() {
+        assert_eq!(rate_string(0.4), "0/s");
+        assert_eq!(rate_string(999.0), "999/s");
+        assert_eq!(rate_string(1500.0), "1.5K/s");
+    }
 }
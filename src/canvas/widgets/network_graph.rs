@@ -146,6 +146,11 @@ impl Painter {
                 ]
             };
 
+            let title = match &app_state.states.net_state.selected_interface {
+                Some(interface) => format!(" Network ({interface}) ").into(),
+                None => " Network ".into(),
+            };
+
             let marker = if app_state.app_config_fields.use_dot {
                 Marker::Dot
             } else {
@@ -159,7 +164,7 @@ impl Painter {
                 y_labels: &y_labels,
                 graph_style: self.colours.graph_style,
                 border_style,
-                title: " Network ".into(),
+                title,
                 is_expanded: app_state.is_expanded,
                 title_style: self.colours.widget_title_style,
                 legend_position: app_state.app_config_fields.network_legend_position,
@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use tui::{
+    layout::{Constraint, Rect},
+    symbols::Marker,
+    terminal::Frame,
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        components::{
+            time_chart::LegendPosition,
+            time_graph::{GraphData, TimeGraph},
+        },
+        drawing_utils::should_hide_x_label,
+        Painter,
+    },
+    data_collection::pressure::Pressure,
+};
+
+/// The upper bounds the graph can scale to, so that small amounts of pressure are still visible.
+const Y_MAXES: [f64; 4] = [10.0, 25.0, 50.0, 100.0];
+
+/// Returns the part of a graph title that shows the pressure of a resource, or an empty string if
+/// there is no pressure information.
+pub(crate) fn pressure_title(pressure: &Option<Pressure>) -> String {
+    match pressure {
+        Some(pressure) => format!("─ PSI {:.1}% ", pressure.some.avg10),
+        None => String::new(),
+    }
+}
+
+/// Returns the legend label for a resource, like `CPU:1.2%`.
+fn pressure_label(name: &str, pressure: &Option<Pressure>) -> String {
+    match pressure {
+        Some(pressure) => format!("{name}:{:.1}%", pressure.some.avg10),
+        None => format!("{name}:N/A"),
+    }
+}
+
+impl Painter {
+    pub fn draw_pressure_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(pressure_widget_state) = app_state
+            .states
+            .pressure_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, pressure_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut pressure_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let converted_data = &app_state.converted_data;
+            let pressure = &converted_data.pressure;
+            let points = [
+                GraphData {
+                    points: &converted_data.cpu_pressure_data,
+                    style: self.colours.avg_colour_style,
+                    name: Some(pressure_label("CPU", &pressure.cpu).into()),
+                },
+                GraphData {
+                    points: &converted_data.mem_pressure_data,
+                    style: self.colours.ram_style,
+                    name: Some(pressure_label("MEM", &pressure.memory).into()),
+                },
+                GraphData {
+                    points: &converted_data.io_pressure_data,
+                    style: self.colours.rx_style,
+                    name: Some(pressure_label("IO", &pressure.io).into()),
+                },
+            ];
+
+            let max_entry = points
+                .iter()
+                .flat_map(|graph_data| graph_data.points.iter().map(|(_, value)| *value))
+                .fold(0.0, f64::max);
+            let y_max = Y_MAXES
+                .into_iter()
+                .find(|y_max| max_entry <= *y_max)
+                .unwrap_or(100.0);
+            let y_labels = [Cow::Borrowed("  0%"), Cow::Owned(format!("{y_max:>3.0}%"))];
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_bounds,
+                hide_x_labels,
+                y_bounds: [0.0, y_max + 0.5],
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: " Pressure ".into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_position: Some(LegendPosition::default()),
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
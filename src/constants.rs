@@ -39,6 +39,9 @@ pub fn default_light_mode_colour_palette() -> ConfigColours {
         cache_color: Some("LightRed".into()),
         swap_color: Some("red".into()),
         arc_color: Some("LightBlue".into()),
+        arc_hit_color: Some("blue".into()),
+        zram_color: Some("green".into()),
+        zswap_color: Some("cyan".into()),
         gpu_core_colors: Some(vec![
             "LightGreen".into(),
             "LightCyan".into(),
@@ -48,6 +51,13 @@ pub fn default_light_mode_colour_palette() -> ConfigColours {
             "Blue".into(),
             "Red".into(),
         ]),
+        cpu_heatmap_colors: Some(vec![
+            "#ffffff".into(),
+            "#005fd7".into(),
+            "#00af5f".into(),
+            "#d7af00".into(),
+            "#d70000".into(),
+        ]),
         rx_color: Some("blue".into()),
         tx_color: Some("red".into()),
         rx_total_color: Some("LightBlue".into()),
@@ -92,11 +102,21 @@ pub fn gruvbox_colour_palette() -> ConfigColours {
             "#d65d03".into(),
             "#af3a03".into(),
         ]),
+        cpu_heatmap_colors: Some(vec![
+            "#282828".into(),
+            "#458588".into(),
+            "#98971a".into(),
+            "#d79921".into(),
+            "#cc241d".into(),
+        ]),
         ram_color: Some("#8ec07c".into()),
         #[cfg(not(target_os = "windows"))]
         cache_color: Some("#b16286".into()),
         swap_color: Some("#fabd2f".into()),
         arc_color: Some("#689d6a".into()),
+        arc_hit_color: Some("#d3869b".into()),
+        zram_color: Some("#b8bb26".into()),
+        zswap_color: Some("#83a598".into()),
         gpu_core_colors: Some(vec![
             "#d79921".into(),
             "#458588".into(),
@@ -151,11 +171,21 @@ pub fn gruvbox_light_colour_palette() -> ConfigColours {
             "#d65d03".into(),
             "#af3a03".into(),
         ]),
+        cpu_heatmap_colors: Some(vec![
+            "#fbf1c7".into(),
+            "#076678".into(),
+            "#79740e".into(),
+            "#b57614".into(),
+            "#9d0006".into(),
+        ]),
         ram_color: Some("#427b58".into()),
         #[cfg(not(target_os = "windows"))]
         cache_color: Some("#d79921".into()),
         swap_color: Some("#cc241d".into()),
         arc_color: Some("#689d6a".into()),
+        arc_hit_color: Some("#8f3f71".into()),
+        zram_color: Some("#79740e".into()),
+        zswap_color: Some("#076678".into()),
         gpu_core_colors: Some(vec![
             "#9d0006".into(),
             "#98971a".into(),
@@ -198,11 +228,21 @@ pub fn nord_colour_palette() -> ConfigColours {
             "#d08770".into(),
             "#bf616a".into(),
         ]),
+        cpu_heatmap_colors: Some(vec![
+            "#2e3440".into(),
+            "#5e81ac".into(),
+            "#a3be8c".into(),
+            "#ebcb8b".into(),
+            "#bf616a".into(),
+        ]),
         ram_color: Some("#88c0d0".into()),
         #[cfg(not(target_os = "windows"))]
         cache_color: Some("#d8dee9".into()),
         swap_color: Some("#d08770".into()),
         arc_color: Some("#5e81ac".into()),
+        arc_hit_color: Some("#b48ead".into()),
+        zram_color: Some("#a3be8c".into()),
+        zswap_color: Some("#88c0d0".into()),
         gpu_core_colors: Some(vec![
             "#8fbcbb".into(),
             "#81a1c1".into(),
@@ -245,11 +285,21 @@ pub fn nord_light_colour_palette() -> ConfigColours {
             "#d08770".into(),
             "#bf616a".into(),
         ]),
+        cpu_heatmap_colors: Some(vec![
+            "#eceff4".into(),
+            "#5e81ac".into(),
+            "#a3be8c".into(),
+            "#ebcb8b".into(),
+            "#bf616a".into(),
+        ]),
         ram_color: Some("#81a1c1".into()),
         #[cfg(not(target_os = "windows"))]
         cache_color: Some("#4c566a".into()),
         swap_color: Some("#d08770".into()),
         arc_color: Some("#5e81ac".into()),
+        arc_hit_color: Some("#b48ead".into()),
+        zram_color: Some("#a3be8c".into()),
+        zswap_color: Some("#88c0d0".into()),
         gpu_core_colors: Some(vec![
             "#8fbcbb".into(),
             "#88c0d0".into(),
@@ -278,7 +328,7 @@ pub fn nord_light_colour_palette() -> ConfigColours {
 }
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 10] = [
+pub const HELP_CONTENTS_TEXT: [&str; 12] = [
     "Either scroll or press the number key to go to the corresponding help menu section:",
     "1 - General",
     "2 - CPU widget",
@@ -289,6 +339,8 @@ pub const HELP_CONTENTS_TEXT: [&str; 10] = [
     "7 - Disk widget",
     "8 - Battery widget",
     "9 - Basic memory widget",
+    "10 - Network table widget",
+    "11 - Replay",
 ];
 
 // TODO [Help]: Search in help?
@@ -328,32 +380,40 @@ pub const GENERAL_HELP_TEXT: [&str; 32] = [
     "Mouse click      Selects the clicked widget, table entry, dialog option, or tab",
 ];
 
-pub const CPU_HELP_TEXT: [&str; 2] = [
+pub const CPU_HELP_TEXT: [&str; 7] = [
     "2 - CPU widget",
     "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
+    "b                Toggle showing what the selected CPU spent its time on",
+    "F                Toggle showing the frequency and governor of each CPU",
+    "o                Cycle grouping CPUs by none, socket, NUMA node, and physical core",
+    "Enter            Expand or collapse the selected group in the legend",
+    "Ctrl-f, /        Open a search bar to filter the legend, ex: use > 50",
 ];
 
-pub const PROCESS_HELP_TEXT: [&str; 17] = [
+pub const PROCESS_HELP_TEXT: [&str; 20] = [
     "3 - Process widget",
     "dd, F9           Kill the selected process",
+    "Enter            Show the details of the selected process",
     "c                Sort by CPU usage, press again to reverse",
     "m                Sort by memory usage, press again to reverse",
     "p                Sort by PID name, press again to reverse",
     "n                Sort by process name, press again to reverse",
     "Tab              Group/un-group processes with the same name",
+    "Shift-Tab        Group/un-group processes by container, unit, or cgroup",
     "Ctrl-f, /        Open process search widget",
     "P                Toggle between showing the full command or just the process name",
     "s, F6            Open process sort widget",
     "I                Invert current sort",
     "%                Toggle between values and percentages for memory usage",
     "t, F5            Toggle tree mode",
+    "T                Toggle showing the threads of each process",
     "+, -, click      Collapse/expand a branch while in tree mode",
     "click on header  Sorts the entries by that column, click again to invert the sort",
     "C                Sort by GPU usage, press again to reverse",
     "M                Sort by GPU memory usage, press again to reverse",
 ];
 
-pub const SEARCH_HELP_TEXT: [&str; 51] = [
+pub const SEARCH_HELP_TEXT: [&str; 69] = [
     "4 - Process search widget",
     "Esc              Close the search widget (retains the filter)",
     "Ctrl-a           Skip to the start of the search query",
@@ -375,12 +435,27 @@ pub const SEARCH_HELP_TEXT: [&str; 51] = [
     "cpu, cpu%        ex: cpu > 4.2",
     "mem, mem%        ex: mem < 4.2",
     "memb             ex: memb < 100 kb",
+    "rss              ex: rss > 1 gb",
+    "virt, virtual    ex: virt > 10 gb",
+    "shared, shr      ex: shared > 100 mb",
+    "private, priv    ex: private > 500 mb",
+    "swap             ex: swap > 0 b",
+    "threads          ex: threads > 100",
+    "nice, ni         ex: nice < 0",
+    "priority, prio   ex: prio <= 10",
+    "policy, sched    ex: policy = fifo",
+    "processor, psr   ex: psr = 3",
+    "minflt           ex: minflt > 1000",
+    "majflt           ex: majflt > 0",
     "read, r/s, rps   ex: read >= 1 b",
     "write, w/s, wps  ex: write <= 1 tb",
     "tread, t.read    ex: tread = 1",
     "twrite, t.write  ex: twrite = 1",
     "user             ex: user = root",
     "state            ex: state = running",
+    "cgroup           ex: cgroup = system.slice",
+    "container        ex: container = 8c5fbb7e",
+    "unit, pod        ex: unit = sshd.service",
     "gpu%             ex: gpu% < 4.2",
     "gmem             ex: gmem < 100 kb",
     "gmem%            ex: gmem% < 4.2",
@@ -405,6 +480,9 @@ pub const SEARCH_HELP_TEXT: [&str; 51] = [
     "MiB              ex: read > 1 mib",
     "GiB              ex: read > 1 gib",
     "TiB              ex: read > 1 tib",
+    "",
+    "The disk, temperature, CPU legend, and cgroup searches use the same keys",
+    "and operators, with the table's column names as search types.",
 ];
 
 pub const SORT_HELP_TEXT: [&str; 6] = [
@@ -416,13 +494,14 @@ pub const SORT_HELP_TEXT: [&str; 6] = [
     "Enter            Sort by current selected column",
 ];
 
-pub const TEMP_HELP_WIDGET: [&str; 3] = [
+pub const TEMP_HELP_WIDGET: [&str; 4] = [
     "6 - Temperature widget",
     "'s'              Sort by sensor name, press again to reverse",
     "'t'              Sort by temperature, press again to reverse",
+    "Ctrl-f, /        Open a search bar to filter sensors, ex: temp > 80",
 ];
 
-pub const DISK_HELP_WIDGET: [&str; 9] = [
+pub const DISK_HELP_WIDGET: [&str; 11] = [
     "7 - Disk widget",
     "'d'              Sort by disk name, press again to reverse",
     "'m'              Sort by disk mount, press again to reverse",
@@ -432,6 +511,8 @@ pub const DISK_HELP_WIDGET: [&str; 9] = [
     "'p'              Sort by disk usage percentage, press again to reverse",
     "'r'              Sort by disk read activity, press again to reverse",
     "'w'              Sort by disk write activity, press again to reverse",
+    "Enter            Show only the selected disk on the disk I/O graph, press again to show all",
+    "Ctrl-f, /        Open a search bar to filter disks, ex: used% > 90",
 ];
 
 pub const BATTERY_HELP_TEXT: [&str; 3] = [
@@ -445,6 +526,22 @@ pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%                Toggle between values and percentages for memory usage",
 ];
 
+pub const NET_TABLE_HELP_TEXT: [&str; 5] = [
+    "10 - Network table widget",
+    "'n'              Sort by interface name, press again to reverse",
+    "'r'              Sort by receive rate, press again to reverse",
+    "'t'              Sort by transmit rate, press again to reverse",
+    "Enter            Show only the selected interface on the network graph, press again to show all",
+];
+
+pub const REPLAY_HELP_TEXT: [&str; 5] = [
+    "11 - Replay",
+    "Space            Pause/resume the replay",
+    "[, ]             Seek backwards/forwards by 10 seconds",
+    "{, }             Seek backwards/forwards by a minute",
+    "<, >             Halve/double the playback speed",
+];
+
 pub const HELP_TEXT: [&[&str]; HELP_CONTENTS_TEXT.len()] = [
     &HELP_CONTENTS_TEXT,
     &GENERAL_HELP_TEXT,
@@ -456,6 +553,8 @@ pub const HELP_TEXT: [&[&str]; HELP_CONTENTS_TEXT.len()] = [
     &DISK_HELP_WIDGET,
     &BATTERY_HELP_TEXT,
     &BASIC_MEM_HELP_TEXT,
+    &NET_TABLE_HELP_TEXT,
+    &REPLAY_HELP_TEXT,
 ];
 
 // Default layouts
@@ -539,6 +638,8 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #unnormalized_cpu = false
 # Whether to group processes with the same name together by default.
 #group_processes = false
+# Whether to group processes by their container, unit, or cgroup by default. Only supported on Linux.
+#group_by_cgroup = false
 # Whether to make process searching case sensitive by default.
 #case_sensitive = false
 # Whether to make process searching look for matching the entire word by default.
@@ -579,6 +680,8 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #mem_as_value = false
 # Show tree mode by default in the processes widget.
 #tree = false
+# Show the threads of each process in the processes widget by default. Only supported on Linux.
+#threads = false
 # Shows an indicator in table widgets tracking where in the list you are.
 #show_table_scroll_position = false
 # Show processes as their commands by default in the process widget.
@@ -595,8 +698,16 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #enable_gpu = false
 # Shows cache and buffer memory
 #enable_cache_memory = false
+# Shows swap and paging activity in the memory widget
+#enable_paging_graph = false
+# Also graphs zram, zswap, and the ZFS ARC hit ratio in the memory widget
+#enable_memory_extra_graphs = false
+# Also shows fan, voltage, power, current, and energy sensors in the temperature widget. Only supported on Linux
+#enable_hwmon_sensors = false
 # How much data is stored at once in terms of time.
 #retention = "10m"
+# Serves collected data as OpenMetrics text at this address, such as "127.0.0.1:9184" or "unix:/path/to/socket".
+#exporter = "127.0.0.1:9184"
 # Where to place the legend for the memory widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
 #memory_legend = "TopRight".
 # Where to place the legend for the network widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
@@ -605,12 +716,36 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 # These are flags around the process widget.
 #[processes]
 # The columns shown by the process widget. The following columns are supported:
-#   PID, Name, CPU%, Mem%, R/s, W/s, T.Read, T.Write, User, State, Time, GMem%, GPU%
+#   PID, Name, CPU%, Mem%, R/s, W/s, T.Read, T.Write, User, State, Time, GMem%, GPU%,
+#   RSS, Virt, Shared, Private, Swap, Threads, Nice, Prio, Policy, PSR, MinFlt/s, MajFlt/s,
+#   Cgroup, Container, Unit
 #columns = ["PID", "Name", "CPU%", "Mem%", "R/s", "W/s", "T.Read", "T.Write", "User", "State", "GMEM%", "GPU%"]
 
+# These are flags around the disk widget.
+#[disk]
+# The columns shown by the disk widget. The following columns are supported:
+#   Disk, Mount, Used, Free, Total, Used%, Free%, R/s, W/s, RIOPS, WIOPS, Await, Busy%, Queue,
+#   IUsed, IFree, Inodes, IUse%, Type, Options, Device
+#columns = ["Disk", "Mount", "Used", "Free", "Total", "Used%", "R/s", "W/s"]
+
 # [cpu]
 # One of "all" (default), "average"/"avg"
 # default = "average"
+# How to group CPUs by topology. One of "none" (default), "socket", "node", or "core"
+# grouping = "socket"
+
+# Alerts fire when a metric crosses a threshold for long enough.  Each alert can highlight
+# the widgets showing the metric, show a banner, and/or run a command.
+# [[alerts]]
+# name = "High CPU"
+# One of "cpu", "memory", "swap", "disk", "temperature", "battery", "process"
+# metric = "cpu"
+# threshold = 90
+# below = false
+# duration = "30s"
+# highlight = true
+# banner = true
+# command = "notify-send 'CPU usage is high'"
 
 # These are all the components that support custom theming.  Note that colour support
 # will depend on terminal support.
@@ -623,12 +758,20 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #avg_cpu_color="Red"
 # Represents the colour the core will use in the CPU legend and graph.
 #cpu_core_colors=["LightMagenta", "LightYellow", "LightCyan", "LightGreen", "LightBlue", "LightRed", "Cyan", "Green", "Blue", "Red"]
+# Represents the gradient used by the CPU heatmap, from 0% to 100% usage. Only RGB colours are blended.
+#cpu_heatmap_colors=["0, 0, 0", "0, 95, 215", "0, 175, 95", "215, 175, 0", "215, 0, 0"]
 # Represents the colour RAM will use in the memory legend and graph.
 #ram_color="LightMagenta"
 # Represents the colour SWAP will use in the memory legend and graph.
 #swap_color="LightYellow"
 # Represents the colour ARC will use in the memory legend and graph.
 #arc_color="LightCyan"
+# Represents the colour the ARC hit ratio will use in the memory legend and graph.
+#arc_hit_color="Green"
+# Represents the colour zram will use in the memory legend and graph.
+#zram_color="LightGreen"
+# Represents the colour zswap will use in the memory legend and graph.
+#zswap_color="Cyan"
 # Represents the colour the GPU will use in the legend and graph.
 #gpu_core_colors=["LightGreen", "LightBlue", "LightRed", "Cyan", "Green", "Blue", "Red"]
 # Represents the colour rx will use in the network legend and graph.
@@ -657,7 +800,7 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 # [[row.child]] represents either a widget or a column.
 # [[row.child.child]] represents a widget.
 #
-# All widgets must have the type value set to one of ["cpu", "mem", "proc", "net", "temp", "disk", "empty"].
+# All widgets must have the type value set to one of ["cpu", "cpu_heatmap", "mem", "mem_table", "proc", "net", "net_table", "temp", "disk", "disk_io", "cgroup", "pressure", "empty"].
 # All layout components have a ratio value - if this is not set, then it defaults to 1.
 # The default widget layout:
 #[[row]]
@@ -684,7 +827,7 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #    default=true
 
 
-# Filters - you can hide specific temperature sensors, network interfaces, and disks using filters.  This is admittedly
+# Filters - you can hide specific temperature sensors, network interfaces, disks, and cgroups using filters.  This is admittedly
 # a bit hard to use as of now, and there is a planned in-app interface for managing this in the future:
 #[disk_filter]
 #is_list_ignored = true
@@ -700,6 +843,15 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #case_sensitive = false
 #whole_word = false
 
+# Disks can also be filtered by filesystem type, mount option, and backing device using
+# fs_type_filter, mount_options_filter, and device_filter.
+#[fs_type_filter]
+#is_list_ignored = true
+#list = ["vfat"]
+#regex = false
+#case_sensitive = false
+#whole_word = true
+
 #[temp_filter]
 #is_list_ignored = true
 #list = ["cpu", "wifi"]
@@ -707,12 +859,27 @@ pub const CONFIG_TEXT: &str = r#"# This is a default config file for bottom.  Al
 #case_sensitive = false
 #whole_word = false
 
+# Fan, voltage, power, current, and energy sensors are filtered separately with sensor_filter.
+#[sensor_filter]
+#is_list_ignored = true
+#list = ["intrusion"]
+#regex = false
+#case_sensitive = false
+#whole_word = false
+
 #[net_filter]
 #is_list_ignored = true
 #list = ["virbr0.*"]
 #regex = true
 #case_sensitive = false
 #whole_word = false
+
+#[cgroup_filter]
+#is_list_ignored = true
+#list = ["/user.slice/.*"]
+#regex = true
+#case_sensitive = false
+#whole_word = false
 "#;
 
 pub const CONFIG_TOP_HEAD: &str = r##"# This is bottom's config file.
@@ -742,7 +909,7 @@ pub const CONFIG_LAYOUT_HEAD: &str = r#"
 # [[row.child]] represents either a widget or a column.
 # [[row.child.child]] represents a widget.
 #
-# All widgets must have the valid type value set to one of ["cpu", "mem", "proc", "net", "temp", "disk", "empty"].
+# All widgets must have the valid type value set to one of ["cpu", "cpu_heatmap", "mem", "mem_table", "proc", "net", "net_table", "temp", "disk", "disk_io", "cgroup", "pressure", "empty"].
 # All layout components have a ratio value - if this is not set, then it defaults to 1.
 "#;
 
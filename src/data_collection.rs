@@ -6,17 +6,19 @@ pub mod nvidia;
 #[cfg(feature = "battery")]
 pub mod batteries;
 
+pub mod cgroups;
 pub mod cpu;
 pub mod disks;
 pub mod memory;
 pub mod network;
+pub mod pressure;
 pub mod processes;
 pub mod temperature;
 
 use std::time::{Duration, Instant};
 
-#[cfg(any(target_os = "linux", feature = "gpu"))]
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "battery")]
 use starship_battery::{Battery, Manager};
 
@@ -24,24 +26,40 @@ use self::temperature::TemperatureType;
 use super::DataFilters;
 use crate::app::layout_manager::UsedWidgets;
 
-#[derive(Clone, Debug)]
+/// How often lists of objects are refreshed. If it's too frequent it can cause segfaults.
+const LIST_REFRESH_TIME: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Data {
+    #[serde(skip, default = "Instant::now")]
     pub collection_time: Instant,
     pub cpu: Option<cpu::CpuHarvest>,
     pub load_avg: Option<cpu::LoadAvgHarvest>,
+    pub pressure: Option<pressure::PressureHarvest>,
     pub memory: Option<memory::MemHarvest>,
     #[cfg(not(target_os = "windows"))]
     pub cache: Option<memory::MemHarvest>,
     pub swap: Option<memory::MemHarvest>,
+    pub mem_details: Option<memory::MemDetails>,
+    pub paging: Option<memory::PagingHarvest>,
+    #[serde(rename = "temperatures")]
     pub temperature_sensors: Option<Vec<temperature::TempHarvest>>,
+    pub sensors: Option<Vec<temperature::SensorHarvest>>,
     pub network: Option<network::NetworkHarvest>,
+    #[serde(rename = "processes")]
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IoHarvest>,
+    pub cgroups: Option<Vec<cgroups::CgroupHarvest>>,
     #[cfg(feature = "battery")]
+    #[serde(rename = "batteries")]
     pub list_of_batteries: Option<Vec<batteries::BatteryHarvest>>,
     #[cfg(feature = "zfs")]
     pub arc: Option<memory::MemHarvest>,
+    #[cfg(feature = "zfs")]
+    pub arc_details: Option<memory::ArcDetails>,
+    pub zram: Option<Vec<memory::ZramHarvest>>,
+    pub zswap: Option<memory::ZswapHarvest>,
     #[cfg(feature = "gpu")]
     pub gpu: Option<Vec<(String, memory::MemHarvest)>>,
 }
@@ -52,19 +70,28 @@ impl Default for Data {
             collection_time: Instant::now(),
             cpu: None,
             load_avg: None,
+            pressure: None,
             memory: None,
             #[cfg(not(target_os = "windows"))]
             cache: None,
             swap: None,
+            mem_details: None,
+            paging: None,
             temperature_sensors: None,
+            sensors: None,
             list_of_processes: None,
             disks: None,
             io: None,
             network: None,
+            cgroups: None,
             #[cfg(feature = "battery")]
             list_of_batteries: None,
             #[cfg(feature = "zfs")]
             arc: None,
+            #[cfg(feature = "zfs")]
+            arc_details: None,
+            zram: None,
+            zswap: None,
             #[cfg(feature = "gpu")]
             gpu: None,
         }
@@ -75,12 +102,19 @@ impl Data {
     pub fn cleanup(&mut self) {
         self.io = None;
         self.temperature_sensors = None;
+        self.sensors = None;
         self.list_of_processes = None;
         self.disks = None;
         self.memory = None;
         self.swap = None;
+        self.mem_details = None;
+        self.paging = None;
+        self.zram = None;
+        self.zswap = None;
         self.cpu = None;
         self.load_avg = None;
+        self.pressure = None;
+        self.cgroups = None;
 
         if let Some(network) = &mut self.network {
             network.first_run_cleanup();
@@ -88,6 +122,7 @@ impl Data {
         #[cfg(feature = "zfs")]
         {
             self.arc = None;
+            self.arc_details = None;
         }
         #[cfg(feature = "gpu")]
         {
@@ -142,6 +177,7 @@ pub struct DataCollector {
     last_collection_time: Instant,
     total_rx: u64,
     total_tx: u64,
+    prev_interfaces: HashMap<String, network::NetworkInterfaceCounters>,
     show_average_cpu: bool,
     widgets_to_harvest: UsedWidgets,
     filters: DataFilters,
@@ -152,6 +188,20 @@ pub struct DataCollector {
     prev_idle: f64,
     #[cfg(target_os = "linux")]
     prev_non_idle: f64,
+    #[cfg(target_os = "linux")]
+    collect_threads: bool,
+    #[cfg(target_os = "linux")]
+    prev_cgroups: HashMap<String, cgroups::CgroupCounters>,
+    #[cfg(target_os = "linux")]
+    cpu_times: HashMap<cpu::CpuDataType, cpu::CpuTimes>,
+    #[cfg(target_os = "linux")]
+    prev_cpu_times: HashMap<cpu::CpuDataType, cpu::CpuTimes>,
+    #[cfg(target_os = "linux")]
+    cpu_topology: HashMap<usize, Option<cpu::CpuTopology>>,
+    #[cfg(target_os = "linux")]
+    prev_paging: Option<memory::PagingCounters>,
+    #[cfg(feature = "zfs")]
+    prev_arc_counters: Option<(u64, u64)>,
 
     #[cfg(feature = "battery")]
     battery_manager: Option<Manager>,
@@ -161,6 +211,12 @@ pub struct DataCollector {
     #[cfg(target_family = "unix")]
     user_table: processes::UserTable,
 
+    /// The backing devices of each disk, and when they were last looked up.
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    backing_devices: HashMap<String, Vec<String>>,
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    backing_devices_time: Instant,
+
     #[cfg(feature = "gpu")]
     gpu_pids: Option<Vec<HashMap<u32, (u64, u32)>>>,
     #[cfg(feature = "gpu")]
@@ -178,12 +234,27 @@ impl DataCollector {
             prev_idle: 0_f64,
             #[cfg(target_os = "linux")]
             prev_non_idle: 0_f64,
+            #[cfg(target_os = "linux")]
+            collect_threads: false,
+            #[cfg(target_os = "linux")]
+            prev_cgroups: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            cpu_times: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            prev_cpu_times: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            cpu_topology: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            prev_paging: None,
+            #[cfg(feature = "zfs")]
+            prev_arc_counters: None,
             temperature_type: TemperatureType::Celsius,
             use_current_cpu_total: false,
             unnormalized_cpu: false,
             last_collection_time: Instant::now() - Duration::from_secs(600), // Initialize it to the past to force it to load on initialization.
             total_rx: 0,
             total_tx: 0,
+            prev_interfaces: HashMap::default(),
             show_average_cpu: false,
             widgets_to_harvest: UsedWidgets::default(),
             #[cfg(feature = "battery")]
@@ -193,6 +264,10 @@ impl DataCollector {
             filters,
             #[cfg(target_family = "unix")]
             user_table: Default::default(),
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            backing_devices: HashMap::default(),
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            backing_devices_time: Instant::now(),
             #[cfg(feature = "gpu")]
             gpu_pids: None,
             #[cfg(feature = "gpu")]
@@ -245,6 +320,12 @@ impl DataCollector {
         self.show_average_cpu = show_average_cpu;
     }
 
+    /// Sets whether to also collect the threads of each process. This is only supported on Linux.
+    #[cfg(target_os = "linux")]
+    pub fn set_collect_threads(&mut self, collect_threads: bool) {
+        self.collect_threads = collect_threads;
+    }
+
     /// Refresh sysinfo data. We use sysinfo for the following data:
     /// - CPU usage
     /// - Memory usage
@@ -253,8 +334,6 @@ impl DataCollector {
     /// - Disk (Windows)
     /// - Temperatures (non-Linux)
     fn refresh_sysinfo_data(&mut self) {
-        // Refresh the list of objects once every minute. If it's too frequent it can cause segfaults.
-        const LIST_REFRESH_TIME: Duration = Duration::from_secs(60);
         let refresh_start = Instant::now();
 
         if self.widgets_to_harvest.use_cpu || self.widgets_to_harvest.use_proc {
@@ -310,8 +389,11 @@ impl DataCollector {
 
         self.data.collection_time = Instant::now();
 
+        #[cfg(target_os = "linux")]
+        self.update_cpu_times();
         self.update_cpu_usage();
         self.update_memory_usage();
+        self.update_pressure();
         self.update_temps();
 
         #[cfg(feature = "battery")]
@@ -323,6 +405,7 @@ impl DataCollector {
         self.update_processes();
         self.update_network_usage();
         self.update_disks();
+        self.update_cgroups();
 
         // Update times for future reference.
         self.last_collection_time = self.data.collection_time;
@@ -356,11 +439,35 @@ impl DataCollector {
         }
     }
 
+    /// Reads `/proc/stat` once for both the CPU breakdown and process CPU usage.
+    #[cfg(target_os = "linux")]
+    #[inline]
+    fn update_cpu_times(&mut self) {
+        if self.widgets_to_harvest.use_cpu || self.widgets_to_harvest.use_proc {
+            self.cpu_times = cpu::read_cpu_times(std::path::Path::new(cpu::STAT_PATH));
+        }
+    }
+
     #[inline]
     fn update_cpu_usage(&mut self) {
         if self.widgets_to_harvest.use_cpu {
             self.data.cpu = cpu::get_cpu_data_list(&self.sys.system, self.show_average_cpu).ok();
 
+            #[cfg(target_os = "linux")]
+            if let Some(cpu_harvest) = &mut self.data.cpu {
+                cpu::add_cpu_breakdowns(cpu_harvest, &self.cpu_times, &mut self.prev_cpu_times);
+                cpu::add_cpu_frequencies(cpu_harvest, std::path::Path::new(cpu::CPU_SYSFS_ROOT));
+                cpu::add_cpu_topology(
+                    cpu_harvest,
+                    std::path::Path::new(cpu::CPU_SYSFS_ROOT),
+                    &mut self.cpu_topology,
+                );
+            }
+
+            if let Some(cpu_harvest) = &mut self.data.cpu {
+                cpu::set_average_frequency(cpu_harvest);
+            }
+
             #[cfg(target_family = "unix")]
             {
                 self.data.load_avg = cpu::get_load_avg().ok();
@@ -368,6 +475,15 @@ impl DataCollector {
         }
     }
 
+    #[inline]
+    fn update_pressure(&mut self) {
+        #[cfg(target_os = "linux")]
+        if self.widgets_to_harvest.use_pressure {
+            self.data.pressure =
+                pressure::get_pressure_data(std::path::Path::new(pressure::PRESSURE_ROOT));
+        }
+    }
+
     #[inline]
     fn update_processes(&mut self) {
         if self.widgets_to_harvest.use_proc {
@@ -394,10 +510,14 @@ impl DataCollector {
             }
 
             #[cfg(target_os = "linux")]
-            if let Ok(data) =
-                temperature::get_temperature_data(&self.temperature_type, &self.filters.temp_filter)
-            {
-                self.data.temperature_sensors = data;
+            if let Ok((temperatures, sensors)) = temperature::get_temperature_data(
+                &self.temperature_type,
+                &self.filters.temp_filter,
+                self.widgets_to_harvest.use_sensors,
+                &self.filters.sensor_filter,
+            ) {
+                self.data.temperature_sensors = temperatures;
+                self.data.sensors = sensors;
             }
         }
     }
@@ -414,9 +534,36 @@ impl DataCollector {
 
             self.data.swap = memory::get_swap_usage(&self.sys.system);
 
+            #[cfg(target_os = "linux")]
+            {
+                self.data.mem_details =
+                    memory::get_mem_details(std::path::Path::new(memory::MEMINFO_PATH));
+
+                let elapsed = self
+                    .data
+                    .collection_time
+                    .duration_since(self.last_collection_time);
+                self.data.paging = memory::get_paging_data(
+                    std::path::Path::new(memory::VMSTAT_PATH),
+                    &mut self.prev_paging,
+                    elapsed,
+                );
+
+                self.data.zram =
+                    memory::get_zram_data(std::path::Path::new(memory::BLOCK_SYSFS_ROOT));
+                self.data.zswap = memory::get_zswap_data(
+                    self.data.mem_details.as_ref(),
+                    std::path::Path::new(memory::ZSWAP_MODULE_ROOT),
+                    std::path::Path::new(memory::ZSWAP_DEBUGFS_ROOT),
+                );
+            }
+
             #[cfg(feature = "zfs")]
             {
-                self.data.arc = memory::arc::get_arc_usage();
+                let arc_stats = memory::arc::get_arc_stats();
+                self.data.arc = memory::arc::get_arc_usage(&arc_stats);
+                self.data.arc_details =
+                    memory::arc::get_arc_details(&arc_stats, &mut self.prev_arc_counters);
             }
         }
     }
@@ -431,6 +578,7 @@ impl DataCollector {
                 self.last_collection_time,
                 &mut self.total_rx,
                 &mut self.total_tx,
+                &mut self.prev_interfaces,
                 current_instant,
                 &self.filters.net_filter,
             );
@@ -455,11 +603,42 @@ impl DataCollector {
     #[inline]
     fn update_disks(&mut self) {
         if self.widgets_to_harvest.use_disk {
+            // Arrays and device mapper targets can be reassembled, so look their backing devices
+            // up again every so often.
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            if self
+                .data
+                .collection_time
+                .duration_since(self.backing_devices_time)
+                > LIST_REFRESH_TIME
+            {
+                self.backing_devices.clear();
+                self.backing_devices_time = self.data.collection_time;
+            }
+
             self.data.disks = disks::get_disk_usage(self).ok();
             self.data.io = disks::get_io_usage().ok();
         }
     }
 
+    #[inline]
+    fn update_cgroups(&mut self) {
+        #[cfg(target_os = "linux")]
+        if self.widgets_to_harvest.use_cgroup {
+            let elapsed = self
+                .data
+                .collection_time
+                .duration_since(self.last_collection_time);
+
+            self.data.cgroups = cgroups::get_cgroup_data(
+                std::path::Path::new(cgroups::CGROUP_ROOT),
+                &mut self.prev_cgroups,
+                elapsed,
+                &self.filters.cgroup_filter,
+            );
+        }
+    }
+
     /// Returns the total memory of the system.
     #[inline]
     fn total_memory(&self) -> u64 {
@@ -0,0 +1,34 @@
+//! Data collection for cgroup v2 groups. This is only supported on Linux.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+        pub(crate) use self::linux::*;
+    }
+}
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+/// The resource usage of a single cgroup. Rates are per second.
+pub struct CgroupHarvest {
+    /// The path of the cgroup relative to the root of the hierarchy, such as
+    /// `/system.slice/sshd.service`.
+    pub path: String,
+
+    /// CPU usage as a percentage of a single CPU, so this can go above 100%.
+    pub cpu_usage_percent: f64,
+
+    /// The memory currently used. This is only available if the memory controller is enabled.
+    pub memory_bytes: Option<u64>,
+
+    /// The memory limit, if there is one.
+    pub memory_max_bytes: Option<u64>,
+
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+
+    /// The number of processes and threads. This is only available if the pids controller is
+    /// enabled.
+    pub pids: Option<u64>,
+}
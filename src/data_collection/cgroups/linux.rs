@@ -0,0 +1,249 @@
+//! Reads cgroup v2 groups from sysfs.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use hashbrown::HashMap;
+
+use super::CgroupHarvest;
+use crate::app::filter::Filter;
+
+/// Where cgroups are usually mounted.
+pub(crate) const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// The cumulative counters of a cgroup from the previous harvest, used to calculate rates.
+#[derive(Default, Clone, Copy, Debug)]
+pub(crate) struct CgroupCounters {
+    cpu_usage_usec: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+}
+
+/// Returns where the unified hierarchy is mounted under `root`. This is `root` itself on cgroup
+/// v2 systems, and `root/unified` on systems using the hybrid layout.
+fn unified_root(root: &Path) -> Option<PathBuf> {
+    [root.to_path_buf(), root.join("unified")]
+        .into_iter()
+        .find(|path| path.join("cgroup.controllers").is_file())
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Returns the total CPU time used in microseconds, from `cpu.stat`.
+fn parse_cpu_usage(cpu_stat: &str) -> Option<u64> {
+    cpu_stat.lines().find_map(|line| {
+        line.strip_prefix("usage_usec ")
+            .and_then(|usage| usage.trim().parse().ok())
+    })
+}
+
+/// Returns the total bytes read and written across all devices, from `io.stat`.
+fn parse_io(io_stat: &str) -> (u64, u64) {
+    io_stat
+        .split_whitespace()
+        .fold((0, 0), |(read, write), field| match field.split_once('=') {
+            Some(("rbytes", bytes)) => (read + bytes.parse().unwrap_or(0), write),
+            Some(("wbytes", bytes)) => (read, write + bytes.parse().unwrap_or(0)),
+            _ => (read, write),
+        })
+}
+
+/// Adds every cgroup below `dir` to `paths`, depth first.
+fn find_cgroups(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut children = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    children.sort();
+
+    for child in children {
+        paths.push(child.clone());
+        find_cgroups(&child, paths);
+    }
+}
+
+/// Returns the usage of every cgroup under `root` that passes `filter`, not including the root
+/// cgroup itself. `prev_counters` is used to calculate rates over `elapsed`, and is replaced by
+/// the current counters.
+///
+/// Returns [`None`] if there is no cgroup v2 hierarchy under `root`.
+pub(crate) fn get_cgroup_data(
+    root: &Path, prev_counters: &mut HashMap<String, CgroupCounters>, elapsed: Duration,
+    filter: &Option<Filter>,
+) -> Option<Vec<CgroupHarvest>> {
+    let root = unified_root(root)?;
+
+    let mut dirs = Vec::new();
+    find_cgroups(&root, &mut dirs);
+
+    let elapsed_secs = elapsed.as_secs_f64();
+    let mut counters = HashMap::with_capacity(dirs.len());
+    let mut harvests = Vec::with_capacity(dirs.len());
+
+    for dir in dirs {
+        let Ok(relative) = dir.strip_prefix(&root) else {
+            continue;
+        };
+        let path = format!("/{}", relative.to_string_lossy());
+
+        if !filter
+            .as_ref()
+            .map_or(true, |filter| filter.keep_entry(&path))
+        {
+            continue;
+        }
+
+        let (read_bytes, write_bytes) = fs::read_to_string(dir.join("io.stat"))
+            .map(|io_stat| parse_io(&io_stat))
+            .unwrap_or_default();
+        let current = CgroupCounters {
+            cpu_usage_usec: fs::read_to_string(dir.join("cpu.stat"))
+                .ok()
+                .and_then(|cpu_stat| parse_cpu_usage(&cpu_stat))
+                .unwrap_or_default(),
+            read_bytes,
+            write_bytes,
+        };
+
+        let mut harvest = CgroupHarvest {
+            memory_bytes: read_u64(&dir.join("memory.current")),
+            // This is "max" if there is no limit, which won't parse.
+            memory_max_bytes: read_u64(&dir.join("memory.max")),
+            pids: read_u64(&dir.join("pids.current")),
+            ..Default::default()
+        };
+
+        if let Some(prev) = prev_counters.get(&path) {
+            if elapsed_secs > 0.0 {
+                harvest.cpu_usage_percent =
+                    current.cpu_usage_usec.saturating_sub(prev.cpu_usage_usec) as f64
+                        / 10_000.0
+                        / elapsed_secs;
+                harvest.read_bytes_per_sec = (current.read_bytes.saturating_sub(prev.read_bytes)
+                    as f64
+                    / elapsed_secs) as u64;
+                harvest.write_bytes_per_sec = (current.write_bytes.saturating_sub(prev.write_bytes)
+                    as f64
+                    / elapsed_secs) as u64;
+            }
+        }
+
+        counters.insert(path.clone(), current);
+        harvest.path = path;
+        harvests.push(harvest);
+    }
+
+    *prev_counters = counters;
+
+    Some(harvests)
+}
+
+#[cfg(test)]
+mod test {
+    use regex::Regex;
+
+    use super::*;
+
+    fn fixture_root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/cgroup")
+    }
+
+    #[test]
+    fn parses_stat_files() {
+        assert_eq!(
+            parse_cpu_usage("usage_usec 1500\nuser_usec 1000\nsystem_usec 500\n"),
+            Some(1500)
+        );
+        assert_eq!(parse_cpu_usage("nr_periods 0\n"), None);
+
+        let io_stat = "8:0 rbytes=1024 wbytes=2048 rios=1 wios=2 dbytes=0 dios=0\n\
+                       8:16 rbytes=1000 wbytes=0 rios=3 wios=0 dbytes=0 dios=0\n";
+        assert_eq!(parse_io(io_stat), (2024, 2048));
+        assert_eq!(parse_io(""), (0, 0));
+    }
+
+    #[test]
+    fn reads_cgroups() {
+        let mut prev_counters = HashMap::default();
+        let cgroups = get_cgroup_data(
+            &fixture_root(),
+            &mut prev_counters,
+            Duration::from_secs(1),
+            &None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cgroups.iter().map(|c| c.path.as_str()).collect::<Vec<_>>(),
+            vec!["/system.slice", "/system.slice/sshd.service", "/user.slice"]
+        );
+
+        let sshd = &cgroups[1];
+        assert_eq!(sshd.memory_bytes, Some(4194304));
+        assert_eq!(sshd.memory_max_bytes, Some(1073741824));
+        assert_eq!(sshd.pids, Some(3));
+        assert_eq!(sshd.cpu_usage_percent, 0.0);
+
+        // A cgroup without a limit, and one without the memory and pids controllers.
+        assert_eq!(cgroups[0].memory_max_bytes, None);
+        assert_eq!(cgroups[2].memory_bytes, None);
+        assert_eq!(cgroups[2].pids, None);
+
+        // Pretend the counters were lower two seconds ago.
+        for counters in prev_counters.values_mut() {
+            counters.cpu_usage_usec = counters.cpu_usage_usec.saturating_sub(1_000_000);
+            counters.read_bytes = counters.read_bytes.saturating_sub(4096);
+            counters.write_bytes = 0;
+        }
+
+        let cgroups = get_cgroup_data(
+            &fixture_root(),
+            &mut prev_counters,
+            Duration::from_secs(2),
+            &None,
+        )
+        .unwrap();
+
+        let sshd = &cgroups[1];
+        assert_eq!(sshd.cpu_usage_percent, 50.0);
+        assert_eq!(sshd.read_bytes_per_sec, 2048);
+        assert_eq!(sshd.write_bytes_per_sec, 4096);
+    }
+
+    #[test]
+    fn filters_cgroups() {
+        let filter = Some(Filter {
+            is_list_ignored: true,
+            list: vec![Regex::new("sshd").unwrap()],
+        });
+
+        let cgroups = get_cgroup_data(
+            &fixture_root(),
+            &mut HashMap::default(),
+            Duration::from_secs(1),
+            &filter,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cgroups.iter().map(|c| c.path.as_str()).collect::<Vec<_>>(),
+            vec!["/system.slice", "/user.slice"]
+        );
+    }
+
+    #[test]
+    fn needs_unified_hierarchy() {
+        let root = fixture_root().join("system.slice");
+        assert!(get_cgroup_data(&root, &mut HashMap::default(), Duration::ZERO, &None).is_none());
+    }
+}
@@ -3,21 +3,125 @@
 pub mod sysinfo;
 pub use self::sysinfo::*;
 
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+        pub(crate) use self::linux::*;
+    }
+}
+
+use serde::{Deserialize, Serialize};
+
 pub type LoadAvgHarvest = [f32; 3];
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CpuDataType {
     Avg,
     Cpu(usize),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CpuData {
     pub data_type: CpuDataType,
     pub cpu_usage: f64,
+
+    /// What the CPU spent its time on. This is only available on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<CpuBreakdown>,
+
+    /// The clock frequency of the CPU, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<CpuFrequency>,
+
+    /// Where the CPU sits in the system. This is only available on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topology: Option<CpuTopology>,
+}
+
+/// Where a logical CPU sits in the system. Logical CPUs with the same package, die, and core are
+/// SMT siblings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CpuTopology {
+    /// The physical package, or socket.
+    pub package: Option<u32>,
+    pub die: Option<u32>,
+    pub core: Option<u32>,
+    /// The NUMA node.
+    pub node: Option<u32>,
+}
+
+/// The frequency of a CPU and the limits it is allowed to scale between, all in MHz.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuFrequency {
+    pub current_mhz: u64,
+    pub min_mhz: Option<u64>,
+    pub max_mhz: Option<u64>,
+
+    /// The scaling governor in use, like `powersave` or `performance`.
+    pub governor: Option<String>,
+}
+
+/// The percentage of time a CPU spent in each state since the last harvest. These add up to 100%.
+///
+/// Unlike `/proc/stat`, time spent running guests is not counted in `user` or `nice`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CpuBreakdown {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+    pub guest: f64,
 }
 
 pub type CpuHarvest = Vec<CpuData>;
 
+/// Averages the given frequencies. The limits are the widest across all of them, and the governor
+/// is only set if all of them share it.
+pub fn average_frequency(frequencies: &[&CpuFrequency]) -> Option<CpuFrequency> {
+    if frequencies.is_empty() {
+        return None;
+    }
+
+    let current_mhz =
+        frequencies.iter().map(|freq| freq.current_mhz).sum::<u64>() / frequencies.len() as u64;
+    let min_mhz = frequencies.iter().filter_map(|freq| freq.min_mhz).min();
+    let max_mhz = frequencies.iter().filter_map(|freq| freq.max_mhz).max();
+    let governor = frequencies[0].governor.clone().filter(|governor| {
+        frequencies
+            .iter()
+            .all(|freq| freq.governor.as_ref() == Some(governor))
+    });
+
+    Some(CpuFrequency {
+        current_mhz,
+        min_mhz,
+        max_mhz,
+        governor,
+    })
+}
+
+/// Sets the frequency of the average entry from the frequencies of the individual CPUs.
+pub fn set_average_frequency(cpu_harvest: &mut CpuHarvest) {
+    let frequencies: Vec<&CpuFrequency> = cpu_harvest
+        .iter()
+        .filter(|cpu| matches!(cpu.data_type, CpuDataType::Cpu(_)))
+        .filter_map(|cpu| cpu.frequency.as_ref())
+        .collect();
+
+    let average = average_frequency(&frequencies);
+
+    if let Some(avg) = cpu_harvest
+        .iter_mut()
+        .find(|cpu| matches!(cpu.data_type, CpuDataType::Avg))
+    {
+        avg.frequency = average;
+    }
+}
+
 pub type PastCpuWork = f64;
 pub type PastCpuTotal = f64;
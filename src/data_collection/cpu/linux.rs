@@ -0,0 +1,436 @@
+//! Linux-specific CPU data collection, which reads the per-state times in `/proc/stat`.
+
+use std::{fs, path::Path};
+
+use hashbrown::HashMap;
+
+use super::{CpuBreakdown, CpuDataType, CpuFrequency, CpuHarvest, CpuTopology};
+
+pub(crate) const STAT_PATH: &str = "/proc/stat";
+
+pub(crate) const CPU_SYSFS_ROOT: &str = "/sys/devices/system/cpu";
+
+/// The cumulative time a CPU has spent in each state, in clock ticks, as reported by `/proc/stat`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CpuTimes {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+    guest: u64,
+    guest_nice: u64,
+}
+
+impl CpuTimes {
+    /// Parses the whitespace-separated values that follow the label of a `/proc/stat` cpu line.
+    /// Missing or invalid values are treated as 0, as older kernels report fewer fields.
+    fn from_values(values: &str) -> Self {
+        let mut values = values
+            .split_whitespace()
+            .map(|val| val.parse::<u64>().unwrap_or(0));
+        let mut next = || values.next().unwrap_or(0);
+
+        CpuTimes {
+            user: next(),
+            nice: next(),
+            system: next(),
+            idle: next(),
+            iowait: next(),
+            irq: next(),
+            softirq: next(),
+            steal: next(),
+            guest: next(),
+            guest_nice: next(),
+        }
+    }
+
+    /// The time spent idle, including time waiting on I/O.
+    pub(crate) fn idle_time(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    /// The time spent doing work. Guest time is already included in `user` and `nice`, so it is
+    /// not counted again.
+    pub(crate) fn busy_time(&self) -> u64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+
+    /// The total time.
+    fn total(&self) -> u64 {
+        self.idle_time() + self.busy_time()
+    }
+
+    /// Returns the percentage of time spent in each state between `prev` and `self`, or `None`
+    /// if no time has passed.
+    fn breakdown_since(&self, prev: &CpuTimes) -> Option<CpuBreakdown> {
+        let total = self.total().saturating_sub(prev.total());
+        if total == 0 {
+            return None;
+        }
+
+        let percent =
+            |curr: u64, prev: u64| curr.saturating_sub(prev) as f64 / total as f64 * 100.0;
+        let guest = percent(self.guest, prev.guest);
+        let guest_nice = percent(self.guest_nice, prev.guest_nice);
+
+        Some(CpuBreakdown {
+            user: (percent(self.user, prev.user) - guest).max(0.0),
+            nice: (percent(self.nice, prev.nice) - guest_nice).max(0.0),
+            system: percent(self.system, prev.system),
+            idle: percent(self.idle, prev.idle),
+            iowait: percent(self.iowait, prev.iowait),
+            irq: percent(self.irq, prev.irq),
+            softirq: percent(self.softirq, prev.softirq),
+            steal: percent(self.steal, prev.steal),
+            guest: guest + guest_nice,
+        })
+    }
+}
+
+/// Maps a `/proc/stat` label like `cpu` or `cpu3` to its [`CpuDataType`].
+fn parse_label(label: &str) -> Option<CpuDataType> {
+    let index = label.strip_prefix("cpu")?;
+    if index.is_empty() {
+        Some(CpuDataType::Avg)
+    } else {
+        index.parse().ok().map(CpuDataType::Cpu)
+    }
+}
+
+/// Parses the cpu lines of `/proc/stat`.
+fn parse_stat(contents: &str) -> HashMap<CpuDataType, CpuTimes> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (label, values) = line.split_once(char::is_whitespace)?;
+            Some((parse_label(label)?, CpuTimes::from_values(values)))
+        })
+        .collect()
+}
+
+/// Reads the cpu lines of the `/proc/stat` file at `path`. This is shared by the CPU breakdown and
+/// process CPU usage, so the file is only read once per update. Returns nothing if it can't be read.
+pub(crate) fn read_cpu_times(path: &Path) -> HashMap<CpuDataType, CpuTimes> {
+    fs::read_to_string(path)
+        .map(|contents| parse_stat(&contents))
+        .unwrap_or_default()
+}
+
+/// Computes the breakdown of each CPU from the current `times`, and replaces `prev_times` with
+/// them. CPUs without a previous reading get no breakdown.
+fn get_cpu_breakdowns(
+    times: &HashMap<CpuDataType, CpuTimes>, prev_times: &mut HashMap<CpuDataType, CpuTimes>,
+) -> HashMap<CpuDataType, CpuBreakdown> {
+    let breakdowns = times
+        .iter()
+        .filter_map(|(data_type, curr)| {
+            let prev = prev_times.get(data_type)?;
+            Some((*data_type, curr.breakdown_since(prev)?))
+        })
+        .collect();
+
+    prev_times.clone_from(times);
+    breakdowns
+}
+
+/// Fills in the [`CpuBreakdown`] of each entry of `cpu_harvest` from the current `/proc/stat`
+/// `times`.
+pub(crate) fn add_cpu_breakdowns(
+    cpu_harvest: &mut CpuHarvest, times: &HashMap<CpuDataType, CpuTimes>,
+    prev_times: &mut HashMap<CpuDataType, CpuTimes>,
+) {
+    let breakdowns = get_cpu_breakdowns(times, prev_times);
+    for cpu in cpu_harvest.iter_mut() {
+        cpu.breakdown = breakdowns.get(&cpu.data_type).copied();
+    }
+}
+
+/// Reads a frequency file from cpufreq, which is in kHz, and returns it in MHz.
+fn read_khz_as_mhz(path: &Path) -> Option<u64> {
+    let khz: u64 = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(khz / 1000)
+}
+
+/// Reads the cpufreq information of the CPU with the given index from the sysfs CPU directory at
+/// `root`. Returns `None` if the kernel does not expose frequency scaling for it.
+fn read_cpu_frequency(root: &Path, index: usize) -> Option<CpuFrequency> {
+    let dir = root.join(format!("cpu{index}")).join("cpufreq");
+    let current_mhz = read_khz_as_mhz(&dir.join("scaling_cur_freq"))?;
+
+    Some(CpuFrequency {
+        current_mhz,
+        min_mhz: read_khz_as_mhz(&dir.join("scaling_min_freq")),
+        max_mhz: read_khz_as_mhz(&dir.join("scaling_max_freq")),
+        governor: fs::read_to_string(dir.join("scaling_governor"))
+            .ok()
+            .map(|governor| governor.trim().to_string())
+            .filter(|governor| !governor.is_empty()),
+    })
+}
+
+/// Replaces the frequency of each CPU with what cpufreq reports under `root`, which also has the
+/// limits and governor. CPUs that cpufreq does not know about keep what sysinfo reported.
+pub(crate) fn add_cpu_frequencies(cpu_harvest: &mut CpuHarvest, root: &Path) {
+    for cpu in cpu_harvest.iter_mut() {
+        if let CpuDataType::Cpu(index) = cpu.data_type {
+            if let Some(frequency) = read_cpu_frequency(root, index) {
+                cpu.frequency = Some(frequency);
+            }
+        }
+    }
+}
+
+/// Reads the topology of the CPU with the given index from the sysfs CPU directory at `root`.
+/// Returns `None` if the kernel does not expose any of it.
+fn read_cpu_topology(root: &Path, index: usize) -> Option<CpuTopology> {
+    fn read_id(path: &Path) -> Option<u32> {
+        fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    let dir = root.join(format!("cpu{index}"));
+    let topology_dir = dir.join("topology");
+
+    // The NUMA node is a `nodeN` link in the CPU's directory.
+    let node = fs::read_dir(&dir).ok().and_then(|entries| {
+        entries.flatten().find_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()
+        })
+    });
+
+    let topology = CpuTopology {
+        package: read_id(&topology_dir.join("physical_package_id")),
+        die: read_id(&topology_dir.join("die_id")),
+        core: read_id(&topology_dir.join("core_id")),
+        node,
+    };
+
+    (topology != CpuTopology::default()).then_some(topology)
+}
+
+/// Sets the topology of each CPU from the sysfs CPU directory at `root`. As the topology rarely
+/// changes, it is only read once per CPU and kept in `cache`.
+pub(crate) fn add_cpu_topology(
+    cpu_harvest: &mut CpuHarvest, root: &Path, cache: &mut HashMap<usize, Option<CpuTopology>>,
+) {
+    for cpu in cpu_harvest.iter_mut() {
+        if let CpuDataType::Cpu(index) = cpu.data_type {
+            cpu.topology = *cache
+                .entry(index)
+                .or_insert_with(|| read_cpu_topology(root, index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_collection::cpu::{set_average_frequency, CpuData};
+
+    const FIRST: &str = "cpu  1000 100 500 8000 200 50 50 100 0 0
+cpu0 500 50 250 4000 100 25 25 50 0 0
+cpu1 500 50 250 4000 100 25 25 50 0 0
+intr 12345
+ctxt 67890
+";
+
+    const SECOND: &str = "cpu  1400 100 600 8200 250 60 70 120 200 0
+cpu0 900 50 350 4000 100 35 45 70 200 0
+cpu1 500 50 250 4200 150 25 25 50 0 0
+intr 12400
+ctxt 67990
+";
+
+    #[test]
+    fn test_cpu_frequencies() {
+        let root = Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/cpufreq"
+        ));
+        let mut harvest = vec![
+            CpuData {
+                data_type: CpuDataType::Avg,
+                cpu_usage: 0.0,
+                breakdown: None,
+                frequency: None,
+                topology: None,
+            },
+            CpuData {
+                data_type: CpuDataType::Cpu(0),
+                cpu_usage: 0.0,
+                breakdown: None,
+                frequency: None,
+                topology: None,
+            },
+            CpuData {
+                data_type: CpuDataType::Cpu(1),
+                cpu_usage: 0.0,
+                breakdown: None,
+                frequency: Some(CpuFrequency {
+                    current_mhz: 1200,
+                    ..Default::default()
+                }),
+                topology: None,
+            },
+        ];
+
+        add_cpu_frequencies(&mut harvest, root);
+        assert_eq!(
+            harvest[1].frequency,
+            Some(CpuFrequency {
+                current_mhz: 3400,
+                min_mhz: Some(800),
+                max_mhz: Some(4700),
+                governor: Some("powersave".to_string()),
+            })
+        );
+
+        // cpu1 has no cpufreq directory, so the sysinfo value is kept.
+        assert_eq!(harvest[2].frequency.as_ref().unwrap().current_mhz, 1200);
+
+        set_average_frequency(&mut harvest);
+        assert_eq!(
+            harvest[0].frequency,
+            Some(CpuFrequency {
+                current_mhz: 2300,
+                min_mhz: Some(800),
+                max_mhz: Some(4700),
+                governor: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_cpu_topology() {
+        let root = Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/cpu_topology"
+        ));
+        let mut harvest: Vec<CpuData> = (0..3)
+            .map(|index| CpuData {
+                data_type: CpuDataType::Cpu(index),
+                cpu_usage: 0.0,
+                breakdown: None,
+                frequency: None,
+                topology: None,
+            })
+            .collect();
+        let mut cache = HashMap::new();
+
+        add_cpu_topology(&mut harvest, root, &mut cache);
+        assert_eq!(
+            harvest[0].topology,
+            Some(CpuTopology {
+                package: Some(0),
+                die: Some(0),
+                core: Some(0),
+                node: Some(0),
+            })
+        );
+        assert_eq!(
+            harvest[1].topology,
+            Some(CpuTopology {
+                package: Some(1),
+                die: Some(0),
+                core: Some(4),
+                node: Some(1),
+            })
+        );
+
+        // cpu2 has no topology information at all.
+        assert_eq!(harvest[2].topology, None);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_label() {
+        assert_eq!(parse_label("cpu"), Some(CpuDataType::Avg));
+        assert_eq!(parse_label("cpu12"), Some(CpuDataType::Cpu(12)));
+        assert_eq!(parse_label("cpux"), None);
+        assert_eq!(parse_label("intr"), None);
+    }
+
+    #[test]
+    fn test_parse_short_line() {
+        let times = CpuTimes::from_values("100 0 100 100");
+        assert_eq!(times.user, 100);
+        assert_eq!(times.idle, 100);
+        assert_eq!(times.iowait, 0);
+        assert_eq!(times.guest_nice, 0);
+    }
+
+    #[test]
+    fn test_idle_and_busy_time() {
+        let cases = [
+            ("100 0 100 100", 100, 200),
+            ("100 0 100 100 20", 120, 200),
+            ("100 0 100 100 20 30", 120, 230),
+            ("100 0 100 100 20 30 40", 120, 270),
+            ("100 0 100 100 20 30 40 50", 120, 320),
+            ("100 0 100 100 20 30 40 50 100", 120, 320),
+            ("100 0 100 100 20 30 40 50 100 200", 120, 320),
+        ];
+
+        for (values, idle, busy) in cases {
+            let times = CpuTimes::from_values(values);
+            assert_eq!(times.idle_time(), idle, "idle time of {values:?}");
+            assert_eq!(times.busy_time(), busy, "busy time of {values:?}");
+        }
+
+        // The label isn't part of the values. It used to be parsed as the user time.
+        let times = parse_stat("cpu  100 0 100 100 20\ncpu0 40 0 50 60 10\n");
+        assert_eq!(times[&CpuDataType::Avg].user, 100);
+        assert_eq!(times[&CpuDataType::Avg].idle_time(), 120);
+        assert_eq!(times[&CpuDataType::Avg].busy_time(), 200);
+        assert_eq!(times[&CpuDataType::Cpu(0)].user, 40);
+    }
+
+    #[test]
+    fn test_first_reading_has_no_breakdown() {
+        let mut prev = HashMap::new();
+        assert!(get_cpu_breakdowns(&parse_stat(FIRST), &mut prev).is_empty());
+        assert_eq!(prev.len(), 3);
+    }
+
+    #[test]
+    fn test_breakdown_deltas() {
+        let mut prev = HashMap::new();
+        get_cpu_breakdowns(&parse_stat(FIRST), &mut prev);
+        let breakdowns = get_cpu_breakdowns(&parse_stat(SECOND), &mut prev);
+
+        // cpu0: 400 user (200 of which is guest), 100 system, 10 irq, 20 softirq, 20 steal.
+        let cpu0 = breakdowns[&CpuDataType::Cpu(0)];
+        let total = 550.0;
+        assert_eq!(cpu0.user, 200.0 / total * 100.0);
+        assert_eq!(cpu0.guest, 200.0 / total * 100.0);
+        assert_eq!(cpu0.system, 100.0 / total * 100.0);
+        assert_eq!(cpu0.idle, 0.0);
+        assert_eq!(cpu0.steal, 20.0 / total * 100.0);
+
+        // cpu1 was idle or waiting on I/O.
+        let cpu1 = breakdowns[&CpuDataType::Cpu(1)];
+        assert_eq!(cpu1.idle, 80.0);
+        assert_eq!(cpu1.iowait, 20.0);
+        assert_eq!(cpu1.user, 0.0);
+
+        let avg = breakdowns[&CpuDataType::Avg];
+        let sum = avg.user
+            + avg.nice
+            + avg.system
+            + avg.idle
+            + avg.iowait
+            + avg.irq
+            + avg.softirq
+            + avg.steal
+            + avg.guest;
+        assert!((sum - 100.0).abs() < 1e-9);
+    }
+}
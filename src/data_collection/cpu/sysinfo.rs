@@ -5,7 +5,7 @@ use std::collections::VecDeque;
 
 use sysinfo::{LoadAvg, System};
 
-use super::{CpuData, CpuDataType, CpuHarvest};
+use super::{CpuData, CpuDataType, CpuFrequency, CpuHarvest};
 use crate::data_collection::cpu::LoadAvgHarvest;
 
 pub fn get_cpu_data_list(sys: &System, show_average_cpu: bool) -> crate::error::Result<CpuHarvest> {
@@ -16,6 +16,12 @@ pub fn get_cpu_data_list(sys: &System, show_average_cpu: bool) -> crate::error::
         .map(|(i, cpu)| CpuData {
             data_type: CpuDataType::Cpu(i),
             cpu_usage: cpu.cpu_usage() as f64,
+            breakdown: None,
+            frequency: (cpu.frequency() > 0).then(|| CpuFrequency {
+                current_mhz: cpu.frequency(),
+                ..Default::default()
+            }),
+            topology: None,
         })
         .collect();
 
@@ -25,6 +31,9 @@ pub fn get_cpu_data_list(sys: &System, show_average_cpu: bool) -> crate::error::
         cpu_deque.push_front(CpuData {
             data_type: CpuDataType::Avg,
             cpu_usage: cpu.cpu_usage() as f64,
+            breakdown: None,
+            frequency: None,
+            topology: None,
         })
     }
 
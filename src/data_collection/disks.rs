@@ -29,10 +29,11 @@ cfg_if! {
 
 use cfg_if::cfg_if;
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::app::filter::Filter;
+use crate::app::{filter::Filter, DataFilters};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DiskHarvest {
     pub name: String,
     pub mount_point: String,
@@ -45,12 +46,93 @@ pub struct DiskHarvest {
     pub free_space: Option<u64>,
     pub used_space: Option<u64>,
     pub total_space: Option<u64>,
+
+    /// Inode counts, if the filesystem has a fixed number of inodes.
+    pub free_inodes: Option<u64>,
+    pub used_inodes: Option<u64>,
+    pub total_inodes: Option<u64>,
+
+    /// The filesystem type, like `ext4`.
+    pub fs_type: Option<String>,
+
+    /// The options the disk was mounted with, like `ro` or `noexec`.
+    #[serde(default)]
+    pub mount_options: Vec<String>,
+
+    /// The disks backing this one, like `/dev/sda` for `/dev/sda1`. This is only found on Linux.
+    #[serde(default)]
+    pub backing_devices: Vec<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IoData {
     pub read_bytes: u64,
     pub write_bytes: u64,
+    pub details: Option<IoDetails>,
+}
+
+/// I/O counters that only some platforms report. Times are in milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoDetails {
+    pub reads: u64,
+    pub writes: u64,
+    pub read_time_ms: u64,
+    pub write_time_ms: u64,
+    /// The number of requests currently in flight.
+    pub in_flight: u64,
+    /// How long the device has had requests in flight.
+    pub busy_time_ms: u64,
+    /// How long each request has spent in flight, summed up.
+    pub weighted_time_ms: u64,
+    pub discards: Option<u64>,
+    pub discard_time_ms: Option<u64>,
+    pub flushes: Option<u64>,
+    pub flush_time_ms: Option<u64>,
+}
+
+/// I/O activity over an interval, worked out from two [`IoDetails`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct IoRates {
+    pub read_ops_per_sec: f64,
+    pub write_ops_per_sec: f64,
+    /// The average time a read or write took, including time spent queued. [`None`] if there were
+    /// no reads or writes.
+    pub await_ms: Option<f64>,
+    /// The percentage of the interval that the device was busy.
+    pub busy_percent: f64,
+    /// The average number of requests in flight.
+    pub queue_size: f64,
+}
+
+impl IoDetails {
+    /// Returns the rates since `prev`, given the seconds elapsed since then.
+    pub fn rates_since(&self, prev: &IoDetails, elapsed_secs: f64) -> Option<IoRates> {
+        if elapsed_secs <= 0.0 {
+            return None;
+        }
+
+        let elapsed_ms = elapsed_secs * 1000.0;
+        let reads = self.reads.saturating_sub(prev.reads);
+        let writes = self.writes.saturating_sub(prev.writes);
+        let ops = reads + writes;
+        let op_time_ms = self.read_time_ms.saturating_sub(prev.read_time_ms)
+            + self.write_time_ms.saturating_sub(prev.write_time_ms);
+
+        Some(IoRates {
+            read_ops_per_sec: reads as f64 / elapsed_secs,
+            write_ops_per_sec: writes as f64 / elapsed_secs,
+            await_ms: if ops == 0 {
+                None
+            } else {
+                Some(op_time_ms as f64 / ops as f64)
+            },
+            busy_percent: (self.busy_time_ms.saturating_sub(prev.busy_time_ms) as f64 / elapsed_ms
+                * 100.0)
+                .min(100.0),
+            queue_size: self.weighted_time_ms.saturating_sub(prev.weighted_time_ms) as f64
+                / elapsed_ms,
+        })
+    }
 }
 
 pub type IoHarvest = HashMap<String, Option<IoData>>;
@@ -73,6 +155,7 @@ cfg_if! {
                     Some(IoData {
                         read_bytes: io.read_bytes(),
                         write_bytes: io.write_bytes(),
+                        details: io.details().copied(),
                     }),
                 );
             }
@@ -100,37 +183,66 @@ cfg_if! {
 pub fn keep_disk_entry(
     disk_name: &str, mount_point: &str, disk_filter: &Option<Filter>, mount_filter: &Option<Filter>,
 ) -> bool {
-    match (disk_filter, mount_filter) {
-        (Some(d), Some(m)) => match (d.is_list_ignored, m.is_list_ignored) {
-            (true, true) => !(d.has_match(disk_name) || m.has_match(mount_point)),
-            (true, false) => {
-                if m.has_match(mount_point) {
-                    true
-                } else {
-                    d.keep_entry(disk_name)
-                }
-            }
-            (false, true) => {
-                if d.has_match(disk_name) {
-                    true
-                } else {
-                    m.keep_entry(mount_point)
-                }
-            }
-            (false, false) => d.has_match(disk_name) || m.has_match(mount_point),
-        },
-        (Some(d), None) => d.keep_entry(disk_name),
-        (None, Some(m)) => m.keep_entry(mount_point),
-        (None, None) => true,
+    keep_disk_fields(&[(disk_filter, &[disk_name]), (mount_filter, &[mount_point])])
+}
+
+/// Whether to keep a disk given all the disk filters. This uses the same precedence as
+/// [`keep_disk_entry`], but also checks the filesystem type, mount options, and backing devices.
+pub fn keep_disk(disk: &DiskHarvest, filters: &DataFilters) -> bool {
+    let fs_type: Vec<&str> = disk.fs_type.iter().map(String::as_str).collect();
+    let mount_options: Vec<&str> = disk.mount_options.iter().map(String::as_str).collect();
+    let backing_devices: Vec<&str> = disk.backing_devices.iter().map(String::as_str).collect();
+
+    keep_disk_fields(&[
+        (&filters.disk_filter, &[disk.name.as_str()]),
+        (&filters.mount_filter, &[disk.mount_point.as_str()]),
+        (&filters.fs_type_filter, &fs_type),
+        (&filters.mount_options_filter, &mount_options),
+        (&filters.device_filter, &backing_devices),
+    ])
+}
+
+/// Checks each filter against the values of the field it filters. An entry matches a filter if
+/// any of the field's values match. Fields without any values (for example, ones a platform
+/// doesn't report) are skipped.
+///
+/// 1. If the entry matches a filter where `is_list_ignored` is `false`, keep it.
+/// 2. Otherwise, if it matches a filter where `is_list_ignored` is `true`, deny it.
+/// 3. Otherwise, deny it if every filter is one where `is_list_ignored` is `false`.
+/// 4. Anything else is allowed.
+fn keep_disk_fields(fields: &[(&Option<Filter>, &[&str])]) -> bool {
+    let mut is_denied = false;
+    let mut has_ignore_list = false;
+    let mut has_keep_list = false;
+
+    for (filter, values) in fields {
+        let Some(filter) = filter else {
+            continue;
+        };
+        if values.is_empty() {
+            continue;
+        }
+
+        let has_match = values.iter().any(|value| filter.has_match(value));
+        if filter.is_list_ignored {
+            has_ignore_list = true;
+            is_denied |= has_match;
+        } else if has_match {
+            return true;
+        } else {
+            has_keep_list = true;
+        }
     }
+
+    !(is_denied || (has_keep_list && !has_ignore_list))
 }
 
 #[cfg(test)]
 mod test {
     use regex::Regex;
 
-    use super::keep_disk_entry;
-    use crate::app::filter::Filter;
+    use super::{keep_disk, keep_disk_entry, DiskHarvest, IoDetails};
+    use crate::app::{filter::Filter, DataFilters};
 
     fn run_filter(disk_filter: &Option<Filter>, mount_filter: &Option<Filter>) -> Vec<usize> {
         let targets = [
@@ -190,4 +302,119 @@ mod test {
         assert_eq!(run_filter(&disk_ignore, &mount_keep), vec![0, 3, 4]);
         assert_eq!(run_filter(&disk_keep, &mount_keep), vec![0, 1, 2, 4]);
     }
+
+    #[test]
+    fn test_keeping_disk_by_metadata() {
+        let disks = [
+            DiskHarvest {
+                name: "/dev/sda1".into(),
+                mount_point: "/".into(),
+                fs_type: Some("ext4".into()),
+                mount_options: vec!["rw".into(), "relatime".into()],
+                backing_devices: vec!["/dev/sda".into()],
+                ..Default::default()
+            },
+            DiskHarvest {
+                name: "/dev/sdb1".into(),
+                mount_point: "/mnt/usb".into(),
+                fs_type: Some("vfat".into()),
+                mount_options: vec!["ro".into(), "noexec".into()],
+                backing_devices: vec!["/dev/sdb".into()],
+                ..Default::default()
+            },
+            DiskHarvest {
+                name: "rpool/data".into(),
+                mount_point: "/data".into(),
+                ..Default::default()
+            },
+        ];
+        let filter = |is_list_ignored, regex: &str| {
+            Some(Filter {
+                is_list_ignored,
+                list: vec![Regex::new(regex).unwrap()],
+            })
+        };
+        let no_filters = DataFilters {
+            disk_filter: None,
+            mount_filter: None,
+            fs_type_filter: None,
+            mount_options_filter: None,
+            device_filter: None,
+            temp_filter: None,
+            sensor_filter: None,
+            net_filter: None,
+            cgroup_filter: None,
+        };
+        let run_filter = |filters: &DataFilters| {
+            disks
+                .iter()
+                .enumerate()
+                .filter_map(|(itx, disk)| keep_disk(disk, filters).then_some(itx))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run_filter(&no_filters), vec![0, 1, 2]);
+
+        let filters = DataFilters {
+            fs_type_filter: filter(true, "^vfat$"),
+            ..no_filters.clone()
+        };
+        assert_eq!(run_filter(&filters), vec![0, 2]);
+
+        let filters = DataFilters {
+            mount_options_filter: filter(true, "^noexec$"),
+            ..no_filters.clone()
+        };
+        assert_eq!(run_filter(&filters), vec![0, 2]);
+
+        // Fields that aren't reported aren't filtered on, so the ZFS dataset is kept.
+        let filters = DataFilters {
+            device_filter: filter(false, "^/dev/sda$"),
+            ..no_filters.clone()
+        };
+        assert_eq!(run_filter(&filters), vec![0, 2]);
+
+        // Allowing an entry takes precedence over denying it.
+        let filters = DataFilters {
+            mount_filter: filter(true, "^/mnt/"),
+            mount_options_filter: filter(false, "^ro$"),
+            ..no_filters.clone()
+        };
+        assert_eq!(run_filter(&filters), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_io_rates() {
+        let prev = IoDetails {
+            reads: 100,
+            writes: 50,
+            read_time_ms: 200,
+            write_time_ms: 300,
+            busy_time_ms: 1000,
+            weighted_time_ms: 2000,
+            ..Default::default()
+        };
+        let current = IoDetails {
+            reads: 300,
+            writes: 250,
+            read_time_ms: 600,
+            write_time_ms: 1500,
+            busy_time_ms: 2000,
+            weighted_time_ms: 5000,
+            ..Default::default()
+        };
+
+        let rates = current.rates_since(&prev, 2.0).unwrap();
+        assert_eq!(rates.read_ops_per_sec, 100.0);
+        assert_eq!(rates.write_ops_per_sec, 100.0);
+        assert_eq!(rates.await_ms, Some(4.0));
+        assert_eq!(rates.busy_percent, 50.0);
+        assert_eq!(rates.queue_size, 1.5);
+
+        let rates = prev.rates_since(&prev, 1.0).unwrap();
+        assert_eq!(rates.await_ms, None);
+        assert_eq!(rates.busy_percent, 0.0);
+
+        assert!(current.rates_since(&prev, 0.0).is_none());
+    }
 }
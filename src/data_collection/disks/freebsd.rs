@@ -50,6 +50,7 @@ pub fn get_io_usage() -> error::Result<IoHarvest> {
                     Some(IoData {
                         read_bytes: io.read_bytes(),
                         write_bytes: io.write_bytes(),
+                        details: io.details().copied(),
                     }),
                 );
             }
@@ -73,6 +74,7 @@ pub fn get_disk_usage(collector: &DataCollector) -> error::Result<Vec<DiskHarves
                         total_space: Some(disk.total_blocks * 1024),
                         mount_point: disk.mounted_on,
                         name: disk.name,
+                        ..Default::default()
                     })
                 } else {
                     None
@@ -1,10 +1,13 @@
 use std::ffi::OsStr;
 
+use super::IoDetails;
+
 #[derive(Debug, Default)]
 pub struct IoCounters {
     name: String,
     read_bytes: u64,
     write_bytes: u64,
+    details: Option<IoDetails>,
 }
 
 impl IoCounters {
@@ -13,9 +16,17 @@ impl IoCounters {
             name,
             read_bytes,
             write_bytes,
+            details: None,
         }
     }
 
+    /// Adds the counters that only some platforms report.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub fn with_details(mut self, details: IoDetails) -> Self {
+        self.details = Some(details);
+        self
+    }
+
     pub(crate) fn device_name(&self) -> &OsStr {
         OsStr::new(&self.name)
     }
@@ -27,4 +38,8 @@ impl IoCounters {
     pub(crate) fn write_bytes(&self) -> u64 {
         self.write_bytes
     }
+
+    pub(crate) fn details(&self) -> Option<&IoDetails> {
+        self.details.as_ref()
+    }
 }
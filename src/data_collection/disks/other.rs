@@ -1,12 +1,10 @@
 //! Fallback disk info using sysinfo.
 
-use super::{keep_disk_entry, DiskHarvest};
+use super::{keep_disk, DiskHarvest};
 use crate::data_collection::DataCollector;
 
 pub(crate) fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarvest>> {
     let disks = &collector.sys.disks;
-    let disk_filter = &collector.filters.disk_filter;
-    let mount_filter = &collector.filters.mount_filter;
 
     Ok(disks
         .iter()
@@ -30,18 +28,22 @@ pub(crate) fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<Di
                 .into_string()
                 .unwrap_or_else(|_| "Mount Unavailable".to_string());
 
-            if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter) {
+            let fs_type = disk.file_system().to_string_lossy();
+            let mut harvest = DiskHarvest {
+                name,
+                mount_point,
+                fs_type: (!fs_type.is_empty()).then(|| fs_type.to_string()),
+                ..Default::default()
+            };
+
+            if keep_disk(&harvest, &collector.filters) {
                 let free_space = disk.available_space();
                 let total_space = disk.total_space();
-                let used_space = total_space - free_space;
-
-                Some(DiskHarvest {
-                    name,
-                    mount_point,
-                    free_space: Some(free_space),
-                    used_space: Some(used_space),
-                    total_space: Some(total_space),
-                })
+                harvest.free_space = Some(free_space);
+                harvest.used_space = Some(total_space - free_space);
+                harvest.total_space = Some(total_space);
+
+                Some(harvest)
             } else {
                 None
             }
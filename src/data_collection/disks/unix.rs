@@ -24,48 +24,64 @@ cfg_if::cfg_if! {
 use file_systems::*;
 use usage::*;
 
-use super::{keep_disk_entry, DiskHarvest};
+use super::{keep_disk, DiskHarvest};
 use crate::data_collection::DataCollector;
 
 /// Returns the disk usage of the mounted (and for now, physical) disks.
-pub fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarvest>> {
-    let disk_filter = &collector.filters.disk_filter;
-    let mount_filter = &collector.filters.mount_filter;
+pub fn get_disk_usage(collector: &mut DataCollector) -> anyhow::Result<Vec<DiskHarvest>> {
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
+    let filters = &collector.filters;
+    let cache = &mut collector.backing_devices;
+
+    // Finding the backing devices walks sysfs, so it is cached until the collector clears it, and
+    // only done for disks that are kept unless a filter needs them.
+    let mut backing_devices = |partition: &Partition, name: &str| {
+        cache
+            .entry_ref(name)
+            .or_insert_with(|| partition.backing_devices())
+            .clone()
+    };
+    let filter_by_device = filters.device_filter.is_some();
 
     for partition in physical_partitions()? {
-        let name = partition.get_device_name();
-        let mount_point = partition.mount_point().to_string_lossy().to_string();
+        let mut disk = DiskHarvest {
+            name: partition.get_device_name(),
+            mount_point: partition.mount_point().to_string_lossy().to_string(),
+            fs_type: Some(partition.fs_type().as_str().to_string()),
+            mount_options: partition.mount_options().to_vec(),
+            ..Default::default()
+        };
 
-        // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
-        //
-        // For implementation, we do this as follows:
-        // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
-        // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
-        // 3. Anything else is allowed.
+        if filter_by_device {
+            disk.backing_devices = backing_devices(&partition, &disk.name);
+        }
+
+        // Precedence ordering in the case where filters disagree, "allow" takes precedence over "deny".
+        // See `keep_disk` for details.
+        if keep_disk(&disk, filters) {
+            if !filter_by_device {
+                disk.backing_devices = backing_devices(&partition, &disk.name);
+            }
 
-        if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter) {
             // The usage line can fail in some cases (for example, if you use Void Linux + LUKS,
             // see https://github.com/ClementTsang/bottom/issues/419 for details).
             if let Ok(usage) = partition.usage() {
                 let total = usage.total();
+                disk.free_space = Some(usage.free());
+                disk.used_space = Some(total - usage.available());
+                disk.total_space = Some(total);
 
-                vec_disks.push(DiskHarvest {
-                    free_space: Some(usage.free()),
-                    used_space: Some(total - usage.available()),
-                    total_space: Some(total),
-                    mount_point,
-                    name,
-                });
-            } else {
-                vec_disks.push(DiskHarvest {
-                    free_space: None,
-                    used_space: None,
-                    total_space: None,
-                    mount_point,
-                    name,
-                });
+                // Filesystems that allocate inodes as needed report having none.
+                let total_inodes = usage.total_inodes();
+                if total_inodes > 0 {
+                    let free_inodes = usage.free_inodes();
+                    disk.free_inodes = Some(free_inodes);
+                    disk.used_inodes = Some(total_inodes.saturating_sub(free_inodes));
+                    disk.total_inodes = Some(total_inodes);
+                }
             }
+
+            vec_disks.push(disk);
         }
     }
 
@@ -4,10 +4,9 @@ use std::{
     fs::File,
     io::{self, BufRead, BufReader},
     num::ParseIntError,
-    str::FromStr,
 };
 
-use crate::data_collection::disks::IoCounters;
+use crate::data_collection::disks::{IoCounters, IoDetails};
 
 /// Copied from the `psutil` sources:
 ///
@@ -23,44 +22,69 @@ use crate::data_collection::disks::IoCounters;
 /// * <https://lkml.org/lkml/2015/8/17/234>
 const DISK_SECTOR_SIZE: u64 = 512;
 
-impl FromStr for IoCounters {
-    type Err = anyhow::Error;
-
-    /// Converts a `&str` to an [`IoCounters`].
-    ///
-    /// Follows the format used in Linux 2.6+. Note that this completely ignores the following stats:
-    /// - Discard stats from 4.18+
-    /// - Flush stats from 5.5+
-    ///
-    /// <https://www.kernel.org/doc/Documentation/iostats.txt>
-    /// <https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats>
-    fn from_str(s: &str) -> anyhow::Result<IoCounters> {
-        fn next_part<'a>(iter: &mut impl Iterator<Item = &'a str>) -> Result<&'a str, io::Error> {
-            iter.next()
-                .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
-        }
-
-        fn next_part_to_u64<'a>(iter: &mut impl Iterator<Item = &'a str>) -> anyhow::Result<u64> {
-            next_part(iter)?
-                .parse()
-                .map_err(|err: ParseIntError| err.into())
-        }
-
-        // Skip the major and minor numbers.
-        let mut parts = s.split_whitespace().skip(2);
-
-        let name = next_part(&mut parts)?.to_string();
-
-        // Skip read count, read merged count.
-        let mut parts = parts.skip(2);
-        let read_bytes = next_part_to_u64(&mut parts)? * DISK_SECTOR_SIZE;
-
-        // Skip read time seconds, write count, and write merged count.
-        let mut parts = parts.skip(3);
-        let write_bytes = next_part_to_u64(&mut parts)? * DISK_SECTOR_SIZE;
+/// Parses a line of `/proc/diskstats` into an [`IoCounters`].
+///
+/// Follows the format used in Linux 2.6+. Discard stats are only reported on 4.18+, and flush
+/// stats on 5.5+.
+///
+/// <https://www.kernel.org/doc/Documentation/iostats.txt>
+/// <https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats>
+fn parse_diskstats_line(s: &str) -> anyhow::Result<IoCounters> {
+    fn next_part<'a>(iter: &mut impl Iterator<Item = &'a str>) -> Result<&'a str, io::Error> {
+        iter.next()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+    }
 
-        Ok(IoCounters::new(name, read_bytes, write_bytes))
+    fn next_part_to_u64<'a>(iter: &mut impl Iterator<Item = &'a str>) -> anyhow::Result<u64> {
+        next_part(iter)?
+            .parse()
+            .map_err(|err: ParseIntError| err.into())
     }
+
+    // Skip the major and minor numbers.
+    let mut parts = s.split_whitespace().skip(2);
+
+    let name = next_part(&mut parts)?.to_string();
+
+    let reads = next_part_to_u64(&mut parts)?;
+    let _reads_merged = next_part_to_u64(&mut parts)?;
+    let read_bytes = next_part_to_u64(&mut parts)?.saturating_mul(DISK_SECTOR_SIZE);
+    let read_time_ms = next_part_to_u64(&mut parts)?;
+
+    let writes = next_part_to_u64(&mut parts)?;
+    let _writes_merged = next_part_to_u64(&mut parts)?;
+    let write_bytes = next_part_to_u64(&mut parts)?.saturating_mul(DISK_SECTOR_SIZE);
+    let write_time_ms = next_part_to_u64(&mut parts)?;
+
+    let in_flight = next_part_to_u64(&mut parts)?;
+    let busy_time_ms = next_part_to_u64(&mut parts)?;
+    let weighted_time_ms = next_part_to_u64(&mut parts)?;
+
+    // Discards are reported as a count, merged count, sectors, and time.
+    let (discards, discard_time_ms) = {
+        let discards = parts.next().and_then(|part| part.parse().ok());
+        let mut parts = parts.by_ref().skip(2);
+        (discards, parts.next().and_then(|part| part.parse().ok()))
+    };
+
+    let flushes = parts.next().and_then(|part| part.parse().ok());
+    let flush_time_ms = parts.next().and_then(|part| part.parse().ok());
+
+    Ok(
+        IoCounters::new(name, read_bytes, write_bytes).with_details(IoDetails {
+            reads,
+            writes,
+            read_time_ms,
+            write_time_ms,
+            in_flight,
+            busy_time_ms,
+            weighted_time_ms,
+            discards,
+            discard_time_ms,
+            flushes,
+            flush_time_ms,
+        }),
+    )
 }
 
 /// Returns an iterator of disk I/O stats. Pulls data from `/proc/diskstats`.
@@ -74,7 +98,7 @@ pub fn io_stats() -> anyhow::Result<Vec<IoCounters>> {
     // This saves us from doing a string allocation on each iteration compared to `lines()`.
     while let Ok(bytes) = reader.read_line(&mut line) {
         if bytes > 0 {
-            if let Ok(counters) = IoCounters::from_str(&line) {
+            if let Ok(counters) = parse_diskstats_line(&line) {
                 results.push(counters);
             }
             line.clear();
@@ -93,3 +117,48 @@ pub fn io_stats() -> anyhow::Result<Vec<IoCounters>> {
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_diskstats_line() {
+        // From a 5.5+ kernel, with discard and flush stats.
+        let line = " 259       0 nvme0n1 112 13 8496 31 9051 2345 240370 4873 2 6320 5502 10 0 2048 3 591 595\n";
+        let counters = parse_diskstats_line(line).unwrap();
+
+        assert_eq!(counters.device_name(), "nvme0n1");
+        assert_eq!(counters.read_bytes(), 8496 * 512);
+        assert_eq!(counters.write_bytes(), 240370 * 512);
+        assert_eq!(
+            counters.details(),
+            Some(&IoDetails {
+                reads: 112,
+                writes: 9051,
+                read_time_ms: 31,
+                write_time_ms: 4873,
+                in_flight: 2,
+                busy_time_ms: 6320,
+                weighted_time_ms: 5502,
+                discards: Some(10),
+                discard_time_ms: Some(3),
+                flushes: Some(591),
+                flush_time_ms: Some(595),
+            })
+        );
+
+        // From an older kernel, without them.
+        let line = "   8       1 sda1 4 0 16 1 2 0 8 3 0 4 4";
+        let counters = parse_diskstats_line(line).unwrap();
+
+        assert_eq!(counters.read_bytes(), 16 * 512);
+        assert_eq!(counters.write_bytes(), 8 * 512);
+
+        let details = counters.details().unwrap();
+        assert_eq!(details.discards, None);
+        assert_eq!(details.flushes, None);
+
+        assert!(parse_diskstats_line("   8       1 sda1 4 0 16").is_err());
+    }
+}
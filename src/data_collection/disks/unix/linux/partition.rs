@@ -3,7 +3,7 @@
 
 use std::{
     ffi::CString,
-    fs::File,
+    fs::{self, File},
     io::{self, BufRead, BufReader},
     mem,
     path::{Path, PathBuf},
@@ -14,11 +14,15 @@ use anyhow::bail;
 
 use crate::data_collection::disks::unix::{FileSystem, Usage};
 
+/// Where block devices and their partitions are listed.
+const SYS_CLASS_BLOCK: &str = "/sys/class/block";
+
 /// Representation of partition details. Based on [`heim`](https://github.com/heim-rs/heim/tree/master).
 pub(crate) struct Partition {
     device: Option<String>,
     mount_point: PathBuf,
     fs_type: FileSystem,
+    options: Vec<String>,
 }
 
 impl Partition {
@@ -40,6 +44,17 @@ impl Partition {
         &self.fs_type
     }
 
+    /// Returns the options this partition was mounted with, like `rw` or `noexec`.
+    #[inline]
+    pub fn mount_options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Returns the disks backing the partition, like `/dev/sda` for `/dev/sda1`.
+    pub fn backing_devices(&self) -> Vec<String> {
+        backing_devices(Path::new(SYS_CLASS_BLOCK), &self.get_device_name())
+    }
+
     /// Returns the device name for the partition.
     pub fn get_device_name(&self) -> String {
         if let Some(device) = self.device() {
@@ -128,15 +143,70 @@ impl FromStr for Partition {
                 bail!("missing filesystem type");
             }
         };
+        let options = match parts.next() {
+            Some(options) => options.split(',').map(str::to_string).collect(),
+            None => Vec::new(),
+        };
 
         Ok(Partition {
             device,
             mount_point,
             fs_type,
+            options,
         })
     }
 }
 
+/// Returns the disks backing `device`, found by walking `root` (usually `/sys/class/block`). A
+/// partition is backed by the disk it is on, and a device-mapper or RAID device by every disk
+/// under the devices it is built on. Returns nothing if `device` isn't a block device.
+fn backing_devices(root: &Path, device: &str) -> Vec<String> {
+    fn add_backing_devices(root: &Path, name: &str, devices: &mut Vec<String>) {
+        let path = root.join(name);
+        if !path.exists() {
+            return;
+        }
+
+        let mut slaves = fs::read_dir(path.join("slaves"))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if !slaves.is_empty() {
+            slaves.sort();
+            for slave in slaves {
+                add_backing_devices(root, &slave, devices);
+            }
+            return;
+        }
+
+        let backing = if path.join("partition").exists() {
+            // A partition's directory is nested in its disk's.
+            fs::canonicalize(&path)
+                .ok()
+                .and_then(|path| Some(path.parent()?.file_name()?.to_str()?.to_string()))
+                .unwrap_or_else(|| name.to_string())
+        } else {
+            name.to_string()
+        };
+
+        let backing = format!("/dev/{backing}");
+        if !devices.contains(&backing) {
+            devices.push(backing);
+        }
+    }
+
+    let mut devices = Vec::new();
+    if let Some(name) = device.strip_prefix("/dev/") {
+        add_backing_devices(root, name, &mut devices);
+    }
+
+    devices
+}
+
 #[allow(dead_code)]
 /// Returns a [`Vec`] containing all partitions.
 pub(crate) fn partitions() -> anyhow::Result<Vec<Partition>> {
@@ -188,3 +258,35 @@ pub(crate) fn physical_partitions() -> anyhow::Result<Vec<Partition>> {
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_mount_line() {
+        let partition = Partition::from_str("/dev/sda3 /home ext4 rw,nosuid,relatime 0 0").unwrap();
+
+        assert_eq!(partition.device(), Some("/dev/sda3"));
+        assert_eq!(partition.mount_point(), Path::new("/home"));
+        assert_eq!(partition.fs_type(), &FileSystem::Ext4);
+        assert_eq!(partition.mount_options(), ["rw", "nosuid", "relatime"]);
+
+        assert!(Partition::from_str("/dev/sda3 /home").is_err());
+    }
+
+    #[test]
+    fn test_backing_devices() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/block");
+
+        assert_eq!(backing_devices(&root, "/dev/sda"), vec!["/dev/sda"]);
+        assert_eq!(backing_devices(&root, "/dev/sda1"), vec!["/dev/sda"]);
+        assert_eq!(backing_devices(&root, "/dev/dm-0"), vec!["/dev/sda"]);
+        assert_eq!(
+            backing_devices(&root, "/dev/md0"),
+            vec!["/dev/sda", "/dev/sdb"]
+        );
+        assert!(backing_devices(&root, "/dev/missing").is_empty());
+        assert!(backing_devices(&root, "rpool/ROOT").is_empty());
+    }
+}
@@ -14,6 +14,7 @@ pub(crate) struct Partition {
     device: String,
     mount_point: PathBuf,
     fs_type: FileSystem,
+    options: Vec<String>,
 }
 
 impl Partition {
@@ -29,6 +30,18 @@ impl Partition {
         &self.fs_type
     }
 
+    /// Returns the options this partition was mounted with, like `rw` or `noexec`.
+    #[inline]
+    pub fn mount_options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Returns the disks backing the partition. This isn't supported here, so it is always empty.
+    #[inline]
+    pub fn backing_devices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Returns the usage stats for this partition.
     pub fn usage(&self) -> anyhow::Result<Usage> {
         let path = CString::new(self.mount_point().as_os_str().as_bytes())?;
@@ -79,10 +92,34 @@ fn partitions_iter() -> anyhow::Result<impl Iterator<Item = Partition>> {
             device,
             mount_point,
             fs_type,
+            options: mount_options(stat.f_flags),
         }
     }))
 }
 
+/// Returns the mount options set in a `statfs`'s flags, named like they are in `mount`.
+fn mount_options(flags: u32) -> Vec<String> {
+    let flags = flags as libc::c_int;
+    let mut options = vec![if flags & libc::MNT_RDONLY != 0 {
+        "ro".to_string()
+    } else {
+        "rw".to_string()
+    }];
+
+    for (flag, option) in [
+        (libc::MNT_NOEXEC, "noexec"),
+        (libc::MNT_NOSUID, "nosuid"),
+        (libc::MNT_NODEV, "nodev"),
+        (libc::MNT_NOATIME, "noatime"),
+    ] {
+        if flags & flag != 0 {
+            options.push(option.to_string());
+        }
+    }
+
+    options
+}
+
 #[allow(dead_code)]
 /// Returns a [`Vec`] containing all partitions.
 pub(crate) fn partitions() -> anyhow::Result<Vec<Partition>> {
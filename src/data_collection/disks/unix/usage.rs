@@ -29,4 +29,15 @@ impl Usage {
     pub fn free(&self) -> u64 {
         u64::from(self.0.f_bavail) * u64::from(self.0.f_frsize)
     }
+
+    /// Returns the total number of inodes. Some filesystems (e.g. btrfs) allocate inodes as needed,
+    /// and report zero here.
+    pub fn total_inodes(&self) -> u64 {
+        u64::from(self.0.f_files)
+    }
+
+    /// Returns the number of free inodes.
+    pub fn free_inodes(&self) -> u64 {
+        u64::from(self.0.f_ffree)
+    }
 }
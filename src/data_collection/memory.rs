@@ -15,9 +15,117 @@ pub mod sysinfo;
 #[cfg(feature = "zfs")]
 pub mod arc;
 
-#[derive(Debug, Clone, Default)]
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+        mod zram;
+        pub(crate) use self::linux::*;
+        pub(crate) use self::zram::*;
+    }
+}
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MemHarvest {
     pub used_bytes: u64,
     pub total_bytes: u64,
     pub use_percent: Option<f64>, // TODO: Might be find to just make this an f64, and any consumer checks NaN.
 }
+
+/// A breakdown of where memory is going, in bytes. This is only available on Linux, and a field is
+/// [`None`] if the kernel does not report it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemDetails {
+    pub total: Option<u64>,
+    pub free: Option<u64>,
+    /// An estimate of how much memory can be allocated without swapping, including memory that
+    /// can be reclaimed from caches.
+    pub available: Option<u64>,
+    pub buffers: Option<u64>,
+    /// The page cache. This includes shared memory.
+    pub cached: Option<u64>,
+    /// Shared memory, including tmpfs.
+    pub shared: Option<u64>,
+    pub slab_reclaimable: Option<u64>,
+    pub slab_unreclaimable: Option<u64>,
+    pub dirty: Option<u64>,
+    pub writeback: Option<u64>,
+    pub anon_huge_pages: Option<u64>,
+    /// The size of the hugetlb pool, and how much of it is unused.
+    pub hugetlb_total: Option<u64>,
+    pub hugetlb_free: Option<u64>,
+    pub kernel_stack: Option<u64>,
+    pub page_tables: Option<u64>,
+    /// How much memory can be committed under strict overcommit, and how much is committed now.
+    pub commit_limit: Option<u64>,
+    pub committed: Option<u64>,
+    /// The size of the zswap pool, and how much data is stored in it before compression. Older
+    /// kernels don't report these.
+    pub zswap: Option<u64>,
+    pub zswapped: Option<u64>,
+}
+
+/// Cumulative paging activity since boot. This is only available on Linux.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PagingCounters {
+    /// Pages read in from swap.
+    pub swap_in: u64,
+    /// Pages written out to swap.
+    pub swap_out: u64,
+    /// Page faults that had to read from disk.
+    pub major_faults: u64,
+    /// Pages scanned while reclaiming memory, either by kswapd or directly by allocations.
+    pub scanned: u64,
+    /// Pages actually reclaimed.
+    pub stolen: u64,
+}
+
+/// The per-second rates of each paging counter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PagingRates {
+    pub swap_in: f64,
+    pub swap_out: f64,
+    pub major_faults: f64,
+    pub scanned: f64,
+    pub stolen: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PagingHarvest {
+    pub total: PagingCounters,
+    /// The rates since the previous collection. This is [`None`] on the first collection.
+    pub per_sec: Option<PagingRates>,
+}
+
+/// A zram device that has been set up, usually as swap. Sizes are in bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZramHarvest {
+    pub name: String,
+    /// How much data is stored, before compression.
+    pub original_bytes: u64,
+    pub compressed_bytes: u64,
+    /// How much memory the device uses, including its own overhead.
+    pub used_bytes: u64,
+}
+
+/// The zswap pool, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZswapHarvest {
+    /// How much memory the compressed pool uses.
+    pub pool_bytes: u64,
+    /// How much data is stored in the pool, before compression. This may not be available.
+    pub stored_bytes: Option<u64>,
+}
+
+/// How well the ZFS ARC is doing. Sizes are in bytes.
+#[cfg(feature = "zfs")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArcDetails {
+    /// The percentage of lookups that were hits since the last collection, or [`None`] if there
+    /// weren't any.
+    pub hit_percent: Option<f64>,
+    /// How much of the ARC holds recently used data, and how much holds frequently used data.
+    pub mru_bytes: u64,
+    pub mfu_bytes: u64,
+}
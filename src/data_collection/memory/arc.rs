@@ -1,67 +1,89 @@
-use super::MemHarvest;
+use super::{ArcDetails, MemHarvest};
 
-/// Return ARC usage.
-#[cfg(feature = "zfs")]
-pub(crate) fn get_arc_usage() -> Option<MemHarvest> {
-    let (mem_total, mem_used) = {
-        cfg_if::cfg_if! {
-            if #[cfg(target_os = "linux")] {
-                // TODO: [OPT] is this efficient?
-                use std::fs::read_to_string;
-                if let Ok(arc_stats) = read_to_string("/proc/spl/kstat/zfs/arcstats") {
-                    let mut mem_arc = 0;
-                    let mut mem_total = 0;
-                    let mut zfs_keys_read: u8 = 0;
-                    const ZFS_KEYS_NEEDED: u8 = 2;
-
-                    for line in arc_stats.lines() {
-                        if let Some((label, value)) = line.split_once(' ') {
-                            let to_write = match label {
-                                "size" => &mut mem_arc,
-                                "c_max" => &mut mem_total,
-                                _ => {
-                                    continue;
-                                }
-                            };
-
-                            if let Some((_type, number)) = value.trim_start().rsplit_once(' ') {
-                                // Parse the value, remember it's in bytes!
-                                if let Ok(number) = number.parse::<u64>() {
-                                    *to_write = number;
-                                    // We only need a few keys, so we can bail early.
-                                    zfs_keys_read += 1;
-                                    if zfs_keys_read == ZFS_KEYS_NEEDED {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    (mem_total, mem_arc)
-                } else {
-                    (0, 0)
+/// The ARC statistics we use. Sizes are in bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ArcStats {
+    size: u64,
+    c_max: u64,
+    hits: u64,
+    misses: u64,
+    mru_size: u64,
+    mfu_size: u64,
+}
+
+/// Parses the contents of `/proc/spl/kstat/zfs/arcstats`, where each line is a name, a type, and
+/// a value.
+#[cfg(target_os = "linux")]
+fn parse_arcstats(arc_stats: &str) -> ArcStats {
+    let mut stats = ArcStats::default();
+
+    for line in arc_stats.lines() {
+        if let Some((label, value)) = line.split_once(' ') {
+            let to_write = match label {
+                "size" => &mut stats.size,
+                "c_max" => &mut stats.c_max,
+                "hits" => &mut stats.hits,
+                "misses" => &mut stats.misses,
+                "mru_size" => &mut stats.mru_size,
+                "mfu_size" => &mut stats.mfu_size,
+                _ => {
+                    continue;
                 }
-            } else if #[cfg(target_os = "freebsd")] {
-                use sysctl::Sysctl;
-                if let (Ok(mem_arc_value), Ok(mem_sys_value)) = (
-                    sysctl::Ctl::new("kstat.zfs.misc.arcstats.size"),
-                    sysctl::Ctl::new("kstat.zfs.misc.arcstats.c_max"),
-                ) {
-                    if let (Ok(sysctl::CtlValue::U64(arc)), Ok(sysctl::CtlValue::Ulong(mem))) =
-                        (mem_arc_value.value(), mem_sys_value.value())
-                    {
-                        (mem, arc)
-                    } else {
-                        (0, 0)
-                    }
-                } else {
-                    (0, 0)
+            };
+
+            if let Some((_type, number)) = value.trim_start().rsplit_once(' ') {
+                // Parse the value, remember it's in bytes!
+                if let Ok(number) = number.parse::<u64>() {
+                    *to_write = number;
                 }
+            }
+        }
+    }
+
+    stats
+}
+
+/// Returns the ARC statistics, or all zeroes if they couldn't be read.
+#[cfg(feature = "zfs")]
+pub(crate) fn get_arc_stats() -> ArcStats {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "linux")] {
+            // TODO: [OPT] is this efficient?
+            use std::fs::read_to_string;
+            if let Ok(arc_stats) = read_to_string("/proc/spl/kstat/zfs/arcstats") {
+                parse_arcstats(&arc_stats)
             } else {
-                (0, 0)
+                ArcStats::default()
+            }
+        } else if #[cfg(target_os = "freebsd")] {
+            use sysctl::Sysctl;
+
+            let get = |name: &str| match sysctl::Ctl::new(&format!("kstat.zfs.misc.arcstats.{name}"))
+                .and_then(|ctl| ctl.value())
+            {
+                Ok(sysctl::CtlValue::U64(value)) => value,
+                Ok(sysctl::CtlValue::Ulong(value)) => value as u64,
+                _ => 0,
+            };
+
+            ArcStats {
+                size: get("size"),
+                c_max: get("c_max"),
+                hits: get("hits"),
+                misses: get("misses"),
+                mru_size: get("mru_size"),
+                mfu_size: get("mfu_size"),
             }
+        } else {
+            ArcStats::default()
         }
-    };
+    }
+}
+
+/// Return ARC usage.
+#[cfg(feature = "zfs")]
+pub(crate) fn get_arc_usage(stats: &ArcStats) -> Option<MemHarvest> {
+    let (mem_total, mem_used) = (stats.c_max, stats.size);
 
     Some(MemHarvest {
         total_bytes: mem_total,
@@ -73,3 +95,76 @@ pub(crate) fn get_arc_usage() -> Option<MemHarvest> {
         },
     })
 }
+
+/// Returns the ARC's hit ratio and how it is split between recently and frequently used data.
+/// `prev_counters` holds the hits and misses from the last call, so that the hit ratio covers
+/// just the time since then, and is replaced by the new counts. Without them, the hit ratio covers
+/// everything since the ARC was created.
+#[cfg(feature = "zfs")]
+pub(crate) fn get_arc_details(
+    stats: &ArcStats, prev_counters: &mut Option<(u64, u64)>,
+) -> Option<ArcDetails> {
+    if stats.c_max == 0 {
+        return None;
+    }
+
+    let (prev_hits, prev_misses) = prev_counters
+        .replace((stats.hits, stats.misses))
+        .unwrap_or_default();
+
+    Some(ArcDetails {
+        hit_percent: hit_percent(
+            stats.hits.saturating_sub(prev_hits),
+            stats.misses.saturating_sub(prev_misses),
+        ),
+        mru_bytes: stats.mru_size,
+        mfu_bytes: stats.mfu_size,
+    })
+}
+
+/// Returns the percentage of lookups that were hits, or [`None`] if there weren't any lookups.
+fn hit_percent(hits: u64, misses: u64) -> Option<f64> {
+    let lookups = hits.saturating_add(misses);
+    if lookups == 0 {
+        None
+    } else {
+        Some(hits as f64 / lookups as f64 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_arcstats() {
+        let contents = "13 1 0x01 123 33456 5270587045 1318291658633716
+name                            type data
+hits                            4    9000
+misses                          4    1000
+mru_size                        4    1048576
+mfu_size                        4    2097152
+size                            4    3221225472
+c_max                           4    8589934592
+";
+
+        assert_eq!(
+            parse_arcstats(contents),
+            ArcStats {
+                size: 3221225472,
+                c_max: 8589934592,
+                hits: 9000,
+                misses: 1000,
+                mru_size: 1048576,
+                mfu_size: 2097152,
+            }
+        );
+    }
+
+    #[test]
+    fn test_hit_percent() {
+        assert_eq!(hit_percent(90, 10), Some(90.0));
+        assert_eq!(hit_percent(0, 0), None);
+    }
+}
@@ -0,0 +1,331 @@
+//! Reads a detailed breakdown of memory usage and paging activity from procfs.
+
+use std::{fs, path::Path, time::Duration};
+
+use hashbrown::HashMap;
+
+use super::{MemDetails, PagingCounters, PagingHarvest, PagingRates};
+
+/// Where the kernel reports memory statistics.
+pub(crate) const MEMINFO_PATH: &str = "/proc/meminfo";
+
+/// Where the kernel reports virtual memory event counters.
+pub(crate) const VMSTAT_PATH: &str = "/proc/vmstat";
+
+/// Parses the contents of `/proc/meminfo`. Most values are in kibibytes, but the hugepage counts
+/// are a number of pages.
+fn parse_meminfo(contents: &str) -> MemDetails {
+    let values: HashMap<&str, u64> = contents
+        .lines()
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            let mut parts = rest.split_whitespace();
+            let value: u64 = parts.next()?.parse().ok()?;
+            let value = match parts.next() {
+                Some("kB") => value.saturating_mul(1024),
+                _ => value,
+            };
+
+            Some((key, value))
+        })
+        .collect();
+
+    let get = |key: &str| values.get(key).copied();
+    let hugepages = |key: &str| Some(get(key)?.saturating_mul(get("Hugepagesize")?));
+
+    MemDetails {
+        total: get("MemTotal"),
+        free: get("MemFree"),
+        available: get("MemAvailable"),
+        buffers: get("Buffers"),
+        cached: get("Cached"),
+        shared: get("Shmem"),
+        slab_reclaimable: get("SReclaimable"),
+        slab_unreclaimable: get("SUnreclaim"),
+        dirty: get("Dirty"),
+        writeback: get("Writeback"),
+        anon_huge_pages: get("AnonHugePages"),
+        hugetlb_total: hugepages("HugePages_Total"),
+        hugetlb_free: hugepages("HugePages_Free"),
+        kernel_stack: get("KernelStack"),
+        page_tables: get("PageTables"),
+        commit_limit: get("CommitLimit"),
+        committed: get("Committed_AS"),
+        zswap: get("Zswap"),
+        zswapped: get("Zswapped"),
+    }
+}
+
+/// Returns the memory breakdown from the meminfo file at `path`.
+pub(crate) fn get_mem_details(path: &Path) -> Option<MemDetails> {
+    Some(parse_meminfo(&fs::read_to_string(path).ok()?))
+}
+
+/// Parses the contents of `/proc/vmstat`.
+fn parse_vmstat(contents: &str) -> PagingCounters {
+    let mut counters = PagingCounters::default();
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(' ') else {
+            continue;
+        };
+        let Ok(value) = value.trim().parse::<u64>() else {
+            continue;
+        };
+
+        // Reclaim is split up by who did it, and on older kernels also by zone (e.g.
+        // `pgscan_kswapd_normal`). Newer kernels also split it by anon/file, which would double
+        // count, so only the by-reclaimer counters are summed.
+        let is_reclaimer = |prefix: &str| {
+            ["kswapd", "direct", "khugepaged"].iter().any(|reclaimer| {
+                key.strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_prefix(reclaimer))
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+            })
+        };
+
+        match key {
+            "pswpin" => counters.swap_in = value,
+            "pswpout" => counters.swap_out = value,
+            "pgmajfault" => counters.major_faults = value,
+            // This counts how often allocations were throttled, not pages.
+            "pgscan_direct_throttle" => {}
+            _ if is_reclaimer("pgscan_") => counters.scanned += value,
+            _ if is_reclaimer("pgsteal_") => counters.stolen += value,
+            _ => {}
+        }
+    }
+
+    counters
+}
+
+/// Returns how quickly each counter went up from `prev` to `current` over `elapsed`.
+fn paging_rates(
+    current: &PagingCounters, prev: &PagingCounters, elapsed: Duration,
+) -> Option<PagingRates> {
+    let elapsed_secs = elapsed.as_secs_f64();
+    if elapsed_secs <= 0.0 {
+        return None;
+    }
+
+    let rate = |current: u64, prev: u64| current.saturating_sub(prev) as f64 / elapsed_secs;
+
+    Some(PagingRates {
+        swap_in: rate(current.swap_in, prev.swap_in),
+        swap_out: rate(current.swap_out, prev.swap_out),
+        major_faults: rate(current.major_faults, prev.major_faults),
+        scanned: rate(current.scanned, prev.scanned),
+        stolen: rate(current.stolen, prev.stolen),
+    })
+}
+
+/// Returns the paging counters from the vmstat file at `path`, along with their rates over
+/// `elapsed`. `prev_counters` is used to calculate the rates, and is replaced by the new counters.
+pub(crate) fn get_paging_data(
+    path: &Path, prev_counters: &mut Option<PagingCounters>, elapsed: Duration,
+) -> Option<PagingHarvest> {
+    let total = parse_vmstat(&fs::read_to_string(path).ok()?);
+    let per_sec = prev_counters
+        .replace(total)
+        .and_then(|prev| paging_rates(&total, &prev, elapsed));
+
+    Some(PagingHarvest { total, per_sec })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let contents = "MemTotal:        6147400 kB
+MemFree:          569192 kB
+MemAvailable:    5505820 kB
+Buffers:           57320 kB
+Cached:          5011600 kB
+Dirty:               216 kB
+Writeback:             0 kB
+Shmem:              9288 kB
+SReclaimable:     174512 kB
+SUnreclaim:        32876 kB
+KernelStack:        1184 kB
+PageTables:         2396 kB
+CommitLimit:     3073700 kB
+Committed_AS:     337236 kB
+AnonHugePages:         0 kB
+HugePages_Total:       4
+HugePages_Free:        1
+Hugepagesize:       2048 kB
+";
+
+        let details = parse_meminfo(contents);
+        assert_eq!(details.total, Some(6147400 * 1024));
+        assert_eq!(details.available, Some(5505820 * 1024));
+        assert_eq!(details.shared, Some(9288 * 1024));
+        assert_eq!(details.slab_unreclaimable, Some(32876 * 1024));
+        assert_eq!(details.committed, Some(337236 * 1024));
+        assert_eq!(details.hugetlb_total, Some(4 * 2048 * 1024));
+        assert_eq!(details.hugetlb_free, Some(2048 * 1024));
+
+        // Older kernels may not report some fields.
+        assert_eq!(parse_meminfo("MemTotal: 1 kB\n").available, None);
+    }
+
+    #[test]
+    fn test_get_mem_details() {
+        let path = Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/proc/meminfo"
+        ));
+        let kib = |value: u64| Some(value * 1024);
+
+        assert_eq!(
+            get_mem_details(path),
+            Some(MemDetails {
+                total: kib(16303428),
+                free: kib(1843200),
+                available: kib(9871360),
+                buffers: kib(412672),
+                cached: kib(7340032),
+                shared: kib(524288),
+                slab_reclaimable: kib(655360),
+                slab_unreclaimable: kib(196608),
+                dirty: kib(1536),
+                writeback: kib(64),
+                anon_huge_pages: kib(262144),
+                hugetlb_total: kib(8 * 2048),
+                hugetlb_free: kib(3 * 2048),
+                kernel_stack: kib(18432),
+                page_tables: kib(49152),
+                commit_limit: kib(16540316),
+                committed: kib(12582912),
+                zswap: kib(51200),
+                zswapped: kib(204800),
+            })
+        );
+
+        assert!(get_mem_details(Path::new("/does/not/exist")).is_none());
+    }
+
+    #[test]
+    fn test_parse_vmstat() {
+        let contents = "pgpgin 1000
+pswpin 12
+pswpout 34
+pgmajfault 56
+pgsteal_kswapd 100
+pgsteal_direct 20
+pgsteal_khugepaged 3
+pgscan_kswapd 200
+pgscan_direct 40
+pgscan_direct_throttle 9
+pgscan_khugepaged 6
+pgscan_anon 146
+pgscan_file 100
+";
+
+        assert_eq!(
+            parse_vmstat(contents),
+            PagingCounters {
+                swap_in: 12,
+                swap_out: 34,
+                major_faults: 56,
+                scanned: 246,
+                stolen: 123,
+            }
+        );
+
+        // Older kernels split reclaim up by zone.
+        let contents = "pgscan_kswapd_dma 1
+pgscan_kswapd_normal 2
+pgscan_direct_normal 4
+pgsteal_kswapd_normal 8
+";
+        let counters = parse_vmstat(contents);
+        assert_eq!(counters.scanned, 7);
+        assert_eq!(counters.stolen, 8);
+    }
+
+    #[test]
+    fn test_paging_rates() {
+        let prev = PagingCounters {
+            swap_in: 10,
+            major_faults: 100,
+            ..Default::default()
+        };
+        let current = PagingCounters {
+            swap_in: 30,
+            swap_out: 4,
+            major_faults: 100,
+            ..Default::default()
+        };
+
+        let rates = paging_rates(&current, &prev, Duration::from_secs(2)).unwrap();
+        assert_eq!(rates.swap_in, 10.0);
+        assert_eq!(rates.swap_out, 2.0);
+        assert_eq!(rates.major_faults, 0.0);
+
+        // Counters can't go backwards, but don't underflow if they somehow do.
+        let rates = paging_rates(&prev, &current, Duration::from_secs(2)).unwrap();
+        assert_eq!(rates.swap_in, 0.0);
+
+        assert!(paging_rates(&current, &prev, Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_get_paging_data() {
+        let path = Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/proc/vmstat"
+        ));
+        let total = PagingCounters {
+            swap_in: 6021,
+            swap_out: 18840,
+            major_faults: 73412,
+            scanned: 2044012 + 90124 + 3321,
+            stolen: 1520034 + 48211 + 1200,
+        };
+
+        // There are no rates until there is a previous reading.
+        let mut prev_counters = None;
+        assert_eq!(
+            get_paging_data(path, &mut prev_counters, Duration::from_secs(2)),
+            Some(PagingHarvest {
+                total,
+                per_sec: None,
+            })
+        );
+        assert_eq!(prev_counters, Some(total));
+
+        let mut prev_counters = Some(PagingCounters {
+            swap_in: 6001,
+            swap_out: 18800,
+            major_faults: 73400,
+            scanned: total.scanned - 1000,
+            stolen: total.stolen - 600,
+        });
+        assert_eq!(
+            get_paging_data(path, &mut prev_counters, Duration::from_secs(2)),
+            Some(PagingHarvest {
+                total,
+                per_sec: Some(PagingRates {
+                    swap_in: 10.0,
+                    swap_out: 20.0,
+                    major_faults: 6.0,
+                    scanned: 500.0,
+                    stolen: 300.0,
+                }),
+            })
+        );
+        assert_eq!(prev_counters, Some(total));
+
+        let mut prev_counters = Some(PagingCounters::default());
+        assert!(get_paging_data(
+            Path::new("/does/not/exist"),
+            &mut prev_counters,
+            Duration::from_secs(1)
+        )
+        .is_none());
+        assert_eq!(prev_counters, Some(PagingCounters::default()));
+    }
+}
@@ -0,0 +1,156 @@
+//! Reads how much memory is used by compressed swap, either as zram devices or as the zswap pool.
+
+use std::{fs, path::Path};
+
+use super::{MemDetails, ZramHarvest, ZswapHarvest};
+
+/// Where block devices, including zram devices, are listed.
+pub(crate) const BLOCK_SYSFS_ROOT: &str = "/sys/block";
+
+/// Where zswap's module parameters live.
+pub(crate) const ZSWAP_MODULE_ROOT: &str = "/sys/module/zswap";
+
+/// Where zswap reports its statistics on kernels that don't show them in `/proc/meminfo`. This
+/// usually needs root.
+pub(crate) const ZSWAP_DEBUGFS_ROOT: &str = "/sys/kernel/debug/zswap";
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Parses the contents of a zram device's `mm_stat` file. The first three fields are the size of
+/// the data stored, its compressed size, and the memory used to store it, all in bytes.
+fn parse_mm_stat(name: String, contents: &str) -> Option<ZramHarvest> {
+    let mut fields = contents
+        .split_whitespace()
+        .map(|field| field.parse::<u64>().ok());
+
+    Some(ZramHarvest {
+        name,
+        original_bytes: fields.next()??,
+        compressed_bytes: fields.next()??,
+        used_bytes: fields.next()??,
+    })
+}
+
+/// Returns the stats of each zram device under `root` that has been set up.
+pub(crate) fn get_zram_data(root: &Path) -> Option<Vec<ZramHarvest>> {
+    let mut devices: Vec<ZramHarvest> = fs::read_dir(root)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with("zram") {
+                return None;
+            }
+
+            // A device without a size hasn't been set up yet.
+            let path = entry.path();
+            if read_u64(&path.join("disksize"))? == 0 {
+                return None;
+            }
+
+            parse_mm_stat(name, &fs::read_to_string(path.join("mm_stat")).ok()?)
+        })
+        .collect();
+
+    if devices.is_empty() {
+        None
+    } else {
+        devices.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        Some(devices)
+    }
+}
+
+/// Returns the size of the zswap pool. Newer kernels report it in `/proc/meminfo`, older ones only
+/// in debugfs.
+pub(crate) fn get_zswap_data(
+    mem_details: Option<&MemDetails>, module_root: &Path, debugfs_root: &Path,
+) -> Option<ZswapHarvest> {
+    let enabled = fs::read_to_string(module_root.join("parameters/enabled"))
+        .is_ok_and(|enabled| enabled.trim() == "Y");
+
+    let harvest = match mem_details.and_then(|details| details.zswap.map(|pool| (details, pool))) {
+        Some((details, pool_bytes)) => ZswapHarvest {
+            pool_bytes,
+            stored_bytes: details.zswapped,
+        },
+        None => ZswapHarvest {
+            pool_bytes: read_u64(&debugfs_root.join("pool_total_size"))?,
+            stored_bytes: read_u64(&debugfs_root.join("stored_pages"))
+                .map(|pages| pages.saturating_mul(rustix::param::page_size() as u64)),
+        },
+    };
+
+    // Pages can stay in the pool for a while after zswap is turned off.
+    if enabled || harvest.pool_bytes > 0 {
+        Some(harvest)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_mm_stat() {
+        let zram = parse_mm_stat(
+            "zram0".to_string(),
+            "  8192000  2048000  2500000        0  2600000      120        0        3        3\n",
+        )
+        .unwrap();
+
+        assert_eq!(zram.original_bytes, 8192000);
+        assert_eq!(zram.compressed_bytes, 2048000);
+        assert_eq!(zram.used_bytes, 2500000);
+
+        assert!(parse_mm_stat("zram0".to_string(), "1 2").is_none());
+        assert!(parse_mm_stat("zram0".to_string(), "1 x 3").is_none());
+    }
+
+    #[test]
+    fn test_get_zram_data() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/zram");
+
+        // zram1 hasn't been set up, and vda isn't a zram device.
+        assert_eq!(
+            get_zram_data(&root),
+            Some(vec![ZramHarvest {
+                name: "zram0".to_string(),
+                original_bytes: 8192000,
+                compressed_bytes: 2048000,
+                used_bytes: 2500000,
+            }])
+        );
+
+        assert!(get_zram_data(Path::new("/does/not/exist")).is_none());
+    }
+
+    #[test]
+    fn test_get_zswap_data() {
+        let missing = Path::new("/does/not/exist");
+        let details = MemDetails {
+            zswap: Some(1024),
+            zswapped: Some(4096),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_zswap_data(Some(&details), missing, missing),
+            Some(ZswapHarvest {
+                pool_bytes: 1024,
+                stored_bytes: Some(4096),
+            })
+        );
+
+        // An empty pool is only shown if zswap is turned on.
+        let details = MemDetails {
+            zswap: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(get_zswap_data(Some(&details), missing, missing), None);
+        assert_eq!(get_zswap_data(None, missing, missing), None);
+    }
+}
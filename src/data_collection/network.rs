@@ -3,18 +3,66 @@
 pub mod sysinfo;
 pub use self::sysinfo::*;
 
-#[derive(Default, Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 /// All units in bits.
 pub struct NetworkHarvest {
     pub rx: u64,
     pub tx: u64,
     pub total_rx: u64,
     pub total_tx: u64,
+
+    /// Per-interface data, for interfaces that pass the network filter.
+    pub interfaces: Vec<NetworkInterfaceHarvest>,
 }
 
 impl NetworkHarvest {
     pub fn first_run_cleanup(&mut self) {
         self.rx = 0;
         self.tx = 0;
+
+        for interface in &mut self.interfaces {
+            interface.first_run_cleanup();
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+/// Data for a single network interface. Byte values are in bits, packet values are in packets,
+/// and rates are per second.
+pub struct NetworkInterfaceHarvest {
+    pub name: String,
+    pub rx: u64,
+    pub tx: u64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub total_rx_packets: u64,
+    pub total_tx_packets: u64,
+    pub total_rx_errors: u64,
+    pub total_tx_errors: u64,
+
+    /// Dropped packet counters. These are only available on some platforms.
+    pub total_rx_dropped: Option<u64>,
+    pub total_tx_dropped: Option<u64>,
+}
+
+impl NetworkInterfaceHarvest {
+    pub fn first_run_cleanup(&mut self) {
+        self.rx = 0;
+        self.tx = 0;
+        self.rx_packets = 0;
+        self.tx_packets = 0;
     }
 }
+
+/// The cumulative counters of an interface from the previous harvest, used to calculate rates.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct NetworkInterfaceCounters {
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub total_rx_packets: u64,
+    pub total_tx_packets: u64,
+}
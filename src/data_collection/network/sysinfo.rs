@@ -2,49 +2,201 @@
 
 use std::time::Instant;
 
+use hashbrown::HashMap;
 use sysinfo::Networks;
 
-use super::NetworkHarvest;
+use super::{NetworkHarvest, NetworkInterfaceCounters, NetworkInterfaceHarvest};
 use crate::app::filter::Filter;
 
-// TODO: Eventually make it so that this thing also takes individual usage into account, so we can show per-interface!
+/// The counters of an interface as read from the system, before any rates are calculated.
+struct InterfaceReading<'a> {
+    name: &'a str,
+    counters: NetworkInterfaceCounters,
+    total_rx_errors: u64,
+    total_tx_errors: u64,
+}
+
 pub fn get_network_data(
     networks: &Networks, prev_net_access_time: Instant, prev_net_rx: &mut u64,
-    prev_net_tx: &mut u64, curr_time: Instant, filter: &Option<Filter>,
+    prev_net_tx: &mut u64, prev_interfaces: &mut HashMap<String, NetworkInterfaceCounters>,
+    curr_time: Instant, filter: &Option<Filter>,
+) -> NetworkHarvest {
+    let readings = networks
+        .iter()
+        .filter(|(name, _)| {
+            if let Some(filter) = filter {
+                filter.keep_entry(name)
+            } else {
+                true
+            }
+        })
+        .map(|(name, network)| InterfaceReading {
+            name,
+            counters: NetworkInterfaceCounters {
+                total_rx: network.total_received() * 8,
+                total_tx: network.total_transmitted() * 8,
+                total_rx_packets: network.total_packets_received(),
+                total_tx_packets: network.total_packets_transmitted(),
+            },
+            total_rx_errors: network.total_errors_on_received(),
+            total_tx_errors: network.total_errors_on_transmitted(),
+        });
+
+    harvest_interfaces(
+        readings,
+        curr_time.duration_since(prev_net_access_time).as_secs_f64(),
+        prev_net_rx,
+        prev_net_tx,
+        prev_interfaces,
+    )
+}
+
+/// Calculates the rates of each interface and of all of them combined, given how many seconds
+/// have passed since the previous readings.
+fn harvest_interfaces<'a>(
+    readings: impl IntoIterator<Item = InterfaceReading<'a>>, elapsed_time: f64,
+    prev_net_rx: &mut u64, prev_net_tx: &mut u64,
+    prev_interfaces: &mut HashMap<String, NetworkInterfaceCounters>,
 ) -> NetworkHarvest {
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut interfaces = Vec::new();
+    let mut curr_interfaces = HashMap::new();
 
-    for (name, network) in networks {
-        let to_keep = if let Some(filter) = filter {
-            filter.keep_entry(name)
+    let rate = |curr: u64, prev: u64| -> u64 {
+        if elapsed_time == 0.0 {
+            0
         } else {
-            true
-        };
-
-        if to_keep {
-            total_rx += network.total_received() * 8;
-            total_tx += network.total_transmitted() * 8;
+            (curr.saturating_sub(prev) as f64 / elapsed_time) as u64
         }
-    }
+    };
 
-    let elapsed_time = curr_time.duration_since(prev_net_access_time).as_secs_f64();
+    for reading in readings {
+        let InterfaceReading {
+            name,
+            counters,
+            total_rx_errors,
+            total_tx_errors,
+        } = reading;
 
-    let (rx, tx) = if elapsed_time == 0.0 {
-        (0, 0)
-    } else {
-        (
-            ((total_rx.saturating_sub(*prev_net_rx)) as f64 / elapsed_time) as u64,
-            ((total_tx.saturating_sub(*prev_net_tx)) as f64 / elapsed_time) as u64,
-        )
-    };
+        total_rx += counters.total_rx;
+        total_tx += counters.total_tx;
+
+        // Interfaces we haven't seen before have no previous values, so treat their rates as zero
+        // rather than reporting their entire lifetime total as a single spike.
+        let prev = prev_interfaces.get(name).copied().unwrap_or(counters);
+
+        interfaces.push(NetworkInterfaceHarvest {
+            name: name.to_string(),
+            rx: rate(counters.total_rx, prev.total_rx),
+            tx: rate(counters.total_tx, prev.total_tx),
+            total_rx: counters.total_rx,
+            total_tx: counters.total_tx,
+            rx_packets: rate(counters.total_rx_packets, prev.total_rx_packets),
+            tx_packets: rate(counters.total_tx_packets, prev.total_tx_packets),
+            total_rx_packets: counters.total_rx_packets,
+            total_tx_packets: counters.total_tx_packets,
+            total_rx_errors,
+            total_tx_errors,
+            total_rx_dropped: get_dropped_packets(name, "rx_dropped"),
+            total_tx_dropped: get_dropped_packets(name, "tx_dropped"),
+        });
+
+        curr_interfaces.insert(name.to_string(), counters);
+    }
+
+    let (rx, tx) = (rate(total_rx, *prev_net_rx), rate(total_tx, *prev_net_tx));
 
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
+    *prev_interfaces = curr_interfaces;
     NetworkHarvest {
         rx,
         tx,
         total_rx,
         total_tx,
+        interfaces,
+    }
+}
+
+/// sysinfo doesn't expose dropped packet counts, so on Linux we read them from sysfs ourselves.
+#[cfg(target_os = "linux")]
+fn get_dropped_packets(interface: &str, counter: &str) -> Option<u64> {
+    std::fs::read_to_string(format!("/sys/class/net/{interface}/statistics/{counter}"))
+        .ok()
+        .and_then(|contents| contents.trim().parse().ok())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_dropped_packets(_interface: &str, _counter: &str) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reading(name: &str, total_rx: u64, total_tx: u64, packets: u64) -> InterfaceReading<'_> {
+        InterfaceReading {
+            name,
+            counters: NetworkInterfaceCounters {
+                total_rx,
+                total_tx,
+                total_rx_packets: packets,
+                total_tx_packets: packets,
+            },
+            total_rx_errors: 0,
+            total_tx_errors: 0,
+        }
+    }
+
+    #[test]
+    fn interface_rates() {
+        let mut prev_rx = 0;
+        let mut prev_tx = 0;
+        let mut prev_interfaces = HashMap::new();
+
+        // Interfaces seen for the first time have no rate yet.
+        let harvest = harvest_interfaces(
+            [reading("eth0", 1000, 2000, 10)],
+            1.0,
+            &mut prev_rx,
+            &mut prev_tx,
+            &mut prev_interfaces,
+        );
+        assert_eq!((harvest.interfaces[0].rx, harvest.interfaces[0].tx), (0, 0));
+
+        let harvest = harvest_interfaces(
+            [
+                reading("eth0", 3000, 2400, 30),
+                reading("wlan0", 500, 500, 5),
+            ],
+            2.0,
+            &mut prev_rx,
+            &mut prev_tx,
+            &mut prev_interfaces,
+        );
+
+        let eth0 = &harvest.interfaces[0];
+        assert_eq!((eth0.rx, eth0.tx), (1000, 200));
+        assert_eq!((eth0.rx_packets, eth0.tx_packets), (10, 10));
+        assert_eq!((eth0.total_rx, eth0.total_tx), (3000, 2400));
+
+        let wlan0 = &harvest.interfaces[1];
+        assert_eq!((wlan0.rx, wlan0.tx), (0, 0));
+
+        // The combined rate includes every interface, including new ones.
+        assert_eq!((harvest.rx, harvest.tx), (1250, 450));
+        assert_eq!((prev_rx, prev_tx), (3500, 2900));
+
+        // Counters that go backwards, such as after an interface is reset, don't underflow.
+        let harvest = harvest_interfaces(
+            [reading("eth0", 100, 100, 1)],
+            1.0,
+            &mut prev_rx,
+            &mut prev_tx,
+            &mut prev_interfaces,
+        );
+        assert_eq!((harvest.interfaces[0].rx, harvest.interfaces[0].tx), (0, 0));
     }
 }
@@ -0,0 +1,41 @@
+//! Data collection for pressure stall information (PSI). This is only supported on Linux.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod linux;
+        pub(crate) use self::linux::*;
+    }
+}
+
+use serde::{Deserialize, Serialize};
+
+/// One line of a pressure file. The averages are the percentage of time that tasks were stalled
+/// over the last 10, 60, and 300 seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+
+    /// The total time stalled, in microseconds.
+    pub total: u64,
+}
+
+/// The pressure of a single resource.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Pressure {
+    /// Time where at least some tasks were stalled.
+    pub some: PressureLine,
+
+    /// Time where all non-idle tasks were stalled at once. Older kernels don't report this for
+    /// the CPU.
+    pub full: Option<PressureLine>,
+}
+
+/// The pressure of each resource. A resource is [`None`] if its pressure couldn't be read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PressureHarvest {
+    pub cpu: Option<Pressure>,
+    pub memory: Option<Pressure>,
+    pub io: Option<Pressure>,
+}
@@ -0,0 +1,116 @@
+//! Reads pressure stall information from procfs.
+
+use std::{fs, path::Path};
+
+use super::{Pressure, PressureHarvest, PressureLine};
+
+/// Where pressure stall information is usually found.
+pub(crate) const PRESSURE_ROOT: &str = "/proc/pressure";
+
+fn parse_line(line: &str) -> Option<PressureLine> {
+    let mut pressure_line = PressureLine::default();
+
+    for field in line.split_whitespace() {
+        match field.split_once('=') {
+            Some(("avg10", value)) => pressure_line.avg10 = value.parse().ok()?,
+            Some(("avg60", value)) => pressure_line.avg60 = value.parse().ok()?,
+            Some(("avg300", value)) => pressure_line.avg300 = value.parse().ok()?,
+            Some(("total", value)) => pressure_line.total = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    Some(pressure_line)
+}
+
+/// Parses a pressure file, such as `/proc/pressure/cpu`.
+fn parse_pressure(contents: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+
+    for line in contents.lines() {
+        if let Some(rest) = line.strip_prefix("some ") {
+            some = parse_line(rest);
+        } else if let Some(rest) = line.strip_prefix("full ") {
+            full = parse_line(rest);
+        }
+    }
+
+    Some(Pressure { some: some?, full })
+}
+
+fn read_pressure(path: &Path) -> Option<Pressure> {
+    parse_pressure(&fs::read_to_string(path).ok()?)
+}
+
+/// Returns the pressure of the CPU, memory, and IO from the files under `root`.
+///
+/// Returns [`None`] if none of them can be read, for example if the kernel was built without
+/// PSI or it was disabled with `psi=0`.
+pub(crate) fn get_pressure_data(root: &Path) -> Option<PressureHarvest> {
+    let harvest = PressureHarvest {
+        cpu: read_pressure(&root.join("cpu")),
+        memory: read_pressure(&root.join("memory")),
+        io: read_pressure(&root.join("io")),
+    };
+
+    if harvest == PressureHarvest::default() {
+        None
+    } else {
+        Some(harvest)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn fixture_root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pressure")
+    }
+
+    #[test]
+    fn parses_pressure() {
+        let pressure = parse_pressure(
+            "some avg10=1.50 avg60=0.75 avg300=0.10 total=123456\n\
+             full avg10=0.50 avg60=0.25 avg300=0.00 total=6789\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            pressure.some,
+            PressureLine {
+                avg10: 1.5,
+                avg60: 0.75,
+                avg300: 0.1,
+                total: 123456,
+            }
+        );
+        assert_eq!(pressure.full.unwrap().total, 6789);
+
+        // Kernels before 5.13 only have a "some" line for the CPU.
+        let pressure = parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").unwrap();
+        assert_eq!(pressure.full, None);
+
+        assert_eq!(parse_pressure(""), None);
+        assert_eq!(parse_pressure("some avg10=abc\n"), None);
+    }
+
+    #[test]
+    fn reads_pressure() {
+        let harvest = get_pressure_data(&fixture_root()).unwrap();
+
+        assert_eq!(harvest.cpu.unwrap().some.avg10, 12.34);
+        assert_eq!(harvest.cpu.unwrap().full, None);
+        assert_eq!(harvest.memory.unwrap().full.unwrap().avg60, 1.5);
+        // There is no IO file in the fixtures.
+        assert_eq!(harvest.io, None);
+    }
+
+    #[test]
+    fn missing_pressure() {
+        assert_eq!(get_pressure_data(&fixture_root().join("missing")), None);
+    }
+}
@@ -31,28 +31,81 @@ cfg_if! {
     }
 }
 
+pub mod details;
+
 use std::{borrow::Cow, time::Duration};
 
+use serde::{Deserialize, Serialize};
+
 use super::DataCollector;
 use crate::{utils::error, Pid};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProcessHarvest {
     /// The pid of the process.
     pub pid: Pid,
 
-    /// The parent PID of the process. A `parent_pid` of 0 is usually the root.
+    /// The parent PID of the process. A `parent_pid` of 0 is usually the root. For threads, this
+    /// is the process the thread belongs to.
     pub parent_pid: Option<Pid>,
 
+    /// Whether this is a thread of a process rather than a process. Threads share the memory and
+    /// I/O of their process, so only their CPU usage, state, time, and scheduling fields are set.
+    pub is_thread: bool,
+
     /// CPU usage as a percentage.
     pub cpu_usage_percent: f32,
 
     /// Memory usage as a percentage.
     pub mem_usage_percent: f32,
 
-    /// Memory usage as bytes.
+    /// Memory usage as bytes. This is the resident set size (RSS).
     pub mem_usage_bytes: u64,
 
+    /// The virtual memory size as bytes.
+    pub virtual_bytes: u64,
+
+    /// Resident memory that may be shared with other processes, such as shared libraries, as bytes.
+    pub shared_bytes: u64,
+
+    /// Resident anonymous memory as bytes, which approximates the memory private to the process.
+    /// Reading the exact amount from `smaps_rollup` is too slow to do for every process.
+    pub private_bytes: u64,
+
+    /// Memory that has been swapped out, as bytes.
+    pub swap_bytes: u64,
+
+    /// The number of threads in the process.
+    pub num_threads: u64,
+
+    /// The nice value of the process.
+    pub nice: i32,
+
+    /// The scheduling priority of the process, as reported by the kernel.
+    pub priority: i32,
+
+    /// The scheduling policy of the process, such as `Other` or `FIFO`.
+    pub policy: Cow<'static, str>,
+
+    /// The CPU the process last ran on.
+    pub processor: u32,
+
+    /// Minor page faults per second.
+    pub minor_faults_per_sec: u64,
+
+    /// Major page faults per second.
+    pub major_faults_per_sec: u64,
+
+    /// The path of the cgroup the process is in. This is only used on Linux.
+    pub cgroup: Option<String>,
+
+    /// The ID of the container the process is in, if any. This is only used on Linux.
+    pub container: Option<String>,
+
+    /// The systemd unit or Kubernetes pod the process is in, if any. This is only used on Linux.
+    pub unit: Option<String>,
+
     /// The name of the process.
     pub name: String,
 
@@ -95,9 +148,6 @@ pub struct ProcessHarvest {
     /// Gpu utilization as a percentage.
     #[cfg(feature = "gpu")]
     pub gpu_util: u32,
-    // TODO: Additional fields
-    // pub rss_kb: u64,
-    // pub virt_kb: u64,
 }
 
 impl ProcessHarvest {
@@ -105,6 +155,13 @@ impl ProcessHarvest {
         self.cpu_usage_percent += rhs.cpu_usage_percent;
         self.mem_usage_bytes += rhs.mem_usage_bytes;
         self.mem_usage_percent += rhs.mem_usage_percent;
+        self.virtual_bytes += rhs.virtual_bytes;
+        self.shared_bytes += rhs.shared_bytes;
+        self.private_bytes += rhs.private_bytes;
+        self.swap_bytes += rhs.swap_bytes;
+        self.num_threads += rhs.num_threads;
+        self.minor_faults_per_sec += rhs.minor_faults_per_sec;
+        self.major_faults_per_sec += rhs.major_faults_per_sec;
         self.read_bytes_per_sec += rhs.read_bytes_per_sec;
         self.write_bytes_per_sec += rhs.write_bytes_per_sec;
         self.total_read_bytes += rhs.total_read_bytes;
@@ -0,0 +1,92 @@
+//! Details about a single process, read on demand rather than on every update.
+
+use std::path::PathBuf;
+
+use crate::Pid;
+
+/// Everything known about a single process. Each section is read separately, as some of them
+/// may not be readable without elevated permissions.
+pub struct ProcessDetails {
+    pub pid: Pid,
+    pub name: String,
+
+    /// The full command line, split into arguments.
+    pub command: Vec<String>,
+
+    /// The path to the executable.
+    pub exe: anyhow::Result<PathBuf>,
+
+    /// The current working directory.
+    pub cwd: anyhow::Result<PathBuf>,
+
+    /// The environment variables, as `NAME=value` pairs.
+    pub environment: anyhow::Result<Vec<String>>,
+
+    /// When the process was started, as a Unix timestamp in seconds.
+    pub start_time: Option<u64>,
+
+    /// The chain of parents, starting from the direct parent up to the root.
+    pub parents: Vec<(Pid, String)>,
+
+    pub open_files: anyhow::Result<Vec<OpenFile>>,
+    pub memory_maps: anyhow::Result<Vec<MappingSummary>>,
+    pub threads: anyhow::Result<Vec<ThreadDetails>>,
+
+    /// The cgroups the process is in, as `hierarchy:controllers:path` entries.
+    pub cgroups: anyhow::Result<Vec<String>>,
+
+    /// The namespaces the process is in, as pairs of the namespace type and its identifier.
+    pub namespaces: anyhow::Result<Vec<(String, String)>>,
+
+    pub limits: anyhow::Result<Vec<Limit>>,
+}
+
+/// An open file descriptor.
+pub struct OpenFile {
+    pub fd: u32,
+
+    /// What the file descriptor points to, such as a path, socket, or pipe.
+    pub target: String,
+}
+
+/// The memory mapped from a single source, such as a file or the heap.
+#[derive(Debug, PartialEq)]
+pub struct MappingSummary {
+    pub name: String,
+
+    /// The total size of the mappings, in bytes.
+    pub size: u64,
+
+    /// The number of separate mappings.
+    pub count: usize,
+}
+
+/// A single thread of a process.
+pub struct ThreadDetails {
+    pub tid: Pid,
+    pub name: String,
+    pub state: char,
+}
+
+/// A resource limit, such as the maximum number of open files.
+#[derive(Debug, PartialEq)]
+pub struct Limit {
+    pub name: String,
+    pub soft: String,
+    pub hard: String,
+    pub units: String,
+}
+
+impl ProcessDetails {
+    /// Reads the details of the process with the given PID.
+    pub fn read(pid: Pid) -> anyhow::Result<Self> {
+        cfg_if::cfg_if! {
+            if #[cfg(target_os = "linux")] {
+                super::linux::details::read_details(pid)
+            } else {
+                let _ = pid;
+                anyhow::bail!("Process details are currently only supported on Linux.")
+            }
+        }
+    }
+}
@@ -1,20 +1,21 @@
 //! Process data collection for Linux.
 
+mod cgroup;
+pub(crate) mod details;
 mod process;
 
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-    time::Duration,
-};
+use std::{fs, time::Duration};
 
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use process::*;
 use sysinfo::ProcessStatus;
 
 use super::{ProcessHarvest, UserTable};
 use crate::{
-    data_collection::DataCollector,
+    data_collection::{
+        cpu::{CpuDataType, CpuTimes},
+        DataCollector,
+    },
     utils::error::{self, BottomError},
     Pid,
 };
@@ -28,33 +29,21 @@ pub struct PrevProcDetails {
     total_read_bytes: u64,
     total_write_bytes: u64,
     cpu_time: u64,
+    minor_faults: u64,
+    major_faults: u64,
 }
 
-/// Given `/proc/stat` file contents, determine the idle and non-idle values of the CPU
-/// used to calculate CPU usage.
-fn fetch_cpu_usage(line: &str) -> (f64, f64) {
-    /// Converts a `Option<&str>` value to an f64. If it fails to parse or is `None`, it
-    /// will return `0_f64`.
-    fn str_to_f64(val: Option<&str>) -> f64 {
-        val.and_then(|v| v.parse::<f64>().ok()).unwrap_or(0_f64)
+/// Returns the name of a scheduling policy from `/proc/<PID>/stat`, following `chrt`.
+fn policy_name(policy: u32) -> &'static str {
+    match policy as libc::c_int {
+        libc::SCHED_OTHER => "Other",
+        libc::SCHED_FIFO => "FIFO",
+        libc::SCHED_RR => "RR",
+        libc::SCHED_BATCH => "Batch",
+        libc::SCHED_IDLE => "Idle",
+        libc::SCHED_DEADLINE => "Deadline",
+        _ => "Unknown",
     }
-
-    let mut val = line.split_whitespace();
-    let user = str_to_f64(val.next());
-    let nice: f64 = str_to_f64(val.next());
-    let system: f64 = str_to_f64(val.next());
-    let idle: f64 = str_to_f64(val.next());
-    let iowait: f64 = str_to_f64(val.next());
-    let irq: f64 = str_to_f64(val.next());
-    let softirq: f64 = str_to_f64(val.next());
-    let steal: f64 = str_to_f64(val.next());
-
-    // Note we do not get guest/guest_nice, as they are calculated as part of user/nice respectively
-    // See https://github.com/htop-dev/htop/blob/main/linux/LinuxProcessList.c
-    let idle = idle + iowait;
-    let non_idle = user + nice + system + irq + softirq + steal;
-
-    (idle, non_idle)
 }
 
 struct CpuUsage {
@@ -65,20 +54,13 @@ struct CpuUsage {
     cpu_fraction: f64,
 }
 
-fn cpu_usage_calculation(prev_idle: &mut f64, prev_non_idle: &mut f64) -> error::Result<CpuUsage> {
-    let (idle, non_idle) = {
-        // From SO answer: https://stackoverflow.com/a/23376195
-        let first_line = {
-            // We just need a single line from this file. Read it and return it.
-            let mut reader = BufReader::new(File::open("/proc/stat")?);
-            let mut buffer = String::new();
-            reader.read_line(&mut buffer)?;
-
-            buffer
-        };
-
-        fetch_cpu_usage(&first_line)
-    };
+/// Calculates the overall CPU usage since the last call from the aggregate `/proc/stat` times.
+fn cpu_usage_calculation(
+    times: &CpuTimes, prev_idle: &mut f64, prev_non_idle: &mut f64,
+) -> CpuUsage {
+    // From SO answer: https://stackoverflow.com/a/23376195
+    let idle = times.idle_time() as f64;
+    let non_idle = times.busy_time() as f64;
 
     let total = idle + non_idle;
     let prev_total = *prev_idle + *prev_non_idle;
@@ -102,10 +84,10 @@ fn cpu_usage_calculation(prev_idle: &mut f64, prev_non_idle: &mut f64) -> error:
         0.0
     };
 
-    Ok(CpuUsage {
+    CpuUsage {
         cpu_usage,
         cpu_fraction,
-    })
+    }
 }
 
 /// Returns the usage and a new set of process times.
@@ -132,13 +114,16 @@ fn get_linux_cpu_usage(
 }
 
 fn read_proc(
-    prev_proc: &PrevProcDetails, process: Process, args: ReadProcArgs, user_table: &mut UserTable,
+    prev_proc: &mut PrevProcDetails, process: Process, args: ReadProcArgs,
+    user_table: &mut UserTable,
 ) -> error::Result<(ProcessHarvest, u64)> {
     let Process {
         pid: _,
         uid,
         stat,
         io,
+        status,
+        cgroup,
         cmdline,
     } = process;
 
@@ -197,6 +182,30 @@ fn read_proc(
     let mem_usage_bytes = stat.rss_bytes();
     let mem_usage_percent = (mem_usage_bytes as f64 / total_memory as f64 * 100.0) as f32;
 
+    // This may be missing if the process exited while it was being read.
+    let status = status.unwrap_or_default();
+
+    let minor_faults_per_sec = stat
+        .minflt
+        .saturating_sub(prev_proc.minor_faults)
+        .checked_div(time_difference_in_secs)
+        .unwrap_or(0);
+    let major_faults_per_sec = stat
+        .majflt
+        .saturating_sub(prev_proc.major_faults)
+        .checked_div(time_difference_in_secs)
+        .unwrap_or(0);
+    prev_proc.minor_faults = stat.minflt;
+    prev_proc.major_faults = stat.majflt;
+
+    let (container, unit) = match &cgroup {
+        Ok(cgroup) => (
+            cgroup::container_id(cgroup).map(str::to_string),
+            cgroup::unit(cgroup).map(str::to_string),
+        ),
+        Err(_) => (None, None),
+    };
+
     // This can fail if permission is denied!
     let (total_read_bytes, total_write_bytes, read_bytes_per_sec, write_bytes_per_sec) =
         if let Ok(io) = io {
@@ -248,9 +257,24 @@ fn read_proc(
         ProcessHarvest {
             pid: process.pid,
             parent_pid,
+            is_thread: false,
             cpu_usage_percent,
             mem_usage_percent,
             mem_usage_bytes,
+            virtual_bytes: status.vm_size,
+            shared_bytes: status.shared(),
+            private_bytes: status.rss_anon,
+            swap_bytes: status.vm_swap,
+            num_threads: stat.num_threads,
+            nice: stat.nice as i32,
+            priority: stat.priority as i32,
+            policy: policy_name(stat.policy).into(),
+            processor: stat.processor,
+            minor_faults_per_sec,
+            major_faults_per_sec,
+            cgroup: cgroup.ok(),
+            container,
+            unit,
             name,
             command,
             read_bytes_per_sec,
@@ -272,6 +296,76 @@ fn read_proc(
     ))
 }
 
+/// Reads the threads of a process from `/proc/<PID>/task`, other than the main thread, which is
+/// the process itself.
+fn read_threads(
+    pid: Pid, pid_mapping: &mut HashMap<Pid, PrevProcDetails>, args: ReadProcArgs,
+    user_table: &mut UserTable,
+) -> Vec<ProcessHarvest> {
+    let Ok(tasks) = fs::read_dir(format!("/proc/{pid}/task")) else {
+        return Vec::new();
+    };
+
+    tasks
+        .flatten()
+        .filter_map(|task| {
+            let thread = Process::from_path(task.path()).ok()?;
+            if thread.pid == pid {
+                return None;
+            }
+
+            let name = thread.stat.comm.clone();
+            let prev_thread_details = pid_mapping.entry(thread.pid).or_default();
+            let (thread_harvest, new_thread_times) =
+                read_proc(prev_thread_details, thread, args, user_table).ok()?;
+            prev_thread_details.cpu_time = new_thread_times;
+
+            let ProcessHarvest {
+                pid: tid,
+                cpu_usage_percent,
+                command,
+                process_state,
+                uid,
+                user,
+                time,
+                nice,
+                priority,
+                policy,
+                processor,
+                minor_faults_per_sec,
+                major_faults_per_sec,
+                cgroup,
+                container,
+                unit,
+                ..
+            } = thread_harvest;
+
+            Some(ProcessHarvest {
+                pid: tid,
+                parent_pid: Some(pid),
+                is_thread: true,
+                cpu_usage_percent,
+                name,
+                command,
+                process_state,
+                uid,
+                user,
+                time,
+                nice,
+                priority,
+                policy,
+                processor,
+                minor_faults_per_sec,
+                major_faults_per_sec,
+                cgroup,
+                container,
+                unit,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
 pub(crate) struct PrevProc<'a> {
     pub prev_idle: &'a mut f64,
     pub prev_non_idle: &'a mut f64,
@@ -309,6 +403,7 @@ pub(crate) fn linux_process_data(
         use_current_cpu_total: collector.use_current_cpu_total,
         unnormalized_cpu: collector.unnormalized_cpu,
     };
+    let collect_threads = collector.collect_threads;
     let pid_mapping = &mut collector.pid_mapping;
     let user_table = &mut collector.user_table;
 
@@ -322,12 +417,13 @@ pub(crate) fn linux_process_data(
         prev_non_idle,
     } = prev_proc;
 
-    // TODO: [PROC THREADS] Add threads
-
-    if let Ok(CpuUsage {
+    if let Some(CpuUsage {
         mut cpu_usage,
         cpu_fraction,
-    }) = cpu_usage_calculation(prev_idle, prev_non_idle)
+    }) = collector
+        .cpu_times
+        .get(&CpuDataType::Avg)
+        .map(|times| cpu_usage_calculation(times, prev_idle, prev_non_idle))
     {
         if unnormalized_cpu {
             let num_processors = collector.sys.system.cpus().len() as f64;
@@ -356,7 +452,7 @@ pub(crate) fn linux_process_data(
             uptime: sysinfo::System::uptime(),
         };
 
-        let process_vector: Vec<ProcessHarvest> = pids
+        let mut process_vector: Vec<ProcessHarvest> = pids
             .filter_map(|pid_path| {
                 if let Ok(process) = Process::from_path(pid_path) {
                     let pid = process.pid;
@@ -396,6 +492,19 @@ pub(crate) fn linux_process_data(
             })
             .collect();
 
+        if collect_threads {
+            let threads = process_vector
+                .iter()
+                .flat_map(|process| read_threads(process.pid, pid_mapping, args, user_table))
+                .collect::<Vec<_>>();
+
+            for thread in &threads {
+                pids_to_clear.remove(&thread.pid);
+            }
+
+            process_vector.extend(threads);
+        }
+
         pids_to_clear.iter().for_each(|pid| {
             pid_mapping.remove(pid);
         });
@@ -407,47 +516,3 @@ pub(crate) fn linux_process_data(
         ))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_proc_cpu_parse() {
-        assert_eq!(
-            (100_f64, 200_f64),
-            fetch_cpu_usage("100 0 100 100"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 4 values"
-        );
-        assert_eq!(
-            (120_f64, 200_f64),
-            fetch_cpu_usage("100 0 100 100 20"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 5 values"
-        );
-        assert_eq!(
-            (120_f64, 230_f64),
-            fetch_cpu_usage("100 0 100 100 20 30"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 6 values"
-        );
-        assert_eq!(
-            (120_f64, 270_f64),
-            fetch_cpu_usage("100 0 100 100 20 30 40"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 7 values"
-        );
-        assert_eq!(
-            (120_f64, 320_f64),
-            fetch_cpu_usage("100 0 100 100 20 30 40 50"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 8 values"
-        );
-        assert_eq!(
-            (120_f64, 320_f64),
-            fetch_cpu_usage("100 0 100 100 20 30 40 50 100"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 9 values"
-        );
-        assert_eq!(
-            (120_f64, 320_f64),
-            fetch_cpu_usage("100 0 100 100 20 30 40 50 100 200"),
-            "Failed to properly calculate idle/non-idle for /proc/stat CPU with 10 values"
-        );
-    }
-}
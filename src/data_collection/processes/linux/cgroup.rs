@@ -0,0 +1,124 @@
+//! Resolves the cgroup of a process from `/proc/<PID>/cgroup`, along with the container and
+//! systemd unit it belongs to, if any.
+
+/// The length of a full container ID, as used by Docker, Podman, containerd, and CRI-O.
+const CONTAINER_ID_LEN: usize = 64;
+
+/// Returns the cgroup path from the contents of `/proc/<PID>/cgroup`. This prefers the unified
+/// (v2) hierarchy, then the systemd hierarchy on v1, then whichever hierarchy comes first.
+pub(crate) fn cgroup_path(cgroup: &str) -> Option<&str> {
+    let mut first = None;
+    let mut systemd = None;
+
+    for line in cgroup.lines() {
+        let mut parts = line.splitn(3, ':');
+        let (Some(_hierarchy), Some(controllers), Some(path)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+
+        if controllers.is_empty() {
+            return Some(path);
+        } else if controllers == "name=systemd" {
+            systemd = Some(path);
+        } else if first.is_none() {
+            first = Some(path);
+        }
+    }
+
+    systemd.or(first)
+}
+
+/// Returns the container ID in a single cgroup path component, such as `docker-<ID>.scope`,
+/// `cri-containerd-<ID>.scope`, or just `<ID>` with the cgroupfs driver.
+fn component_container_id(component: &str) -> Option<&str> {
+    let component = component.strip_suffix(".scope").unwrap_or(component);
+    let id = component
+        .rsplit_once('-')
+        .map_or(component, |(_prefix, id)| id);
+
+    (id.len() == CONTAINER_ID_LEN && id.bytes().all(|b| b.is_ascii_hexdigit())).then_some(id)
+}
+
+/// Returns the ID of the container a cgroup path belongs to, if any.
+pub(crate) fn container_id(path: &str) -> Option<&str> {
+    path.rsplit('/').find_map(component_container_id)
+}
+
+/// Returns the Kubernetes pod slice or systemd unit a cgroup path belongs to, if any. Pods take
+/// precedence, and otherwise this is the deepest service or scope that isn't a container.
+pub(crate) fn unit(path: &str) -> Option<&str> {
+    let mut unit = None;
+
+    for component in path.split('/') {
+        let is_pod = (component.starts_with("kubepods") && component.contains("-pod"))
+            || (component.starts_with("pod") && path.starts_with("/kubepods/"));
+
+        if is_pod {
+            return Some(component);
+        } else if (component.ends_with(".service") || component.ends_with(".scope"))
+            && component_container_id(component).is_none()
+        {
+            unit = Some(component);
+        }
+    }
+
+    unit
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ID: &str = "8c5fbb7e25d4a9f9e0e4d8bb2ad0cde2c53ff4bd6a2c6d4a90b4b1f5e5c1a0b2";
+
+    #[test]
+    fn finds_cgroup_path() {
+        assert_eq!(
+            cgroup_path("0::/system.slice/sshd.service\n"),
+            Some("/system.slice/sshd.service")
+        );
+
+        let v1 = "12:cpu,cpuacct:/docker/abc\n1:name=systemd:/system.slice/docker.service\n";
+        assert_eq!(cgroup_path(v1), Some("/system.slice/docker.service"));
+
+        assert_eq!(cgroup_path("4:memory:/user.slice\n"), Some("/user.slice"));
+        assert_eq!(cgroup_path(""), None);
+    }
+
+    #[test]
+    fn finds_containers_and_units() {
+        let docker = format!("/system.slice/docker-{ID}.scope");
+        assert_eq!(container_id(&docker), Some(ID));
+        assert_eq!(unit(&docker), None);
+
+        let kubernetes = format!(
+            "/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod5f3d_9a1b.slice/cri-containerd-{ID}.scope"
+        );
+        assert_eq!(container_id(&kubernetes), Some(ID));
+        assert_eq!(
+            unit(&kubernetes),
+            Some("kubepods-burstable-pod5f3d_9a1b.slice")
+        );
+
+        let cgroupfs = format!("/kubepods/besteffort/pod5f3d-9a1b/{ID}");
+        assert_eq!(container_id(&cgroupfs), Some(ID));
+        assert_eq!(unit(&cgroupfs), Some("pod5f3d-9a1b"));
+
+        let podman = format!(
+            "/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{ID}.scope/container"
+        );
+        assert_eq!(container_id(&podman), Some(ID));
+        assert_eq!(unit(&podman), Some("user@1000.service"));
+
+        let service = "/system.slice/sshd.service";
+        assert_eq!(container_id(service), None);
+        assert_eq!(unit(service), Some("sshd.service"));
+
+        let app = "/user.slice/user-1000.slice/user@1000.service/app.slice/app-firefox-1234.scope";
+        assert_eq!(unit(app), Some("app-firefox-1234.scope"));
+        assert_eq!(container_id("/"), None);
+        assert_eq!(unit("/"), None);
+    }
+}
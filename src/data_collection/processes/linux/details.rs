@@ -0,0 +1,281 @@
+//! Reads the details of a single process from `/proc/<PID>`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use hashbrown::HashMap;
+
+use super::process::Process;
+use crate::{
+    data_collection::processes::details::{
+        Limit, MappingSummary, OpenFile, ProcessDetails, ThreadDetails,
+    },
+    Pid,
+};
+
+/// The most parents to follow, in case the chain somehow loops.
+const MAX_PARENTS: usize = 64;
+
+pub(crate) fn read_details(pid: Pid) -> anyhow::Result<ProcessDetails> {
+    let root = PathBuf::from(format!("/proc/{pid}"));
+    let process = Process::from_path(root.clone())
+        .with_context(|| format!("Process {pid} could not be read, it may have exited."))?;
+
+    let start_time = Some(rustix::param::clock_ticks_per_second())
+        .filter(|&ticks_per_sec| ticks_per_sec > 0)
+        .map(|ticks_per_sec| {
+            sysinfo::System::boot_time() + process.stat.start_time / ticks_per_sec
+        });
+
+    Ok(ProcessDetails {
+        pid,
+        name: process.stat.comm.clone(),
+        command: process.cmdline.unwrap_or_default(),
+        exe: fs::read_link(root.join("exe")).map_err(Into::into),
+        cwd: fs::read_link(root.join("cwd")).map_err(Into::into),
+        environment: read_environment(&root),
+        start_time,
+        parents: parents(process.stat.ppid),
+        open_files: open_files(&root),
+        memory_maps: fs::read_to_string(root.join("maps"))
+            .map(|maps| summarize_maps(&maps))
+            .map_err(Into::into),
+        threads: threads(&root),
+        cgroups: fs::read_to_string(root.join("cgroup"))
+            .map(|cgroups| cgroups.lines().map(str::to_string).collect())
+            .map_err(Into::into),
+        namespaces: namespaces(&root),
+        limits: fs::read_to_string(root.join("limits"))
+            .map(|limits| parse_limits(&limits))
+            .map_err(Into::into),
+    })
+}
+
+fn read_environment(root: &Path) -> anyhow::Result<Vec<String>> {
+    let environ = fs::read(root.join("environ"))?;
+
+    Ok(environ
+        .split(|&b| b == 0)
+        .filter(|var| !var.is_empty())
+        .map(|var| String::from_utf8_lossy(var).into_owned())
+        .collect())
+}
+
+/// Follows the parents of a process up to the root.
+fn parents(mut ppid: Pid) -> Vec<(Pid, String)> {
+    let mut parents = Vec::new();
+
+    while ppid > 0 && parents.len() < MAX_PARENTS {
+        let Ok(parent) = Process::from_path(PathBuf::from(format!("/proc/{ppid}"))) else {
+            break;
+        };
+
+        parents.push((ppid, parent.stat.comm));
+        ppid = parent.stat.ppid;
+    }
+
+    parents
+}
+
+fn open_files(root: &Path) -> anyhow::Result<Vec<OpenFile>> {
+    let mut open_files = fs::read_dir(root.join("fd"))?
+        .flatten()
+        .filter_map(|entry| {
+            let fd = entry.file_name().to_string_lossy().parse().ok()?;
+            let target = fs::read_link(entry.path()).ok()?;
+
+            Some(OpenFile {
+                fd,
+                target: target.to_string_lossy().into_owned(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    open_files.sort_by_key(|file| file.fd);
+
+    Ok(open_files)
+}
+
+fn threads(root: &Path) -> anyhow::Result<Vec<ThreadDetails>> {
+    let mut threads = fs::read_dir(root.join("task"))?
+        .flatten()
+        .filter_map(|entry| {
+            let thread = Process::from_path(entry.path()).ok()?;
+
+            Some(ThreadDetails {
+                tid: thread.pid,
+                name: thread.stat.comm,
+                state: thread.stat.state,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    threads.sort_by_key(|thread| thread.tid);
+
+    Ok(threads)
+}
+
+fn namespaces(root: &Path) -> anyhow::Result<Vec<(String, String)>> {
+    let mut namespaces = fs::read_dir(root.join("ns"))?
+        .flatten()
+        .filter_map(|entry| {
+            let target = fs::read_link(entry.path()).ok()?;
+
+            Some((
+                entry.file_name().to_string_lossy().into_owned(),
+                target.to_string_lossy().into_owned(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    namespaces.sort();
+
+    Ok(namespaces)
+}
+
+/// Totals up the mappings in `/proc/<PID>/maps` by what they map, largest first. Anonymous
+/// mappings are grouped together.
+fn summarize_maps(maps: &str) -> Vec<MappingSummary> {
+    let mut summaries: HashMap<&str, (u64, usize)> = HashMap::new();
+
+    for line in maps.lines() {
+        let Some((start, end)) = line
+            .split_whitespace()
+            .next()
+            .and_then(|range| range.split_once('-'))
+        else {
+            continue;
+        };
+        let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16))
+        else {
+            continue;
+        };
+
+        // Skip the permissions, offset, device, and inode to get to the path, which may contain
+        // spaces.
+        let name = line
+            .splitn(6, char::is_whitespace)
+            .nth(5)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or("[anonymous]");
+
+        let summary = summaries.entry(name).or_default();
+        summary.0 += end.saturating_sub(start);
+        summary.1 += 1;
+    }
+
+    let mut summaries = summaries
+        .into_iter()
+        .map(|(name, (size, count))| MappingSummary {
+            name: name.to_string(),
+            size,
+            count,
+        })
+        .collect::<Vec<_>>();
+
+    summaries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+    summaries
+}
+
+/// Parses `/proc/<PID>/limits`, which is a table whose columns are aligned with its header.
+fn parse_limits(limits: &str) -> Vec<Limit> {
+    let mut lines = limits.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let (Some(soft_start), Some(hard_start), Some(units_start)) = (
+        header.find("Soft Limit"),
+        header.find("Hard Limit"),
+        header.find("Units"),
+    ) else {
+        return Vec::new();
+    };
+
+    let column = |line: &str, start: usize, end: usize| {
+        line.get(start..end.min(line.len()))
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    };
+
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Limit {
+            name: column(line, 0, soft_start),
+            soft: column(line, soft_start, hard_start),
+            hard: column(line, hard_start, units_start),
+            units: column(line, units_start, line.len()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn summarizes_maps() {
+        let maps = "\
+55d0c1a00000-55d0c1a28000 r--p 00000000 fd:01 1234                       /usr/bin/btm
+55d0c1a28000-55d0c1b00000 r-xp 00028000 fd:01 1234                       /usr/bin/btm
+55d0c2000000-55d0c2100000 rw-p 00000000 00:00 0                          [heap]
+7f0000000000-7f0000400000 rw-p 00000000 00:00 0
+7f0000400000-7f0000402000 rw-p 00000000 00:00 0
+7f0000500000-7f0000501000 r--p 00000000 fd:01 99                         /tmp/with space";
+
+        assert_eq!(
+            summarize_maps(maps),
+            vec![
+                MappingSummary {
+                    name: "[anonymous]".to_string(),
+                    size: 0x402000,
+                    count: 2,
+                },
+                MappingSummary {
+                    name: "/usr/bin/btm".to_string(),
+                    size: 0x100000,
+                    count: 2,
+                },
+                MappingSummary {
+                    name: "[heap]".to_string(),
+                    size: 0x100000,
+                    count: 1,
+                },
+                MappingSummary {
+                    name: "/tmp/with space".to_string(),
+                    size: 0x1000,
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_limits() {
+        let limits = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max open files            1024                 524288               files
+Max realtime timeout      unlimited            unlimited            us
+";
+
+        let limits = parse_limits(limits);
+
+        assert_eq!(limits.len(), 3);
+        assert_eq!(
+            limits[1],
+            Limit {
+                name: "Max open files".to_string(),
+                soft: "1024".to_string(),
+                hard: "524288".to_string(),
+                units: "files".to_string(),
+            }
+        );
+        assert_eq!(limits[2].units, "us");
+    }
+}
@@ -16,6 +16,7 @@ use rustix::{
     path::Arg,
 };
 
+use super::cgroup;
 use crate::Pid;
 
 static PAGESIZE: OnceLock<u64> = OnceLock::new();
@@ -46,11 +47,34 @@ pub(crate) struct Stat {
     /// The amount of time this process has been scheduled in kernel mode in clock ticks.
     pub stime: u64,
 
+    /// The number of minor faults, which did not require loading a page from disk.
+    pub minflt: u64,
+
+    /// The number of major faults, which required loading a page from disk.
+    pub majflt: u64,
+
     /// The resident set size, or the number of pages the process has in real memory.
     pub rss: u64,
 
     /// The start time of the process, represented in clock ticks.
     pub start_time: u64,
+
+    /// The scheduling priority as shown by the kernel. For normal processes this is the nice
+    /// value plus 20, and for real-time processes this is the negated real-time priority minus
+    /// one.
+    pub priority: i64,
+
+    /// The nice value, from 19 (lowest priority) to -20 (highest priority).
+    pub nice: i64,
+
+    /// The number of threads in the process.
+    pub num_threads: u64,
+
+    /// The CPU number the process last ran on.
+    pub processor: u32,
+
+    /// The scheduling policy, as one of the `SCHED_*` constants.
+    pub policy: u32,
 }
 
 impl Stat {
@@ -60,7 +84,10 @@ impl Stat {
         // we can't just use read_to_string.
         f.read_to_end(unsafe { buffer.as_mut_vec() })?;
 
-        let line = buffer.to_string_lossy();
+        Stat::parse(&buffer.to_string_lossy())
+    }
+
+    fn parse(line: &str) -> anyhow::Result<Stat> {
         let line = line.trim();
 
         let (comm, rest) = {
@@ -82,27 +109,57 @@ impl Stat {
             .ok_or_else(|| anyhow!("missing state"))?;
         let ppid: Pid = next_part(&mut rest)?.parse()?;
 
-        // Skip 9 fields until utime (pgrp, session, tty_nr, tpgid, flags, minflt, cminflt, majflt, cmajflt).
-        let mut rest = rest.skip(9);
+        // Skip 5 fields until minflt (pgrp, session, tty_nr, tpgid, flags).
+        let mut rest = rest.skip(5);
+        let minflt: u64 = next_part(&mut rest)?.parse()?;
+
+        // Skip one field until majflt (cminflt).
+        let mut rest = rest.skip(1);
+        let majflt: u64 = next_part(&mut rest)?.parse()?;
+
+        // Skip one field until utime (cmajflt).
+        let mut rest = rest.skip(1);
         let utime: u64 = next_part(&mut rest)?.parse()?;
         let stime: u64 = next_part(&mut rest)?.parse()?;
 
-        // Skip 6 fields until starttime (cutime, cstime, priority, nice, num_threads, itrealvalue).
-        let mut rest = rest.skip(6);
+        // Skip 2 fields until priority (cutime, cstime).
+        let mut rest = rest.skip(2);
+        let priority: i64 = next_part(&mut rest)?.parse()?;
+        let nice: i64 = next_part(&mut rest)?.parse()?;
+        let num_threads: u64 = next_part(&mut rest)?.parse()?;
+
+        // Skip one field until starttime (itrealvalue).
+        let mut rest = rest.skip(1);
         let start_time: u64 = next_part(&mut rest)?.parse()?;
 
         // Skip one field until rss (vsize)
         let mut rest = rest.skip(1);
         let rss: u64 = next_part(&mut rest)?.parse()?;
 
+        // Skip 14 fields until processor (rsslim, startcode, endcode, startstack, kstkesp,
+        // kstkeip, signal, blocked, sigignore, sigcatch, wchan, nswap, cnswap, exit_signal).
+        let mut rest = rest.skip(14);
+        let processor: u32 = next_part(&mut rest)?.parse()?;
+
+        // Skip one field until policy (rt_priority).
+        let mut rest = rest.skip(1);
+        let policy: u32 = next_part(&mut rest)?.parse()?;
+
         Ok(Stat {
             comm,
             state,
             ppid,
             utime,
             stime,
+            minflt,
+            majflt,
             rss,
             start_time,
+            priority,
+            nice,
+            num_threads,
+            processor,
+            policy,
         })
     }
 
@@ -189,6 +246,71 @@ impl Io {
     }
 }
 
+/// A wrapper around the memory data in `/proc/<PID>/status`. Kernel threads don't have any of
+/// these fields, in which case they are zero.
+///
+/// Note this does not necessarily get all fields, only the ones we use in bottom.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Status {
+    /// The virtual memory size in bytes.
+    pub vm_size: u64,
+
+    /// The resident anonymous memory in bytes, such as the heap and stack.
+    pub rss_anon: u64,
+
+    /// The resident file mappings in bytes, which may be shared with other processes.
+    pub rss_file: u64,
+
+    /// The resident shared memory in bytes.
+    pub rss_shmem: u64,
+
+    /// The amount of memory swapped out in bytes.
+    pub vm_swap: u64,
+}
+
+impl Status {
+    #[inline]
+    fn from_file(mut f: File, buffer: &mut String) -> anyhow::Result<Status> {
+        f.read_to_string(buffer)?;
+
+        Ok(Status::parse(buffer))
+    }
+
+    fn parse(status: &str) -> Status {
+        let mut result = Status::default();
+
+        for line in status.lines() {
+            let Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let field = match field {
+                "VmSize" => &mut result.vm_size,
+                "RssAnon" => &mut result.rss_anon,
+                "RssFile" => &mut result.rss_file,
+                "RssShmem" => &mut result.rss_shmem,
+                "VmSwap" => &mut result.vm_swap,
+                _ => continue,
+            };
+
+            // These are all in kibibytes, despite being labelled as "kB".
+            if let Some(kib) = value
+                .split_whitespace()
+                .next()
+                .and_then(|kib| kib.parse::<u64>().ok())
+            {
+                *field = kib * 1024;
+            }
+        }
+
+        result
+    }
+
+    /// The resident memory that may be shared with other processes.
+    pub fn shared(&self) -> u64 {
+        self.rss_file + self.rss_shmem
+    }
+}
+
 /// A wrapper around a Linux process operations in `/proc/<PID>`.
 ///
 /// Core documentation based on [proc's manpages](https://man7.org/linux/man-pages/man5/proc.5.html).
@@ -197,6 +319,10 @@ pub(crate) struct Process {
     pub uid: Option<uid_t>,
     pub stat: Stat,
     pub io: anyhow::Result<Io>,
+    pub status: anyhow::Result<Status>,
+
+    /// The path of the cgroup the process is in.
+    pub cgroup: anyhow::Result<String>,
     pub cmdline: anyhow::Result<Vec<String>>,
 }
 
@@ -256,12 +382,27 @@ impl Process {
         reset(&mut root, &mut buffer);
 
         let io = open_at(&mut root, "io", &fd).and_then(|file| Io::from_file(file, &mut buffer));
+        reset(&mut root, &mut buffer);
+
+        let status =
+            open_at(&mut root, "status", &fd).and_then(|file| Status::from_file(file, &mut buffer));
+        reset(&mut root, &mut buffer);
+
+        let cgroup = open_at(&mut root, "cgroup", &fd).and_then(|mut file| {
+            file.read_to_string(&mut buffer)?;
+
+            cgroup::cgroup_path(&buffer)
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("cgroup missing"))
+        });
 
         Ok(Process {
             pid,
             uid,
             stat,
             io,
+            status,
+            cgroup,
             cmdline,
         })
     }
@@ -295,3 +436,63 @@ fn open_at(root: &mut PathBuf, child: &str, fd: &OwnedFd) -> anyhow::Result<File
 
     Ok(File::from(new_fd))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_stat() {
+        let stat =
+            "1234 (Web Content) S 1200 1234 1200 0 -1 4194560 52000 0 17 0 812 140 0 0 15 -5 \
+                    42 0 606794 2703360 285 18446744073709551615 1 1 0 0 0 0 0 4096 0 0 0 0 17 3 \
+                    0 3 0 0 0 0 0 0 0 0 0 0 0\n";
+
+        let stat = Stat::parse(stat).unwrap();
+
+        assert_eq!(stat.comm, "Web Content");
+        assert_eq!(stat.state, 'S');
+        assert_eq!(stat.ppid, 1200);
+        assert_eq!(stat.minflt, 52000);
+        assert_eq!(stat.majflt, 17);
+        assert_eq!(stat.utime, 812);
+        assert_eq!(stat.stime, 140);
+        assert_eq!(stat.priority, 15);
+        assert_eq!(stat.nice, -5);
+        assert_eq!(stat.num_threads, 42);
+        assert_eq!(stat.start_time, 606794);
+        assert_eq!(stat.rss, 285);
+        assert_eq!(stat.processor, 3);
+        assert_eq!(stat.policy, 3);
+    }
+
+    #[test]
+    fn parses_status() {
+        let status = "\
+Name:\tbtm
+Umask:\t0022
+State:\tS (sleeping)
+VmPeak:\t  200000 kB
+VmSize:\t  123456 kB
+VmRSS:\t    2048 kB
+RssAnon:\t    1024 kB
+RssFile:\t     768 kB
+RssShmem:\t     256 kB
+VmSwap:\t      12 kB
+Threads:\t4
+";
+
+        assert_eq!(
+            Status::parse(status),
+            Status {
+                vm_size: 123456 * 1024,
+                rss_anon: 1024 * 1024,
+                rss_file: 768 * 1024,
+                rss_shmem: 256 * 1024,
+                vm_swap: 12 * 1024,
+            }
+        );
+        assert_eq!(Status::parse(status).shared(), 1024 * 1024);
+        assert_eq!(Status::parse("Name:\tkthreadd\n"), Status::default());
+    }
+}
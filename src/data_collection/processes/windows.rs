@@ -84,6 +84,7 @@ pub fn sysinfo_process_data(
         process_vector.push(ProcessHarvest {
             pid: process_val.pid().as_u32() as _,
             parent_pid: process_val.parent().map(|p| p.as_u32() as _),
+            is_thread: false,
             name,
             command,
             mem_usage_percent: if total_memory > 0 {
@@ -92,6 +93,20 @@ pub fn sysinfo_process_data(
                 0.0
             } as f32,
             mem_usage_bytes: process_val.memory(),
+            virtual_bytes: process_val.virtual_memory(),
+            shared_bytes: 0,
+            private_bytes: 0,
+            swap_bytes: 0,
+            num_threads: 0,
+            nice: 0,
+            priority: 0,
+            policy: "".into(),
+            processor: 0,
+            minor_faults_per_sec: 0,
+            major_faults_per_sec: 0,
+            cgroup: None,
+            container: None,
+            unit: None,
             cpu_usage_percent: process_cpu_usage,
             read_bytes_per_sec: disk_usage.read_bytes,
             write_bytes_per_sec: disk_usage.written_bytes,
@@ -13,14 +13,58 @@ cfg_if::cfg_if! {
     }
 }
 
+use serde::{Deserialize, Serialize};
+
 use crate::app::filter::Filter;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct TempHarvest {
     pub name: String,
     pub temperature: Option<f32>,
 }
 
+/// The kind of a sensor that is not a temperature sensor, like a fan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SensorKind {
+    Fan,
+    Voltage,
+    Power,
+    Current,
+    Energy,
+}
+
+impl SensorKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SensorKind::Fan => "Fan",
+            SensorKind::Voltage => "Voltage",
+            SensorKind::Power => "Power",
+            SensorKind::Current => "Current",
+            SensorKind::Energy => "Energy",
+        }
+    }
+
+    /// The unit of the value of this kind of sensor.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            SensorKind::Fan => "RPM",
+            SensorKind::Voltage => "V",
+            SensorKind::Power => "W",
+            SensorKind::Current => "A",
+            SensorKind::Energy => "J",
+        }
+    }
+}
+
+/// A reading from a sensor that is not a temperature sensor. The value is in RPM, volts, watts,
+/// amps, or joules, depending on the kind of sensor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensorHarvest {
+    pub name: String,
+    pub kind: SensorKind,
+    pub value: f64,
+}
+
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
 pub enum TemperatureType {
     #[default]
@@ -8,7 +8,7 @@ use std::{
 use anyhow::Result;
 use hashbrown::{HashMap, HashSet};
 
-use super::{is_temp_filtered, TempHarvest, TemperatureType};
+use super::{is_temp_filtered, SensorHarvest, SensorKind, TempHarvest, TemperatureType};
 use crate::{app::filter::Filter, utils::error::BottomError};
 
 const EMPTY_NAME: &str = "Unknown";
@@ -16,7 +16,7 @@ const EMPTY_NAME: &str = "Unknown";
 /// Returned results from grabbing hwmon/coretemp temperature sensor values/names.
 struct HwmonResults {
     temperatures: Vec<TempHarvest>,
-    num_hwmon: usize,
+    sensors: Vec<SensorHarvest>,
 }
 
 /// Parses and reads temperatures that were in millidegree Celsius, and if successful, returns a temperature in Celsius.
@@ -28,6 +28,102 @@ fn parse_temp(path: &Path) -> Result<f32> {
         / 1_000.0)
 }
 
+/// Parses and reads a fan, voltage, power, current, or energy sensor, converting it from the
+/// millivolts, microwatts, milliamps, or microjoules that hwmon uses.
+fn parse_sensor(path: &Path, kind: SensorKind) -> Result<f64> {
+    let divisor = match kind {
+        SensorKind::Fan => 1.0,
+        SensorKind::Voltage | SensorKind::Current => 1_000.0,
+        SensorKind::Power | SensorKind::Energy => 1_000_000.0,
+    };
+
+    Ok(fs::read_to_string(path)?
+        .trim_end()
+        .parse::<f64>()
+        .map_err(|e| BottomError::ConversionError(e.to_string()))?
+        / divisor)
+}
+
+/// Returns the kind of sensor a file in a hwmon directory is for if it holds the reading of a fan,
+/// voltage, power, current, or energy sensor, like `fan1_input`. This also returns the channel of
+/// the sensor, like `fan1`, which its other files such as its label start with.
+///
+/// Power sensors may report an average instead of an instantaneous value, in which case the
+/// average is only used if there is no instantaneous value.
+fn sensor_file<'a>(dir: &Path, file_name: &'a str) -> Option<(SensorKind, &'a str)> {
+    let (channel, suffix) = file_name.split_once('_')?;
+
+    let kind = [
+        ("fan", SensorKind::Fan),
+        ("in", SensorKind::Voltage),
+        ("power", SensorKind::Power),
+        ("curr", SensorKind::Current),
+        ("energy", SensorKind::Energy),
+    ]
+    .into_iter()
+    .find_map(|(prefix, kind)| {
+        let index = channel.strip_prefix(prefix)?;
+        (!index.is_empty() && index.bytes().all(|b| b.is_ascii_digit())).then_some(kind)
+    })?;
+
+    match suffix {
+        "input" => Some((kind, channel)),
+        "average" if kind == SensorKind::Power => {
+            (!dir.join(format!("{channel}_input")).exists()).then_some((kind, channel))
+        }
+        _ => None,
+    }
+}
+
+/// Gets the name of the device that a hwmon directory is for, if it has one:
+/// - For GPUs, this will use the kernel device name, ex `card0`
+/// - For nvme drives, this will also use the kernel name, ex `nvme0`.
+///   This is found differently than for GPUs
+/// - For whatever acpitz is, on my machine this is now `thermal_zone0`.
+/// - For k10temp, this will still be k10temp, but it has to be handled special.
+fn hwmon_device_name(file_path: &Path, sensor_name: Option<&String>) -> Option<String> {
+    let device = file_path.join("device");
+
+    // This will exist for GPUs but not others, this is how we find their kernel name.
+    let drm = device.join("drm");
+    if drm.exists() {
+        // This should never actually be empty. If it is though, we'll fall back to the sensor name
+        // later on.
+        let mut gpu = None;
+
+        if let Ok(cards) = drm.read_dir() {
+            for card in cards.flatten() {
+                if let Some(name) = card.file_name().to_str() {
+                    if name.starts_with("card") {
+                        gpu = Some(humanize_name(name.trim().to_string(), sensor_name));
+                        break;
+                    }
+                }
+            }
+        }
+
+        gpu
+    } else {
+        // This little mess is to account for stuff like k10temp. This is needed because the
+        // `device` symlink points to `nvme*` for nvme drives, but to PCI buses for anything
+        // else. If the first character is alphabetic, it's an actual name like k10temp or
+        // nvme0, not a PCI bus.
+        fs::read_link(device).ok().and_then(|link| {
+            let link = link
+                .file_name()
+                .and_then(|f| f.to_str())
+                .map(|s| s.trim().to_owned());
+
+            match link {
+                Some(link) if link.as_bytes().first().is_some_and(u8::is_ascii_alphabetic) => {
+                    Some(humanize_name(link, sensor_name))
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
 /// Get all candidates from hwmon and coretemp. It will also return the number of entries from hwmon.
 fn get_hwmon_candidates() -> (HashSet<PathBuf>, usize) {
     let mut dirs = HashSet::default();
@@ -199,9 +295,10 @@ fn is_device_awake(path: &Path) -> bool {
     }
 }
 
-/// Get temperature sensors from the linux sysfs interface `/sys/class/hwmon` and
-/// `/sys/devices/platform/coretemp.*`. It returns all found temperature sensors, and the number
-/// of checked hwmon directories (not coretemp directories).
+/// Get temperature sensors from the given directories of the linux sysfs interface
+/// `/sys/class/hwmon` and `/sys/devices/platform/coretemp.*`. It returns all found temperature
+/// sensors that pass `filter`. If `get_sensors` is set, it also returns the fan, voltage, power,
+/// current, and energy sensors that pass `sensor_filter`.
 ///
 /// For more details, see the relevant Linux kernel documentation:
 /// - [`/sys/class/hwmon`](https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-class-hwmon)
@@ -217,11 +314,15 @@ fn is_device_awake(path: &Path) -> bool {
 /// the device is already in ACPI D0. This has the notable issue that
 /// once this happens, the device will be *kept* on through the sensor
 /// reading, and not be able to re-enter ACPI D3cold.
-fn hwmon_temperatures(temp_type: &TemperatureType, filter: &Option<Filter>) -> HwmonResults {
+fn hwmon_temperatures(
+    dirs: impl IntoIterator<Item = PathBuf>, temp_type: &TemperatureType, filter: &Option<Filter>,
+    get_sensors: bool, sensor_filter: &Option<Filter>,
+) -> HwmonResults {
     let mut temperatures: Vec<TempHarvest> = vec![];
+    let mut sensors: Vec<SensorHarvest> = vec![];
     let mut seen_names: HashMap<String, u32> = HashMap::new();
-
-    let (dirs, num_hwmon) = get_hwmon_candidates();
+    // These are counted separately so that showing other sensors doesn't rename any temperatures.
+    let mut seen_sensor_names: HashMap<String, u32> = HashMap::new();
 
     // Note that none of this is async if we ever go back to it, but sysfs is in
     // memory, so in theory none of this should block if we're slightly careful.
@@ -247,6 +348,8 @@ fn hwmon_temperatures(temp_type: &TemperatureType, filter: &Option<Filter>) -> H
             continue;
         }
 
+        let hwmon_name = hwmon_device_name(&file_path, sensor_name.as_ref());
+
         if let Ok(dir_entries) = file_path.read_dir() {
             // Enumerate the devices temperature sensors
             for file in dir_entries.flatten() {
@@ -255,6 +358,29 @@ fn hwmon_temperatures(temp_type: &TemperatureType, filter: &Option<Filter>) -> H
 
                 // We only want temperature sensors, skip others early
                 if !(name.starts_with("temp") && name.ends_with("input")) {
+                    if !get_sensors {
+                        continue;
+                    }
+
+                    if let Some((kind, channel)) = sensor_file(&file_path, &name) {
+                        // Unlabelled sensors are named after their channel, like `sensors` does.
+                        let sensor_label =
+                            read_to_string_lossy(file_path.join(format!("{channel}_label")))
+                                .unwrap_or_else(|| channel.to_string());
+                        let name = finalize_name(
+                            hwmon_name.clone(),
+                            Some(sensor_label),
+                            &sensor_name,
+                            &mut seen_sensor_names,
+                        );
+
+                        if is_temp_filtered(sensor_filter, &name) {
+                            if let Ok(value) = parse_sensor(&file.path(), kind) {
+                                sensors.push(SensorHarvest { name, kind, value });
+                            }
+                        }
+                    }
+
                     continue;
                 }
 
@@ -262,59 +388,12 @@ fn hwmon_temperatures(temp_type: &TemperatureType, filter: &Option<Filter>) -> H
                 let sensor_label_path = file_path.join(name.replace("input", "label"));
                 let sensor_label = read_to_string_lossy(sensor_label_path);
 
-                // Do some messing around to get a more sensible name for sensors:
-                // - For GPUs, this will use the kernel device name, ex `card0`
-                // - For nvme drives, this will also use the kernel name, ex `nvme0`.
-                //   This is found differently than for GPUs
-                // - For whatever acpitz is, on my machine this is now `thermal_zone0`.
-                // - For k10temp, this will still be k10temp, but it has to be handled special.
-                let hwmon_name = {
-                    let device = file_path.join("device");
-
-                    // This will exist for GPUs but not others, this is how we find their kernel name.
-                    let drm = device.join("drm");
-                    if drm.exists() {
-                        // This should never actually be empty. If it is though, we'll fall back to the sensor name
-                        // later on.
-                        let mut gpu = None;
-
-                        if let Ok(cards) = drm.read_dir() {
-                            for card in cards.flatten() {
-                                if let Some(name) = card.file_name().to_str() {
-                                    if name.starts_with("card") {
-                                        gpu = Some(humanize_name(
-                                            name.trim().to_string(),
-                                            sensor_name.as_ref(),
-                                        ));
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-
-                        gpu
-                    } else {
-                        // This little mess is to account for stuff like k10temp. This is needed because the
-                        // `device` symlink points to `nvme*` for nvme drives, but to PCI buses for anything
-                        // else. If the first character is alphabetic, it's an actual name like k10temp or
-                        // nvme0, not a PCI bus.
-                        fs::read_link(device).ok().and_then(|link| {
-                            let link = link
-                                .file_name()
-                                .and_then(|f| f.to_str())
-                                .map(|s| s.trim().to_owned());
-
-                            match link {
-                                Some(link) if link.as_bytes()[0].is_ascii_alphabetic() => {
-                                    Some(humanize_name(link, sensor_name.as_ref()))
-                                }
-                                _ => None,
-                            }
-                        })
-                    }
-                };
-
-                let name = finalize_name(hwmon_name, sensor_label, &sensor_name, &mut seen_names);
+                let name = finalize_name(
+                    hwmon_name.clone(),
+                    sensor_label,
+                    &sensor_name,
+                    &mut seen_names,
+                );
 
                 // TODO: It's possible we may want to move the filter check further up to avoid probing hwmon if not needed?
                 if is_temp_filtered(filter, &name) {
@@ -331,7 +410,7 @@ fn hwmon_temperatures(temp_type: &TemperatureType, filter: &Option<Filter>) -> H
 
     HwmonResults {
         temperatures,
-        num_hwmon,
+        sensors,
     }
 }
 
@@ -382,24 +461,138 @@ fn add_thermal_zone_temperatures(
     }
 }
 
-/// Gets temperature sensors and data.
+/// Gets temperature sensors and data. If `get_sensors` is set, this also gets the fan, voltage,
+/// power, current, and energy sensors from hwmon.
 pub fn get_temperature_data(
-    temp_type: &TemperatureType, filter: &Option<Filter>,
-) -> Result<Option<Vec<TempHarvest>>> {
-    let mut results = hwmon_temperatures(temp_type, filter);
+    temp_type: &TemperatureType, filter: &Option<Filter>, get_sensors: bool,
+    sensor_filter: &Option<Filter>,
+) -> Result<(Option<Vec<TempHarvest>>, Option<Vec<SensorHarvest>>)> {
+    let (dirs, num_hwmon) = get_hwmon_candidates();
+    let mut results = hwmon_temperatures(dirs, temp_type, filter, get_sensors, sensor_filter);
 
-    if results.num_hwmon == 0 {
+    if num_hwmon == 0 {
         add_thermal_zone_temperatures(&mut results.temperatures, temp_type, filter);
     }
 
-    Ok(Some(results.temperatures))
+    Ok((
+        Some(results.temperatures),
+        get_sensors.then_some(results.sensors),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
+
     use hashbrown::HashMap;
 
-    use super::finalize_name;
+    use regex::Regex;
+
+    use super::{finalize_name, hwmon_temperatures, sensor_file};
+    use crate::{
+        app::filter::Filter,
+        data_collection::temperature::{SensorHarvest, SensorKind, TempHarvest, TemperatureType},
+    };
+
+    #[test]
+    fn test_sensor_file() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/hwmon/hwmon0");
+
+        assert_eq!(
+            sensor_file(&dir, "in0_input"),
+            Some((SensorKind::Voltage, "in0"))
+        );
+        assert_eq!(
+            sensor_file(&dir, "fan1_input"),
+            Some((SensorKind::Fan, "fan1"))
+        );
+
+        // Limits and labels aren't readings.
+        assert_eq!(sensor_file(&dir, "fan1_min"), None);
+        assert_eq!(sensor_file(&dir, "fan1_label"), None);
+        assert_eq!(sensor_file(&dir, "in_input"), None);
+        assert_eq!(sensor_file(&dir, "temp1_input"), None);
+
+        // The average is only used if there is no instantaneous value.
+        assert_eq!(
+            sensor_file(&dir, "power1_average"),
+            Some((SensorKind::Power, "power1"))
+        );
+        assert_eq!(sensor_file(&dir, "power2_average"), None);
+    }
+
+    #[test]
+    fn test_hwmon_temperatures() {
+        let dirs = || [Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/hwmon/hwmon0")];
+        let ignore = |pattern: &str| {
+            Some(Filter {
+                is_list_ignored: true,
+                list: vec![Regex::new(pattern).unwrap()],
+            })
+        };
+        let sensor = |name: &str, kind: SensorKind, value: f64| (name.to_string(), kind, value);
+
+        let results = hwmon_temperatures(dirs(), &TemperatureType::Celsius, &None, true, &None);
+        let temperatures = results
+            .temperatures
+            .into_iter()
+            .map(|TempHarvest { name, temperature }| (name, temperature))
+            .collect::<Vec<_>>();
+        let mut sensors = results
+            .sensors
+            .into_iter()
+            .map(|SensorHarvest { name, kind, value }| (name, kind, value))
+            .collect::<Vec<_>>();
+        sensors.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(temperatures, vec![("nct6775".to_string(), Some(45.0))]);
+        assert_eq!(
+            sensors,
+            vec![
+                // Labelled sensors use their label, and unlabelled ones their channel.
+                sensor("nct6775: +12V", SensorKind::Voltage, 12.096),
+                sensor("nct6775: CPU Fan", SensorKind::Fan, 1234.0),
+                sensor("nct6775: curr1", SensorKind::Current, 1.5),
+                sensor("nct6775: energy1", SensorKind::Energy, 123.0),
+                sensor("nct6775: fan2", SensorKind::Fan, 0.0),
+                sensor("nct6775: in0", SensorKind::Voltage, 1.104),
+                // The average is only used if there is no instantaneous value.
+                sensor("nct6775: power1", SensorKind::Power, 15.5),
+                sensor("nct6775: power2", SensorKind::Power, 20.25),
+            ]
+        );
+
+        // The temperature filter doesn't apply to other sensors, and the sensor filter doesn't
+        // apply to temperatures.
+        let results = hwmon_temperatures(
+            dirs(),
+            &TemperatureType::Celsius,
+            &ignore("nct6775"),
+            true,
+            &ignore("Fan|fan"),
+        );
+        assert!(results.temperatures.is_empty());
+        assert_eq!(results.sensors.len(), 6);
+        assert!(results
+            .sensors
+            .iter()
+            .all(|sensor| sensor.kind != SensorKind::Fan));
+
+        let results = hwmon_temperatures(
+            dirs(),
+            &TemperatureType::Celsius,
+            &None,
+            true,
+            &ignore("nct6775"),
+        );
+        assert_eq!(results.temperatures.len(), 1);
+        assert!(results.sensors.is_empty());
+
+        // Other sensors are only read if asked for.
+        let results = hwmon_temperatures(dirs(), &TemperatureType::Celsius, &None, false, &None);
+        assert_eq!(results.temperatures.len(), 1);
+        assert!(results.sensors.is_empty());
+    }
 
     #[test]
     fn test_finalize_name() {
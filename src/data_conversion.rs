@@ -3,14 +3,27 @@
 
 // TODO: Split this up!
 
+use std::{collections::BTreeSet, time::Instant};
+
 use kstring::KString;
 
 use crate::{
-    app::{data_farmer::DataCollection, AxisScaling},
+    app::{
+        data_farmer::{DataCollection, TimedData, Value},
+        AxisScaling,
+    },
     canvas::components::time_chart::Point,
-    data_collection::{cpu::CpuDataType, memory::MemHarvest, temperature::TemperatureType},
+    data_collection::{
+        cpu::{CpuBreakdown, CpuDataType, CpuFrequency, CpuTopology},
+        memory::{MemHarvest, PagingRates, ZramHarvest},
+        pressure::PressureHarvest,
+        temperature::TemperatureType,
+    },
     utils::{data_prefixes::*, data_units::DataUnit, general::*},
-    widgets::{DiskWidgetData, TempWidgetData},
+    widgets::{
+        CgroupWidgetData, DiskWidgetData, MemTableWidgetData, NetInterfaceWidgetData,
+        TempWidgetData,
+    },
 };
 
 #[derive(Debug, Default)]
@@ -50,6 +63,7 @@ pub struct ConvertedNetworkData {
 }
 
 #[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)] // There is only ever one `All`, so boxing the entries doesn't save anything.
 pub enum CpuWidgetData {
     All,
     Entry {
@@ -57,6 +71,13 @@ pub enum CpuWidgetData {
         /// A point here represents time (x) and value (y).
         data: Vec<Point>,
         last_entry: f64,
+        /// A breakdown of what the CPU spent its time on over time, if available.
+        breakdown_data: Vec<(f64, CpuBreakdown)>,
+        last_breakdown: Option<CpuBreakdown>,
+        /// The frequency in MHz over time, if available.
+        frequency_data: Vec<Point>,
+        last_frequency: Option<CpuFrequency>,
+        topology: Option<CpuTopology>,
     },
 }
 
@@ -79,30 +100,130 @@ pub struct ConvertedData {
     pub cache_data: Vec<Point>,
     pub swap_data: Vec<Point>,
 
+    /// The latest paging rates, and their history.
+    pub paging: Option<PagingRates>,
+    pub swap_in_data: Vec<Point>,
+    pub swap_out_data: Vec<Point>,
+    pub major_fault_data: Vec<Point>,
+    pub page_scan_data: Vec<Point>,
+    pub page_steal_data: Vec<Point>,
+
+    /// Legend lines for compressed swap, along with how much RAM it uses over time.
+    pub zram_label: Option<String>,
+    pub zram_data: Vec<Point>,
+    pub zswap_label: Option<String>,
+    pub zswap_data: Vec<Point>,
+
     #[cfg(feature = "zfs")]
     pub arc_labels: Option<(String, String)>,
     #[cfg(feature = "zfs")]
     pub arc_data: Vec<Point>,
+    #[cfg(feature = "zfs")]
+    pub arc_hit_label: Option<String>,
+    #[cfg(feature = "zfs")]
+    pub arc_hit_data: Vec<Point>,
 
     #[cfg(feature = "gpu")]
     pub gpu_data: Option<Vec<ConvertedGpuData>>,
 
     pub load_avg_data: [f32; 3],
+    pub pressure: PressureHarvest,
+    pub cpu_pressure_data: Vec<Point>,
+    pub mem_pressure_data: Vec<Point>,
+    pub io_pressure_data: Vec<Point>,
     pub cpu_data: Vec<CpuWidgetData>,
     pub battery_data: Vec<ConvertedBatteryData>,
     pub disk_data: Vec<DiskWidgetData>,
+    /// Lines for the disk I/O graph, along with their legend labels.
+    pub disk_io_data: Vec<(String, Vec<Point>)>,
+    pub net_interface_data: Vec<NetInterfaceWidgetData>,
     pub temp_data: Vec<TempWidgetData>,
+    pub cgroup_data: Vec<CgroupWidgetData>,
+    pub mem_details_data: Vec<MemTableWidgetData>,
 }
 
 impl ConvertedData {
+    pub fn ingest_paging_data(&mut self, data: &DataCollection) {
+        let points = |get_value: fn(&PagingRates) -> Value| {
+            convert_timed_data_points(data, |timed_data| {
+                timed_data.paging_data.as_ref().map(get_value)
+            })
+        };
+
+        self.paging = data.paging_harvest.and_then(|paging| paging.per_sec);
+        self.swap_in_data = points(|paging| paging.swap_in);
+        self.swap_out_data = points(|paging| paging.swap_out);
+        self.major_fault_data = points(|paging| paging.major_faults);
+        self.page_scan_data = points(|paging| paging.scanned);
+        self.page_steal_data = points(|paging| paging.stolen);
+    }
+
+    /// Converts the zram, zswap, and ARC details shown in the memory widget.
+    pub fn ingest_memory_extras_data(&mut self, data: &DataCollection) {
+        let total_ram = data.memory_harvest.total_bytes;
+
+        self.zram_label = if data.zram_harvest.is_empty() {
+            None
+        } else {
+            let sum =
+                |get_bytes: fn(&ZramHarvest) -> u64| data.zram_harvest.iter().map(get_bytes).sum();
+            Some(compressed_memory_label(
+                "ZRM",
+                sum(|zram| zram.used_bytes),
+                total_ram,
+                sum(|zram| zram.compressed_bytes),
+                Some(sum(|zram| zram.original_bytes)),
+            ))
+        };
+        self.zram_data = convert_timed_data_points(data, |timed_data| timed_data.zram_data);
+
+        self.zswap_label = data.zswap_harvest.map(|zswap| {
+            compressed_memory_label(
+                "ZSW",
+                zswap.pool_bytes,
+                total_ram,
+                zswap.pool_bytes,
+                zswap.stored_bytes,
+            )
+        });
+        self.zswap_data = convert_timed_data_points(data, |timed_data| timed_data.zswap_data);
+
+        #[cfg(feature = "zfs")]
+        {
+            self.arc_hit_label = data.arc_details_harvest.map(|arc| {
+                let hit_percent = match arc.hit_percent {
+                    Some(hit_percent) => format!("{hit_percent:3.0}%"),
+                    None => " N/A".to_string(),
+                };
+
+                format!(
+                    "HIT:{hit_percent}   MRU {} MFU {}",
+                    binary_byte_string(arc.mru_bytes),
+                    binary_byte_string(arc.mfu_bytes)
+                )
+            });
+            self.arc_hit_data =
+                convert_timed_data_points(data, |timed_data| timed_data.arc_hit_data);
+        }
+    }
+
     // TODO: Can probably heavily reduce this step to avoid clones.
     pub fn ingest_disk_data(&mut self, data: &DataCollection) {
+        let join_or_na = |values: &[String]| -> KString {
+            if values.is_empty() {
+                "N/A".into()
+            } else {
+                values.join(",").into()
+            }
+        };
+
         self.disk_data.clear();
 
         data.disk_harvest
             .iter()
             .zip(&data.io_labels)
-            .for_each(|(disk, (io_read, io_write))| {
+            .enumerate()
+            .for_each(|(itx, (disk, (io_read, io_write)))| {
                 // Because this sometimes does *not* equal to disk.total.
                 let summed_total_bytes = match (disk.used_space, disk.free_space) {
                     (Some(used), Some(free)) => Some(used + free),
@@ -118,13 +239,138 @@ impl ConvertedData {
                     summed_total_bytes,
                     io_read: io_read.into(),
                     io_write: io_write.into(),
+                    io_bytes_per_sec: data.io_labels_and_prev.get(itx).map(|(curr, _)| *curr),
+                    io_rates: data.io_rates.get(itx).copied().flatten(),
+                    free_inodes: disk.free_inodes,
+                    used_inodes: disk.used_inodes,
+                    total_inodes: disk.total_inodes,
+                    fs_type: KString::from_ref(disk.fs_type.as_deref().unwrap_or("N/A")),
+                    mount_options: join_or_na(&disk.mount_options),
+                    device: join_or_na(&disk.backing_devices),
                 });
             });
 
         self.disk_data.shrink_to_fit();
     }
 
-    pub fn ingest_temp_data(&mut self, data: &DataCollection, temperature_type: TemperatureType) {
+    pub fn ingest_net_interface_data(
+        &mut self, data: &DataCollection, unit_type: &DataUnit, use_binary_prefix: bool,
+    ) {
+        self.net_interface_data.clear();
+
+        let unit = match unit_type {
+            DataUnit::Byte => "B/s",
+            DataUnit::Bit => "b/s",
+        };
+        let format_rate = |bits: u64| -> KString {
+            let rate = match unit_type {
+                DataUnit::Byte => bits / 8,
+                DataUnit::Bit => bits,
+            };
+            let (value, prefix) = if use_binary_prefix {
+                get_binary_prefix(rate, unit)
+            } else {
+                get_decimal_prefix(rate, unit)
+            };
+            format!("{value:.1}{prefix}").into()
+        };
+        let format_total = |bits: u64| -> KString {
+            // Like the network legend, totals are always in bytes.
+            let (value, prefix) = if use_binary_prefix {
+                get_binary_bytes(bits / 8)
+            } else {
+                get_decimal_bytes(bits / 8)
+            };
+            format!("{value:.1}{prefix}").into()
+        };
+
+        data.network_harvest
+            .interfaces
+            .iter()
+            .for_each(|interface| {
+                self.net_interface_data.push(NetInterfaceWidgetData {
+                    name: KString::from_ref(&interface.name),
+                    rx: interface.rx,
+                    tx: interface.tx,
+                    total_rx: interface.total_rx,
+                    total_tx: interface.total_tx,
+                    rx_packets: interface.rx_packets,
+                    tx_packets: interface.tx_packets,
+                    rx_errors: interface.total_rx_errors,
+                    tx_errors: interface.total_tx_errors,
+                    rx_dropped: interface.total_rx_dropped,
+                    tx_dropped: interface.total_tx_dropped,
+                    rx_display: format_rate(interface.rx),
+                    tx_display: format_rate(interface.tx),
+                    total_rx_display: format_total(interface.total_rx),
+                    total_tx_display: format_total(interface.total_tx),
+                });
+            });
+
+        self.net_interface_data.shrink_to_fit();
+    }
+
+    pub fn ingest_cgroup_data(&mut self, data: &DataCollection) {
+        self.cgroup_data.clear();
+
+        data.cgroup_harvest.iter().for_each(|cgroup| {
+            self.cgroup_data.push(CgroupWidgetData {
+                path: KString::from_ref(&cgroup.path),
+                cpu_usage_percent: cgroup.cpu_usage_percent,
+                memory_bytes: cgroup.memory_bytes,
+                memory_max_bytes: cgroup.memory_max_bytes,
+                read_bytes_per_sec: cgroup.read_bytes_per_sec,
+                write_bytes_per_sec: cgroup.write_bytes_per_sec,
+                pids: cgroup.pids,
+            });
+        });
+
+        self.cgroup_data.shrink_to_fit();
+    }
+
+    pub fn ingest_mem_details_data(&mut self, data: &DataCollection) {
+        self.mem_details_data.clear();
+
+        if let Some(details) = &data.mem_details_harvest {
+            let rows = [
+                ("Total", details.total),
+                ("Available", details.available),
+                ("Free", details.free),
+                ("Buffers", details.buffers),
+                ("Page cache", details.cached),
+                ("Shared/tmpfs", details.shared),
+                ("Slab reclaimable", details.slab_reclaimable),
+                ("Slab unreclaimable", details.slab_unreclaimable),
+                ("Dirty", details.dirty),
+                ("Writeback", details.writeback),
+                ("Anon huge pages", details.anon_huge_pages),
+                ("Hugetlb pool", details.hugetlb_total),
+                ("Hugetlb free", details.hugetlb_free),
+                ("Kernel stack", details.kernel_stack),
+                ("Page tables", details.page_tables),
+                ("Zswap pool", details.zswap),
+                ("Zswapped", details.zswapped),
+                ("Commit limit", details.commit_limit),
+                ("Committed", details.committed),
+            ];
+
+            // Skip anything the kernel doesn't report rather than showing it as empty.
+            self.mem_details_data
+                .extend(rows.into_iter().filter_map(|(name, bytes)| {
+                    Some(MemTableWidgetData {
+                        name,
+                        bytes: bytes?,
+                        total_bytes: details.total,
+                    })
+                }));
+        }
+    }
+
+    /// Gets the rows of the temperature widget. If `include_sensors` is set, this also includes
+    /// the fan, voltage, power, current, and energy sensors.
+    pub fn ingest_temp_data(
+        &mut self, data: &DataCollection, temperature_type: TemperatureType, include_sensors: bool,
+    ) {
         self.temp_data.clear();
 
         data.temp_harvest.iter().for_each(|temp_harvest| {
@@ -132,9 +378,20 @@ impl ConvertedData {
                 sensor: KString::from_ref(&temp_harvest.name),
                 temperature_value: temp_harvest.temperature.map(|temp| temp.ceil() as u64),
                 temperature_type,
+                reading: None,
             });
         });
 
+        if include_sensors {
+            self.temp_data
+                .extend(data.sensor_harvest.iter().map(|sensor| TempWidgetData {
+                    sensor: KString::from_ref(&sensor.name),
+                    temperature_value: None,
+                    temperature_type,
+                    reading: Some((sensor.kind, sensor.value)),
+                }));
+        }
+
         self.temp_data.shrink_to_fit();
     }
 
@@ -154,6 +411,11 @@ impl ConvertedData {
                             data_type: data.data_type,
                             data: vec![],
                             last_entry: *cpu_usage,
+                            breakdown_data: vec![],
+                            last_breakdown: data.breakdown,
+                            frequency_data: vec![],
+                            last_frequency: data.frequency.clone(),
+                            topology: data.topology,
                         })
                         .collect::<Vec<CpuWidgetData>>(),
                 );
@@ -162,16 +424,27 @@ impl ConvertedData {
                     .iter_mut()
                     .skip(1)
                     .zip(&data.cpu_data)
-                    .for_each(|(mut cpu, cpu_usage)| match &mut cpu {
+                    .zip(&current_data.cpu_harvest)
+                    .for_each(|((mut cpu, cpu_usage), harvest)| match &mut cpu {
                         CpuWidgetData::All => unreachable!(),
                         CpuWidgetData::Entry {
                             data_type: _,
                             data,
                             last_entry,
+                            breakdown_data,
+                            last_breakdown,
+                            frequency_data,
+                            last_frequency,
+                            topology,
                         } => {
                             // A bit faster to just update all the times, so we just clear the vector.
                             data.clear();
+                            breakdown_data.clear();
+                            frequency_data.clear();
                             *last_entry = *cpu_usage;
+                            *last_breakdown = harvest.breakdown;
+                            last_frequency.clone_from(&harvest.frequency);
+                            *topology = harvest.topology;
                         }
                     });
             }
@@ -186,6 +459,11 @@ impl ConvertedData {
                     data_type: _,
                     data,
                     last_entry: _,
+                    breakdown_data,
+                    last_breakdown: _,
+                    frequency_data,
+                    last_frequency: _,
+                    topology: _,
                 } => {
                     for (time, timed_data) in &current_data.timed_data_vec {
                         let time_start: f64 =
@@ -195,12 +473,22 @@ impl ConvertedData {
                             data.push((-time_start, *val));
                         }
 
+                        if let Some(Some(breakdown)) = timed_data.cpu_breakdown_data.get(itx) {
+                            breakdown_data.push((-time_start, *breakdown));
+                        }
+
+                        if let Some(Some(frequency)) = timed_data.cpu_frequency_data.get(itx) {
+                            frequency_data.push((-time_start, *frequency));
+                        }
+
                         if *time == current_time {
                             break;
                         }
                     }
 
                     data.shrink_to_fit();
+                    breakdown_data.shrink_to_fit();
+                    frequency_data.shrink_to_fit();
                 }
             }
         }
@@ -262,6 +550,28 @@ pub fn convert_swap_data_points(current_data: &DataCollection) -> Vec<Point> {
     result
 }
 
+/// Returns the points of a time series, where `get_value` picks the value from each entry, like the
+/// pressure of a resource.
+pub fn convert_timed_data_points(
+    current_data: &DataCollection, get_value: impl Fn(&TimedData) -> Option<Value>,
+) -> Vec<Point> {
+    let mut result: Vec<Point> = Vec::new();
+    let current_time = current_data.current_instant;
+
+    for (time, data) in &current_data.timed_data_vec {
+        if let Some(pressure) = get_value(data) {
+            let time_from_start: f64 =
+                (current_time.duration_since(*time).as_millis() as f64).floor();
+            result.push((-time_from_start, pressure));
+            if *time == current_time {
+                break;
+            }
+        }
+    }
+
+    result
+}
+
 /// Returns the most appropriate binary prefix unit type (e.g. kibibyte) and denominator for the given amount of bytes.
 ///
 /// The expected usage is to divide out the given value with the returned denominator in order to be able to use it
@@ -301,8 +611,38 @@ pub fn convert_mem_label(harvest: &MemHarvest) -> Option<(String, String)> {
     }
 }
 
+/// Returns a legend line for compressed swap, like `ZRM:  2%   370MiB→120MiB (3.1x)`. The
+/// percentage is how much of RAM is used.
+fn compressed_memory_label(
+    name: &str, used_bytes: u64, total_ram: u64, compressed_bytes: u64, original_bytes: Option<u64>,
+) -> String {
+    let percent = if total_ram > 0 {
+        used_bytes as f64 / total_ram as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    match original_bytes {
+        Some(original_bytes) if compressed_bytes > 0 => format!(
+            "{name}:{percent:3.0}%   {}→{} ({:.1}x)",
+            binary_byte_string(original_bytes),
+            binary_byte_string(compressed_bytes),
+            original_bytes as f64 / compressed_bytes as f64
+        ),
+        Some(original_bytes) => format!(
+            "{name}:{percent:3.0}%   {}→{}",
+            binary_byte_string(original_bytes),
+            binary_byte_string(compressed_bytes),
+        ),
+        None => format!("{name}:{percent:3.0}%   {}", binary_byte_string(used_bytes)),
+    }
+}
+
+/// Returns the RX and TX points to graph. If `interface` is set, then only that interface's
+/// data is used, otherwise the total of all (filtered) interfaces is used.
 pub fn get_rx_tx_data_points(
-    data: &DataCollection, scale_type: &AxisScaling, unit_type: &DataUnit, use_binary_prefix: bool,
+    data: &DataCollection, interface: Option<&str>, scale_type: &AxisScaling, unit_type: &DataUnit,
+    use_binary_prefix: bool,
 ) -> (Vec<Point>, Vec<Point>) {
     let mut rx: Vec<Point> = Vec::new();
     let mut tx: Vec<Point> = Vec::new();
@@ -312,28 +652,31 @@ pub fn get_rx_tx_data_points(
     for (time, data) in &data.timed_data_vec {
         let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
 
+        let (curr_rx, curr_tx) = match interface {
+            Some(name) => data.interface_data.get(name).copied().unwrap_or_default(),
+            None => (data.rx_data, data.tx_data),
+        };
+
         let (rx_data, tx_data) = match scale_type {
             AxisScaling::Log => {
                 if use_binary_prefix {
                     match unit_type {
                         DataUnit::Byte => {
                             // As dividing by 8 is equal to subtracting 4 in base 2!
-                            ((data.rx_data).log2() - 4.0, (data.tx_data).log2() - 4.0)
+                            (curr_rx.log2() - 4.0, curr_tx.log2() - 4.0)
                         }
-                        DataUnit::Bit => ((data.rx_data).log2(), (data.tx_data).log2()),
+                        DataUnit::Bit => (curr_rx.log2(), curr_tx.log2()),
                     }
                 } else {
                     match unit_type {
-                        DataUnit::Byte => {
-                            ((data.rx_data / 8.0).log10(), (data.tx_data / 8.0).log10())
-                        }
-                        DataUnit::Bit => ((data.rx_data).log10(), (data.tx_data).log10()),
+                        DataUnit::Byte => ((curr_rx / 8.0).log10(), (curr_tx / 8.0).log10()),
+                        DataUnit::Bit => (curr_rx.log10(), curr_tx.log10()),
                     }
                 }
             }
             AxisScaling::Linear => match unit_type {
-                DataUnit::Byte => (data.rx_data / 8.0, data.tx_data / 8.0),
-                DataUnit::Bit => (data.rx_data, data.tx_data),
+                DataUnit::Byte => (curr_rx / 8.0, curr_tx / 8.0),
+                DataUnit::Bit => (curr_rx, curr_tx),
             },
         };
 
@@ -348,10 +691,26 @@ pub fn get_rx_tx_data_points(
 }
 
 pub fn convert_network_data_points(
-    data: &DataCollection, need_four_points: bool, scale_type: &AxisScaling, unit_type: &DataUnit,
-    use_binary_prefix: bool,
+    data: &DataCollection, interface: Option<&str>, need_four_points: bool,
+    scale_type: &AxisScaling, unit_type: &DataUnit, use_binary_prefix: bool,
 ) -> ConvertedNetworkData {
-    let (rx, tx) = get_rx_tx_data_points(data, scale_type, unit_type, use_binary_prefix);
+    let (rx, tx) = get_rx_tx_data_points(data, interface, scale_type, unit_type, use_binary_prefix);
+
+    let (curr_rx, curr_tx, curr_total_rx, curr_total_tx) = match interface {
+        Some(name) => data
+            .network_harvest
+            .interfaces
+            .iter()
+            .find(|harvest| harvest.name == name)
+            .map(|harvest| (harvest.rx, harvest.tx, harvest.total_rx, harvest.total_tx))
+            .unwrap_or_default(),
+        None => (
+            data.network_harvest.rx,
+            data.network_harvest.tx,
+            data.network_harvest.total_rx,
+            data.network_harvest.total_tx,
+        ),
+    };
 
     let unit = match unit_type {
         DataUnit::Byte => "B/s",
@@ -360,16 +719,16 @@ pub fn convert_network_data_points(
 
     let (rx_data, tx_data, total_rx_data, total_tx_data) = match unit_type {
         DataUnit::Byte => (
-            data.network_harvest.rx / 8,
-            data.network_harvest.tx / 8,
-            data.network_harvest.total_rx / 8,
-            data.network_harvest.total_tx / 8,
+            curr_rx / 8,
+            curr_tx / 8,
+            curr_total_rx / 8,
+            curr_total_tx / 8,
         ),
         DataUnit::Bit => (
-            data.network_harvest.rx,
-            data.network_harvest.tx,
-            data.network_harvest.total_rx / 8, // We always make this bytes...
-            data.network_harvest.total_tx / 8,
+            curr_rx,
+            curr_tx,
+            curr_total_rx / 8, // We always make this bytes...
+            curr_total_tx / 8,
         ),
     };
 
@@ -469,6 +828,81 @@ pub fn convert_network_data_points(
     }
 }
 
+/// Returns the lines for the disk I/O graph, each with a legend label. If `disk` is set, these are
+/// that disk's read and write rates. Otherwise, each disk's total rate is stacked on top of the
+/// disks before it, so the topmost line is the total across all disks.
+pub fn convert_disk_io_data_points(
+    data: &DataCollection, disk: Option<&str>,
+) -> Vec<(String, Vec<Point>)> {
+    let current_time = data.current_instant;
+    let time_from_start =
+        |time: &Instant| -(current_time.duration_since(*time).as_millis() as f64).floor();
+    let latest = data
+        .timed_data_vec
+        .iter()
+        .rev()
+        .find(|(_, entry)| !entry.disk_io_data.is_empty())
+        .map(|(_, entry)| &entry.disk_io_data);
+    let rate_string = |rate: Value| dec_bytes_per_second_string(rate as u64);
+
+    if let Some(disk) = disk {
+        let mut read: Vec<Point> = Vec::new();
+        let mut write: Vec<Point> = Vec::new();
+
+        for (time, entry) in &data.timed_data_vec {
+            if let Some((read_rate, write_rate)) = entry.disk_io_data.get(disk) {
+                read.push((time_from_start(time), *read_rate));
+                write.push((time_from_start(time), *write_rate));
+            }
+        }
+
+        let (curr_read, curr_write) = latest
+            .and_then(|latest| latest.get(disk).copied())
+            .unwrap_or_default();
+
+        vec![
+            (format!("Read: {}", rate_string(curr_read)), read),
+            (format!("Write: {}", rate_string(curr_write)), write),
+        ]
+    } else {
+        let disks: BTreeSet<&str> = data
+            .timed_data_vec
+            .iter()
+            .flat_map(|(_, entry)| entry.disk_io_data.keys().map(|disk| &**disk))
+            .collect();
+        let mut lines: Vec<Vec<Point>> = vec![Vec::new(); disks.len()];
+
+        for (time, entry) in &data.timed_data_vec {
+            if entry.disk_io_data.is_empty() {
+                continue;
+            }
+
+            let mut total = 0.0;
+            for (line, disk) in lines.iter_mut().zip(&disks) {
+                if let Some((read_rate, write_rate)) = entry.disk_io_data.get(*disk) {
+                    total += read_rate + write_rate;
+                }
+                line.push((time_from_start(time), total));
+            }
+        }
+
+        disks
+            .into_iter()
+            .zip(lines)
+            .map(|(disk, line)| {
+                let (curr_read, curr_write) = latest
+                    .and_then(|latest| latest.get(disk).copied())
+                    .unwrap_or_default();
+
+                (
+                    format!("{disk}: {}", rate_string(curr_read + curr_write)),
+                    line,
+                )
+            })
+            .collect()
+    }
+}
+
 /// Returns a string given a value that is converted to the closest binary variant.
 /// If the value is greater than a gibibyte, then it will return a decimal place.
 pub fn binary_byte_string(value: u64) -> String {
@@ -513,6 +947,15 @@ pub fn dec_bytes_string(value: u64) -> String {
     }
 }
 
+/// Returns a string for a frequency given in MHz, in GHz if it is at least 1 GHz.
+pub fn frequency_string(mhz: u64) -> String {
+    if mhz >= 1000 {
+        format!("{:.2}GHz", mhz as f64 / 1000.0)
+    } else {
+        format!("{mhz}MHz")
+    }
+}
+
 #[cfg(feature = "battery")]
 pub fn convert_battery_harvest(current_data: &DataCollection) -> Vec<ConvertedBatteryData> {
     current_data
@@ -637,6 +1080,105 @@ pub fn convert_gpu_data(current_data: &DataCollection) -> Option<Vec<ConvertedGp
 mod test {
     use super::*;
 
+    #[test]
+    fn test_rx_tx_data_points_for_interface() {
+        use std::time::Instant;
+
+        use crate::app::data_farmer::TimedData;
+
+        let now = Instant::now();
+        let mut entry = TimedData {
+            rx_data: 300.0,
+            tx_data: 600.0,
+            ..Default::default()
+        };
+        entry.interface_data.insert("eth0".into(), (100.0, 200.0));
+        entry.interface_data.insert("eth1".into(), (200.0, 400.0));
+
+        let data = DataCollection {
+            current_instant: now,
+            timed_data_vec: vec![(now, entry)],
+            ..Default::default()
+        };
+
+        let get_points = |interface| {
+            get_rx_tx_data_points(
+                &data,
+                interface,
+                &AxisScaling::Linear,
+                &DataUnit::Bit,
+                false,
+            )
+        };
+
+        assert_eq!(get_points(None), (vec![(0.0, 300.0)], vec![(0.0, 600.0)]));
+        assert_eq!(
+            get_points(Some("eth1")),
+            (vec![(0.0, 200.0)], vec![(0.0, 400.0)])
+        );
+        assert_eq!(
+            get_points(Some("missing")),
+            (vec![(0.0, 0.0)], vec![(0.0, 0.0)])
+        );
+    }
+
+    #[test]
+    fn test_disk_io_data_points() {
+        use std::time::{Duration, Instant};
+
+        use crate::app::data_farmer::TimedData;
+
+        let now = Instant::now();
+        let mut earlier = TimedData::default();
+        earlier
+            .disk_io_data
+            .insert("/dev/sda".into(), (1000.0, 2000.0));
+        earlier
+            .disk_io_data
+            .insert("/dev/nvme0n1".into(), (0.0, 500.0));
+        let mut latest = TimedData::default();
+        latest
+            .disk_io_data
+            .insert("/dev/sda".into(), (3000.0, 4000.0));
+
+        let data = DataCollection {
+            current_instant: now,
+            timed_data_vec: vec![
+                (now - Duration::from_secs(1), earlier),
+                (now, TimedData::default()),
+                (now, latest),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            convert_disk_io_data_points(&data, Some("/dev/sda")),
+            vec![
+                (
+                    "Read: 3KB/s".to_string(),
+                    vec![(-1000.0, 1000.0), (0.0, 3000.0)]
+                ),
+                (
+                    "Write: 4KB/s".to_string(),
+                    vec![(-1000.0, 2000.0), (0.0, 4000.0)]
+                ),
+            ]
+        );
+        assert_eq!(
+            convert_disk_io_data_points(&data, None),
+            vec![
+                (
+                    "/dev/nvme0n1: 0B/s".to_string(),
+                    vec![(-1000.0, 500.0), (0.0, 0.0)]
+                ),
+                (
+                    "/dev/sda: 7KB/s".to_string(),
+                    vec![(-1000.0, 3500.0), (0.0, 7000.0)]
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_binary_byte_string() {
         assert_eq!(binary_byte_string(0), "0B".to_string());
@@ -662,6 +1204,13 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_frequency_string() {
+        assert_eq!(frequency_string(800), "800MHz".to_string());
+        assert_eq!(frequency_string(1000), "1.00GHz".to_string());
+        assert_eq!(frequency_string(3456), "3.46GHz".to_string());
+    }
+
     #[test]
     fn test_dec_bytes_per_second_string() {
         assert_eq!(dec_bytes_per_second_string(0), "0B/s".to_string());
@@ -696,4 +1245,25 @@ mod test {
             "10.4TB/s".to_string()
         );
     }
+    #[test]
+    fn test_compressed_memory_label() {
+        assert_eq!(
+            compressed_memory_label(
+                "ZRM",
+                100 * MEBI_LIMIT,
+                1000 * MEBI_LIMIT,
+                90 * MEBI_LIMIT,
+                Some(360 * MEBI_LIMIT)
+            ),
+            "ZRM: 10%   360MiB→90MiB (4.0x)"
+        );
+        assert_eq!(
+            compressed_memory_label("ZSW", 0, 1000 * MEBI_LIMIT, 0, Some(0)),
+            "ZSW:  0%   0B→0B"
+        );
+        assert_eq!(
+            compressed_memory_label("ZSW", 5 * MEBI_LIMIT, 0, 5 * MEBI_LIMIT, None),
+            "ZSW:  0%   5MiB"
+        );
+    }
 }
@@ -0,0 +1,254 @@
+//! Serves collected data as OpenMetrics text, so it can be scraped by something like Prometheus.
+
+pub mod openmetrics;
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+#[cfg(target_family = "unix")]
+use std::{os::unix::fs::FileTypeExt, os::unix::net::UnixListener, path::PathBuf};
+
+use crate::data_collection::{temperature::TemperatureType, Data};
+
+/// How long to wait on a connection before giving up on it.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Where the exporter listens for requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExporterAddress {
+    Tcp(SocketAddr),
+    #[cfg(target_family = "unix")]
+    Unix(PathBuf),
+}
+
+impl FromStr for ExporterAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("unix:") {
+            #[cfg(target_family = "unix")]
+            {
+                return Ok(ExporterAddress::Unix(PathBuf::from(path)));
+            }
+
+            #[cfg(not(target_family = "unix"))]
+            {
+                let _ = path;
+                return Err("Unix sockets are not supported on this platform.".to_string());
+            }
+        }
+
+        s.parse().map(ExporterAddress::Tcp).map_err(|_| {
+            format!("'{s}' is not a valid address, use something like '127.0.0.1:9184' or 'unix:/path/to/socket'.")
+        })
+    }
+}
+
+impl Display for ExporterAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExporterAddress::Tcp(address) => write!(f, "{address}"),
+            #[cfg(target_family = "unix")]
+            ExporterAddress::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Serves the most recently collected data from a background thread.
+pub struct Exporter {
+    metrics: Arc<Mutex<String>>,
+    temperature_type: TemperatureType,
+}
+
+impl Exporter {
+    /// Binds to the given address and starts serving requests. Until the first update, no
+    /// metrics are returned.
+    pub fn start(address: &ExporterAddress, temperature_type: TemperatureType) -> io::Result<Self> {
+        let metrics = Arc::new(Mutex::new(openmetrics::render(
+            &Data::default(),
+            temperature_type,
+        )));
+
+        match address {
+            ExporterAddress::Tcp(address) => {
+                let listener = TcpListener::bind(address)?;
+                let metrics = metrics.clone();
+
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        let _ = stream.set_read_timeout(Some(CONNECTION_TIMEOUT));
+                        let _ = stream.set_write_timeout(Some(CONNECTION_TIMEOUT));
+                        let _ = handle_connection(stream, &metrics);
+                    }
+                });
+            }
+            #[cfg(target_family = "unix")]
+            ExporterAddress::Unix(path) => {
+                // Clean up a socket left behind by a previous run, but never remove anything else.
+                if let Ok(metadata) = std::fs::symlink_metadata(path) {
+                    if metadata.file_type().is_socket() {
+                        std::fs::remove_file(path)?;
+                    }
+                }
+
+                let listener = UnixListener::bind(path)?;
+                let metrics = metrics.clone();
+
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        let _ = stream.set_read_timeout(Some(CONNECTION_TIMEOUT));
+                        let _ = stream.set_write_timeout(Some(CONNECTION_TIMEOUT));
+                        let _ = handle_connection(stream, &metrics);
+                    }
+                });
+            }
+        }
+
+        Ok(Self {
+            metrics,
+            temperature_type,
+        })
+    }
+
+    /// Replaces the served metrics with ones built from the given data.
+    pub fn update(&self, data: &Data) {
+        let rendered = openmetrics::render(data, self.temperature_type);
+
+        if let Ok(mut metrics) = self.metrics.lock() {
+            *metrics = rendered;
+        }
+    }
+}
+
+/// Responds to a single HTTP request. Only `GET /metrics` is supported.
+fn handle_connection(mut stream: impl Read + Write, metrics: &Mutex<String>) -> io::Result<()> {
+    let mut request_line = String::new();
+    {
+        let mut reader = BufReader::new(&mut stream);
+        reader.read_line(&mut request_line)?;
+
+        // We don't care about any headers, but they still need to be read.
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+            header.clear();
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next();
+    let path = parts
+        .next()
+        .map(|path| path.split('?').next().unwrap_or(path));
+
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => (
+            "200 OK",
+            CONTENT_TYPE,
+            metrics
+                .lock()
+                .map(|metrics| metrics.clone())
+                .unwrap_or_default(),
+        ),
+        (Some("GET"), _) => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "Metrics are served at /metrics.\n".to_string(),
+        ),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Only GET requests are supported.\n".to_string(),
+        ),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// A fake connection, which reads from a fixed request and records what is written.
+    struct Connection {
+        request: Cursor<Vec<u8>>,
+        response: Vec<u8>,
+    }
+
+    impl Read for Connection {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.request.read(buf)
+        }
+    }
+
+    impl Write for Connection {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.response.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn respond(request: &str) -> String {
+        let mut connection = Connection {
+            request: Cursor::new(request.as_bytes().to_vec()),
+            response: Vec::new(),
+        };
+        let metrics = Mutex::new("# EOF\n".to_string());
+
+        handle_connection(&mut connection, &metrics).unwrap();
+        String::from_utf8(connection.response).unwrap()
+    }
+
+    #[test]
+    fn serves_metrics() {
+        let response = respond("GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains(&format!("Content-Type: {CONTENT_TYPE}\r\n")));
+        assert!(response.contains("Content-Length: 6\r\n"));
+        assert!(response.ends_with("\r\n\r\n# EOF\n"));
+    }
+
+    #[test]
+    fn rejects_other_requests() {
+        assert!(respond("GET / HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(respond("POST /metrics HTTP/1.1\r\n\r\n")
+            .starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[test]
+    fn parses_addresses() {
+        assert_eq!(
+            "127.0.0.1:9184".parse::<ExporterAddress>(),
+            Ok(ExporterAddress::Tcp("127.0.0.1:9184".parse().unwrap()))
+        );
+        assert_eq!(
+            "[::1]:9184".parse::<ExporterAddress>(),
+            Ok(ExporterAddress::Tcp("[::1]:9184".parse().unwrap()))
+        );
+        assert!("localhost".parse::<ExporterAddress>().is_err());
+
+        #[cfg(target_family = "unix")]
+        assert_eq!(
+            "unix:/tmp/btm.sock".parse::<ExporterAddress>(),
+            Ok(ExporterAddress::Unix(PathBuf::from("/tmp/btm.sock")))
+        );
+    }
+}
@@ -0,0 +1,809 @@
+//! Renders collected data in the OpenMetrics text format.
+
+use std::fmt::Write;
+
+use crate::data_collection::{
+    cpu::CpuDataType,
+    disks::{DiskHarvest, IoDetails},
+    memory::MemHarvest,
+    network::NetworkInterfaceHarvest,
+    temperature::{SensorKind, TemperatureType},
+    Data,
+};
+
+type Labels<'a> = Vec<(&'static str, &'a str)>;
+
+/// Writes metric families, skipping any that have no samples.
+#[derive(Default)]
+struct MetricsWriter {
+    out: String,
+}
+
+impl MetricsWriter {
+    fn gauge<'a>(
+        &mut self, name: &str, help: &str, samples: impl IntoIterator<Item = (Labels<'a>, f64)>,
+    ) {
+        self.family(name, "gauge", help, "", samples);
+    }
+
+    fn counter<'a>(
+        &mut self, name: &str, help: &str, samples: impl IntoIterator<Item = (Labels<'a>, f64)>,
+    ) {
+        self.family(name, "counter", help, "_total", samples);
+    }
+
+    #[cfg(feature = "battery")]
+    fn stateset<'a>(
+        &mut self, name: &str, help: &str, samples: impl IntoIterator<Item = (Labels<'a>, f64)>,
+    ) {
+        self.family(name, "stateset", help, "", samples);
+    }
+
+    fn family<'a>(
+        &mut self, name: &str, kind: &str, help: &str, suffix: &str,
+        samples: impl IntoIterator<Item = (Labels<'a>, f64)>,
+    ) {
+        let mut samples = samples.into_iter().peekable();
+        if samples.peek().is_none() {
+            return;
+        }
+
+        // Writing to a string can't fail.
+        let _ = writeln!(self.out, "# TYPE {name} {kind}");
+        let _ = writeln!(self.out, "# HELP {name} {help}");
+
+        for (labels, value) in samples {
+            let _ = write!(self.out, "{name}{suffix}");
+
+            if !labels.is_empty() {
+                let labels = labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let _ = write!(self.out, "{{{labels}}}");
+            }
+
+            let _ = writeln!(self.out, " {}", format_value(value));
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.out.push_str("# EOF\n");
+        self.out
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Widens an `f32` without picking up noise from its imprecision, e.g. `0.21` rather than
+/// `0.20999999344348907`.
+fn widen(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::from(value))
+}
+
+/// Labels samples by sensor name. Sensors can share a name (e.g. several chips with a `temp1`
+/// that have no label), so those are also given an `index` label to keep each label set unique.
+fn sensor_samples<'a>(samples: &[(&'a str, f64)], indices: &'a [String]) -> Vec<(Labels<'a>, f64)> {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(position, (name, value))| {
+            let mut labels = vec![("sensor", *name)];
+
+            if samples.iter().filter(|(other, _)| other == name).count() > 1 {
+                let index = samples[..position]
+                    .iter()
+                    .filter(|(other, _)| other == name)
+                    .count();
+                labels.push(("index", indices[index].as_str()));
+            }
+
+            (labels, *value)
+        })
+        .collect()
+}
+
+/// Returns all of the memory harvests in the data, along with the labels that describe them.
+fn memory_harvests(data: &Data) -> Vec<(Labels<'_>, &MemHarvest)> {
+    let mut harvests = Vec::new();
+
+    if let Some(memory) = &data.memory {
+        harvests.push((vec![("type", "ram")], memory));
+    }
+
+    if let Some(swap) = &data.swap {
+        harvests.push((vec![("type", "swap")], swap));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    if let Some(cache) = &data.cache {
+        harvests.push((vec![("type", "cache")], cache));
+    }
+
+    #[cfg(feature = "zfs")]
+    if let Some(arc) = &data.arc {
+        harvests.push((vec![("type", "arc")], arc));
+    }
+
+    #[cfg(feature = "gpu")]
+    if let Some(gpus) = &data.gpu {
+        for (name, gpu) in gpus {
+            harvests.push((vec![("type", "gpu"), ("gpu", name.as_str())], gpu));
+        }
+    }
+
+    harvests
+}
+
+/// Renders the given data as OpenMetrics text. Temperatures are reported in whatever unit they
+/// were collected in.
+pub fn render(data: &Data, temperature_type: TemperatureType) -> String {
+    let mut writer = MetricsWriter::default();
+
+    if let Some(cpu) = &data.cpu {
+        let names = cpu
+            .iter()
+            .map(|cpu| match cpu.data_type {
+                CpuDataType::Avg => "avg".to_string(),
+                CpuDataType::Cpu(index) => index.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        writer.gauge(
+            "bottom_cpu_usage_ratio",
+            "CPU usage, either per core or averaged across all cores.",
+            cpu.iter()
+                .zip(&names)
+                .map(|(cpu, name)| (vec![("cpu", name.as_str())], cpu.cpu_usage / 100.0)),
+        );
+    }
+
+    if let Some(load_avg) = &data.load_avg {
+        writer.gauge(
+            "bottom_load_average",
+            "System load average.",
+            ["1m", "5m", "15m"]
+                .into_iter()
+                .zip(load_avg)
+                .map(|(period, load)| (vec![("period", period)], widen(*load))),
+        );
+    }
+
+    let memory = memory_harvests(data);
+    writer.gauge(
+        "bottom_memory_used_bytes",
+        "Memory in use.",
+        memory
+            .iter()
+            .map(|(labels, harvest)| (labels.clone(), harvest.used_bytes as f64)),
+    );
+    writer.gauge(
+        "bottom_memory_total_bytes",
+        "Total memory available.",
+        memory
+            .iter()
+            .map(|(labels, harvest)| (labels.clone(), harvest.total_bytes as f64)),
+    );
+
+    if let Some(paging) = &data.paging {
+        let total = &paging.total;
+
+        writer.counter(
+            "bottom_swap_pages",
+            "Pages swapped in from or out to swap.",
+            [
+                (vec![("direction", "in")], total.swap_in as f64),
+                (vec![("direction", "out")], total.swap_out as f64),
+            ],
+        );
+        writer.counter(
+            "bottom_major_page_faults",
+            "Page faults that had to read from disk.",
+            [(vec![], total.major_faults as f64)],
+        );
+        writer.counter(
+            "bottom_reclaim_pages",
+            "Pages scanned and reclaimed while reclaiming memory.",
+            [
+                (vec![("kind", "scanned")], total.scanned as f64),
+                (vec![("kind", "stolen")], total.stolen as f64),
+            ],
+        );
+    }
+
+    if let Some(network) = &data.network {
+        let interfaces = &network.interfaces;
+        let samples = |value: fn(&NetworkInterfaceHarvest) -> u64| {
+            interfaces.iter().map(move |interface| {
+                (
+                    vec![("interface", interface.name.as_str())],
+                    value(interface) as f64,
+                )
+            })
+        };
+
+        writer.counter(
+            "bottom_network_receive_bytes",
+            "Bytes received by a network interface.",
+            samples(|interface| interface.total_rx / 8),
+        );
+        writer.counter(
+            "bottom_network_transmit_bytes",
+            "Bytes transmitted by a network interface.",
+            samples(|interface| interface.total_tx / 8),
+        );
+        writer.counter(
+            "bottom_network_receive_packets",
+            "Packets received by a network interface.",
+            samples(|interface| interface.total_rx_packets),
+        );
+        writer.counter(
+            "bottom_network_transmit_packets",
+            "Packets transmitted by a network interface.",
+            samples(|interface| interface.total_tx_packets),
+        );
+    }
+
+    if let Some(disks) = &data.disks {
+        let samples = |value: fn(&DiskHarvest) -> Option<u64>| {
+            disks.iter().filter_map(move |disk| {
+                value(disk).map(|value| {
+                    (
+                        vec![
+                            ("disk", disk.name.as_str()),
+                            ("mount", disk.mount_point.as_str()),
+                        ],
+                        value as f64,
+                    )
+                })
+            })
+        };
+
+        writer.gauge(
+            "bottom_disk_used_bytes",
+            "Space used on a disk.",
+            samples(|disk| disk.used_space),
+        );
+        writer.gauge(
+            "bottom_disk_free_bytes",
+            "Space free on a disk.",
+            samples(|disk| disk.free_space),
+        );
+        writer.gauge(
+            "bottom_disk_total_bytes",
+            "Total space on a disk.",
+            samples(|disk| disk.total_space),
+        );
+        writer.gauge(
+            "bottom_disk_used_inodes",
+            "Inodes used on a disk.",
+            samples(|disk| disk.used_inodes),
+        );
+        writer.gauge(
+            "bottom_disk_free_inodes",
+            "Inodes free on a disk.",
+            samples(|disk| disk.free_inodes),
+        );
+        writer.gauge(
+            "bottom_disk_total_inodes",
+            "Total inodes on a disk.",
+            samples(|disk| disk.total_inodes),
+        );
+    }
+
+    if let Some(io) = &data.io {
+        let mut io = io
+            .iter()
+            .filter_map(|(device, io)| io.as_ref().map(|io| (device.as_str(), io)))
+            .collect::<Vec<_>>();
+        io.sort_unstable_by_key(|(device, _)| *device);
+
+        writer.counter(
+            "bottom_disk_read_bytes",
+            "Bytes read from a device.",
+            io.iter()
+                .map(|(device, io)| (vec![("device", *device)], io.read_bytes as f64)),
+        );
+        writer.counter(
+            "bottom_disk_written_bytes",
+            "Bytes written to a device.",
+            io.iter()
+                .map(|(device, io)| (vec![("device", *device)], io.write_bytes as f64)),
+        );
+
+        let details = io
+            .iter()
+            .filter_map(|(device, io)| io.details.as_ref().map(|details| (*device, details)))
+            .collect::<Vec<_>>();
+        let samples = |value: fn(&IoDetails) -> f64| {
+            details
+                .iter()
+                .map(move |(device, details)| (vec![("device", *device)], value(details)))
+        };
+
+        writer.counter(
+            "bottom_disk_reads_completed",
+            "Reads completed by a device.",
+            samples(|details| details.reads as f64),
+        );
+        writer.counter(
+            "bottom_disk_writes_completed",
+            "Writes completed by a device.",
+            samples(|details| details.writes as f64),
+        );
+        writer.counter(
+            "bottom_disk_read_time_seconds",
+            "Time spent on reads by a device.",
+            samples(|details| details.read_time_ms as f64 / 1000.0),
+        );
+        writer.counter(
+            "bottom_disk_write_time_seconds",
+            "Time spent on writes by a device.",
+            samples(|details| details.write_time_ms as f64 / 1000.0),
+        );
+        writer.counter(
+            "bottom_disk_io_time_seconds",
+            "Time a device spent with I/O in flight.",
+            samples(|details| details.busy_time_ms as f64 / 1000.0),
+        );
+        writer.counter(
+            "bottom_disk_io_time_weighted_seconds",
+            "Time spent by each I/O request in flight, summed up.",
+            samples(|details| details.weighted_time_ms as f64 / 1000.0),
+        );
+        writer.gauge(
+            "bottom_disk_io_in_flight",
+            "I/O requests currently in flight on a device.",
+            samples(|details| details.in_flight as f64),
+        );
+    }
+
+    if let Some(temperatures) = &data.temperature_sensors {
+        let (name, unit) = match temperature_type {
+            TemperatureType::Celsius => ("bottom_temperature_celsius", "Celsius"),
+            TemperatureType::Kelvin => ("bottom_temperature_kelvin", "Kelvin"),
+            TemperatureType::Fahrenheit => ("bottom_temperature_fahrenheit", "Fahrenheit"),
+        };
+
+        let temperatures = temperatures
+            .iter()
+            .filter_map(|sensor| {
+                sensor
+                    .temperature
+                    .map(|temp| (sensor.name.as_str(), widen(temp)))
+            })
+            .collect::<Vec<_>>();
+        let indices = (0..temperatures.len())
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>();
+
+        writer.gauge(
+            name,
+            &format!("Temperature of a sensor, in {unit}."),
+            sensor_samples(&temperatures, &indices),
+        );
+    }
+
+    if let Some(sensors) = &data.sensors {
+        let indices = (0..sensors.len())
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>();
+        let samples = |kind: SensorKind| {
+            let sensors = sensors
+                .iter()
+                .filter(|sensor| sensor.kind == kind)
+                .map(|sensor| (sensor.name.as_str(), sensor.value))
+                .collect::<Vec<_>>();
+
+            sensor_samples(&sensors, &indices)
+        };
+
+        writer.gauge(
+            "bottom_fan_speed_rpm",
+            "Speed of a fan, in revolutions per minute.",
+            samples(SensorKind::Fan),
+        );
+        writer.gauge(
+            "bottom_voltage_volts",
+            "Voltage reported by a sensor.",
+            samples(SensorKind::Voltage),
+        );
+        writer.gauge(
+            "bottom_power_watts",
+            "Power reported by a sensor.",
+            samples(SensorKind::Power),
+        );
+        writer.gauge(
+            "bottom_current_amperes",
+            "Current reported by a sensor.",
+            samples(SensorKind::Current),
+        );
+        writer.counter(
+            "bottom_energy_joules",
+            "Energy used as reported by a sensor.",
+            samples(SensorKind::Energy),
+        );
+    }
+
+    #[cfg(feature = "battery")]
+    if let Some(batteries) = &data.list_of_batteries {
+        let ids = (0..batteries.len())
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>();
+        let batteries = batteries.iter().zip(&ids).collect::<Vec<_>>();
+
+        writer.gauge(
+            "bottom_battery_charge_ratio",
+            "How charged a battery is.",
+            batteries.iter().map(|(battery, id)| {
+                (
+                    vec![("battery", id.as_str())],
+                    battery.charge_percent / 100.0,
+                )
+            }),
+        );
+        writer.gauge(
+            "bottom_battery_health_ratio",
+            "A battery's health, as its current capacity relative to its design capacity.",
+            batteries.iter().map(|(battery, id)| {
+                (
+                    vec![("battery", id.as_str())],
+                    battery.health_percent / 100.0,
+                )
+            }),
+        );
+        writer.gauge(
+            "bottom_battery_power_watts",
+            "How quickly a battery is being charged or discharged.",
+            batteries.iter().map(|(battery, id)| {
+                (
+                    vec![("battery", id.as_str())],
+                    battery.power_consumption_rate_watts,
+                )
+            }),
+        );
+        writer.gauge(
+            "bottom_battery_time_to_full_seconds",
+            "Estimated time until a battery is fully charged.",
+            batteries.iter().filter_map(|(battery, id)| {
+                battery
+                    .secs_until_full
+                    .map(|secs| (vec![("battery", id.as_str())], secs as f64))
+            }),
+        );
+        writer.gauge(
+            "bottom_battery_time_to_empty_seconds",
+            "Estimated time until a battery is empty.",
+            batteries.iter().filter_map(|(battery, id)| {
+                battery
+                    .secs_until_empty
+                    .map(|secs| (vec![("battery", id.as_str())], secs as f64))
+            }),
+        );
+
+        const STATES: [&str; 5] = ["unknown", "charging", "discharging", "empty", "full"];
+        writer.stateset(
+            "bottom_battery_state",
+            "The state of a battery.",
+            batteries.iter().flat_map(|(battery, id)| {
+                let current_state = battery.state.to_string();
+                STATES.into_iter().map(move |state| {
+                    (
+                        vec![("battery", id.as_str()), ("bottom_battery_state", state)],
+                        if current_state == state { 1.0 } else { 0.0 },
+                    )
+                })
+            }),
+        );
+    }
+
+    writer.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_collection::{
+        cpu::CpuData,
+        disks::{IoData, IoHarvest},
+        memory::{PagingCounters, PagingHarvest},
+        network::NetworkHarvest,
+        temperature::{SensorHarvest, TempHarvest},
+    };
+
+    #[test]
+    fn empty_data() {
+        assert_eq!(
+            render(&Data::default(), TemperatureType::Celsius),
+            "# EOF\n"
+        );
+    }
+
+    #[test]
+    fn renders_families() {
+        let data = Data {
+            cpu: Some(vec![
+                CpuData {
+                    data_type: CpuDataType::Avg,
+                    cpu_usage: 50.0,
+                    breakdown: None,
+                    frequency: None,
+                    topology: None,
+                },
+                CpuData {
+                    data_type: CpuDataType::Cpu(0),
+                    cpu_usage: 25.0,
+                    breakdown: None,
+                    frequency: None,
+                    topology: None,
+                },
+            ]),
+            memory: Some(MemHarvest {
+                used_bytes: 1024,
+                total_bytes: 4096,
+                use_percent: Some(25.0),
+            }),
+            network: Some(NetworkHarvest {
+                interfaces: vec![NetworkInterfaceHarvest {
+                    name: "eth0".to_string(),
+                    total_rx: 800,
+                    total_tx: 1600,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            disks: Some(vec![DiskHarvest {
+                name: "/dev/sda1".to_string(),
+                mount_point: "/".to_string(),
+                used_space: Some(10),
+                total_space: Some(30),
+                used_inodes: Some(4),
+                ..Default::default()
+            }]),
+            temperature_sensors: Some(vec![
+                TempHarvest {
+                    name: "CPU \"package\"".to_string(),
+                    temperature: Some(40.5),
+                },
+                TempHarvest {
+                    name: "unknown".to_string(),
+                    temperature: None,
+                },
+            ]),
+            sensors: Some(vec![
+                SensorHarvest {
+                    name: "nct6775: fan1".to_string(),
+                    kind: SensorKind::Fan,
+                    value: 1200.0,
+                },
+                SensorHarvest {
+                    name: "nct6775: +12V".to_string(),
+                    kind: SensorKind::Voltage,
+                    value: 12.1,
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let metrics = render(&data, TemperatureType::Fahrenheit);
+        let expected = [
+            "# TYPE bottom_cpu_usage_ratio gauge",
+            "# HELP bottom_cpu_usage_ratio CPU usage, either per core or averaged across all cores.",
+            "bottom_cpu_usage_ratio{cpu=\"avg\"} 0.5",
+            "bottom_cpu_usage_ratio{cpu=\"0\"} 0.25",
+            "# TYPE bottom_memory_used_bytes gauge",
+            "# HELP bottom_memory_used_bytes Memory in use.",
+            "bottom_memory_used_bytes{type=\"ram\"} 1024",
+            "# TYPE bottom_memory_total_bytes gauge",
+            "# HELP bottom_memory_total_bytes Total memory available.",
+            "bottom_memory_total_bytes{type=\"ram\"} 4096",
+            "# TYPE bottom_network_receive_bytes counter",
+            "# HELP bottom_network_receive_bytes Bytes received by a network interface.",
+            "bottom_network_receive_bytes_total{interface=\"eth0\"} 100",
+            "# TYPE bottom_network_transmit_bytes counter",
+            "# HELP bottom_network_transmit_bytes Bytes transmitted by a network interface.",
+            "bottom_network_transmit_bytes_total{interface=\"eth0\"} 200",
+            "# TYPE bottom_network_receive_packets counter",
+            "# HELP bottom_network_receive_packets Packets received by a network interface.",
+            "bottom_network_receive_packets_total{interface=\"eth0\"} 0",
+            "# TYPE bottom_network_transmit_packets counter",
+            "# HELP bottom_network_transmit_packets Packets transmitted by a network interface.",
+            "bottom_network_transmit_packets_total{interface=\"eth0\"} 0",
+            "# TYPE bottom_disk_used_bytes gauge",
+            "# HELP bottom_disk_used_bytes Space used on a disk.",
+            "bottom_disk_used_bytes{disk=\"/dev/sda1\",mount=\"/\"} 10",
+            "# TYPE bottom_disk_total_bytes gauge",
+            "# HELP bottom_disk_total_bytes Total space on a disk.",
+            "bottom_disk_total_bytes{disk=\"/dev/sda1\",mount=\"/\"} 30",
+            "# TYPE bottom_disk_used_inodes gauge",
+            "# HELP bottom_disk_used_inodes Inodes used on a disk.",
+            "bottom_disk_used_inodes{disk=\"/dev/sda1\",mount=\"/\"} 4",
+            "# TYPE bottom_temperature_fahrenheit gauge",
+            "# HELP bottom_temperature_fahrenheit Temperature of a sensor, in Fahrenheit.",
+            "bottom_temperature_fahrenheit{sensor=\"CPU \\\"package\\\"\"} 40.5",
+            "# TYPE bottom_fan_speed_rpm gauge",
+            "# HELP bottom_fan_speed_rpm Speed of a fan, in revolutions per minute.",
+            "bottom_fan_speed_rpm{sensor=\"nct6775: fan1\"} 1200",
+            "# TYPE bottom_voltage_volts gauge",
+            "# HELP bottom_voltage_volts Voltage reported by a sensor.",
+            "bottom_voltage_volts{sensor=\"nct6775: +12V\"} 12.1",
+            "# EOF",
+        ];
+
+        assert_eq!(metrics.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn duplicate_sensor_names() {
+        let data = Data {
+            temperature_sensors: Some(vec![
+                TempHarvest {
+                    name: "k10temp: Tctl".to_string(),
+                    temperature: Some(50.0),
+                },
+                TempHarvest {
+                    name: "k10temp: Tctl".to_string(),
+                    temperature: Some(55.0),
+                },
+                TempHarvest {
+                    name: "nvme: Composite".to_string(),
+                    temperature: Some(40.0),
+                },
+            ]),
+            sensors: Some(vec![
+                SensorHarvest {
+                    name: "nct6775: fan1".to_string(),
+                    kind: SensorKind::Fan,
+                    value: 1200.0,
+                },
+                SensorHarvest {
+                    name: "nct6775: fan1".to_string(),
+                    kind: SensorKind::Voltage,
+                    value: 1.2,
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let metrics = render(&data, TemperatureType::Celsius);
+        let expected = [
+            "# TYPE bottom_temperature_celsius gauge",
+            "# HELP bottom_temperature_celsius Temperature of a sensor, in Celsius.",
+            "bottom_temperature_celsius{sensor=\"k10temp: Tctl\",index=\"0\"} 50",
+            "bottom_temperature_celsius{sensor=\"k10temp: Tctl\",index=\"1\"} 55",
+            "bottom_temperature_celsius{sensor=\"nvme: Composite\"} 40",
+            "# TYPE bottom_fan_speed_rpm gauge",
+            "# HELP bottom_fan_speed_rpm Speed of a fan, in revolutions per minute.",
+            "bottom_fan_speed_rpm{sensor=\"nct6775: fan1\"} 1200",
+            "# TYPE bottom_voltage_volts gauge",
+            "# HELP bottom_voltage_volts Voltage reported by a sensor.",
+            "bottom_voltage_volts{sensor=\"nct6775: fan1\"} 1.2",
+            "# EOF",
+        ];
+
+        assert_eq!(metrics.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn renders_paging() {
+        let data = Data {
+            paging: Some(PagingHarvest {
+                total: PagingCounters {
+                    swap_in: 1,
+                    swap_out: 2,
+                    major_faults: 3,
+                    scanned: 4,
+                    stolen: 5,
+                },
+                per_sec: None,
+            }),
+            ..Default::default()
+        };
+
+        let metrics = render(&data, TemperatureType::Celsius);
+        let expected = [
+            "# TYPE bottom_swap_pages counter",
+            "# HELP bottom_swap_pages Pages swapped in from or out to swap.",
+            "bottom_swap_pages_total{direction=\"in\"} 1",
+            "bottom_swap_pages_total{direction=\"out\"} 2",
+            "# TYPE bottom_major_page_faults counter",
+            "# HELP bottom_major_page_faults Page faults that had to read from disk.",
+            "bottom_major_page_faults_total 3",
+            "# TYPE bottom_reclaim_pages counter",
+            "# HELP bottom_reclaim_pages Pages scanned and reclaimed while reclaiming memory.",
+            "bottom_reclaim_pages_total{kind=\"scanned\"} 4",
+            "bottom_reclaim_pages_total{kind=\"stolen\"} 5",
+            "# EOF",
+        ];
+
+        assert_eq!(metrics.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn renders_disk_io() {
+        let mut io = IoHarvest::new();
+        io.insert(
+            "sda".to_string(),
+            Some(IoData {
+                read_bytes: 512,
+                write_bytes: 1024,
+                details: Some(IoDetails {
+                    reads: 1,
+                    writes: 2,
+                    read_time_ms: 1500,
+                    write_time_ms: 2500,
+                    in_flight: 3,
+                    busy_time_ms: 4000,
+                    weighted_time_ms: 5000,
+                    ..Default::default()
+                }),
+            }),
+        );
+        io.insert("sdb".to_string(), None);
+
+        let data = Data {
+            io: Some(io),
+            ..Default::default()
+        };
+
+        let metrics = render(&data, TemperatureType::Celsius);
+        let expected = [
+            "# TYPE bottom_disk_read_bytes counter",
+            "# HELP bottom_disk_read_bytes Bytes read from a device.",
+            "bottom_disk_read_bytes_total{device=\"sda\"} 512",
+            "# TYPE bottom_disk_written_bytes counter",
+            "# HELP bottom_disk_written_bytes Bytes written to a device.",
+            "bottom_disk_written_bytes_total{device=\"sda\"} 1024",
+            "# TYPE bottom_disk_reads_completed counter",
+            "# HELP bottom_disk_reads_completed Reads completed by a device.",
+            "bottom_disk_reads_completed_total{device=\"sda\"} 1",
+            "# TYPE bottom_disk_writes_completed counter",
+            "# HELP bottom_disk_writes_completed Writes completed by a device.",
+            "bottom_disk_writes_completed_total{device=\"sda\"} 2",
+            "# TYPE bottom_disk_read_time_seconds counter",
+            "# HELP bottom_disk_read_time_seconds Time spent on reads by a device.",
+            "bottom_disk_read_time_seconds_total{device=\"sda\"} 1.5",
+            "# TYPE bottom_disk_write_time_seconds counter",
+            "# HELP bottom_disk_write_time_seconds Time spent on writes by a device.",
+            "bottom_disk_write_time_seconds_total{device=\"sda\"} 2.5",
+            "# TYPE bottom_disk_io_time_seconds counter",
+            "# HELP bottom_disk_io_time_seconds Time a device spent with I/O in flight.",
+            "bottom_disk_io_time_seconds_total{device=\"sda\"} 4",
+            "# TYPE bottom_disk_io_time_weighted_seconds counter",
+            "# HELP bottom_disk_io_time_weighted_seconds Time spent by each I/O request in flight, summed up.",
+            "bottom_disk_io_time_weighted_seconds_total{device=\"sda\"} 5",
+            "# TYPE bottom_disk_io_in_flight gauge",
+            "# HELP bottom_disk_io_in_flight I/O requests currently in flight on a device.",
+            "bottom_disk_io_in_flight{device=\"sda\"} 3",
+            "# EOF",
+        ];
+
+        assert_eq!(metrics.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn formats_special_values() {
+        assert_eq!(format_value(f64::NAN), "NaN");
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+        assert_eq!(format_value(f64::NEG_INFINITY), "-Inf");
+        assert_eq!(format_value(1.5), "1.5");
+    }
+}
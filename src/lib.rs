@@ -26,7 +26,11 @@ pub mod canvas;
 pub mod constants;
 pub mod data_collection;
 pub mod data_conversion;
+#[cfg(feature = "exporter")]
+pub mod exporter;
 pub mod options;
+pub mod output;
+pub mod session;
 pub mod widgets;
 
 use std::{
@@ -61,6 +65,7 @@ use crossterm::{
 use data_conversion::*;
 pub use options::args;
 use options::Config;
+use session::replay::{ReplayEvent, LONG_SEEK_STEP, SEEK_STEP};
 use utils::error;
 #[allow(unused_imports)]
 pub use utils::logging::*;
@@ -79,6 +84,7 @@ pub enum BottomEvent {
     MouseInput(MouseEvent),
     PasteEvent(String),
     Update(Box<data_collection::Data>),
+    Replay(ReplayEvent),
     Clean,
     Terminate,
 }
@@ -87,6 +93,21 @@ pub enum BottomEvent {
 #[derive(Debug)]
 pub enum CollectionThreadEvent {
     Reset,
+
+    /// Pauses or resumes a replay.
+    SetPaused(bool),
+
+    /// Sets the playback speed of a replay.
+    SetSpeed(f64),
+
+    /// Moves a replay forwards.
+    SeekForward(Duration),
+
+    /// Moves a replay backwards.
+    SeekBackward(Duration),
+
+    /// Sets whether to also collect the threads of each process.
+    SetCollectThreads(bool),
 }
 
 pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
@@ -115,9 +136,26 @@ pub fn handle_key_event_or_break(
 ) -> bool {
     // c_debug!("KeyEvent: {event:?}");
 
+    if let KeyCode::Char(caught_char) = event.code {
+        if event.modifiers.is_empty() || event.modifiers == KeyModifiers::SHIFT {
+            if handle_replay_key(caught_char, app, reset_sender) {
+                return false;
+            }
+
+            if caught_char == 'T' && app.toggle_threads() {
+                let _ = reset_sender.send(CollectionThreadEvent::SetCollectThreads(
+                    app.app_config_fields.show_threads,
+                ));
+                return false;
+            }
+        }
+    }
+
     if event.modifiers.is_empty() {
         // Required catch for searching - otherwise you couldn't search with q.
-        if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
+        if event.code == KeyCode::Char('q')
+            && !(app.is_in_search_widget() || app.is_in_table_search())
+        {
             return true;
         }
         match event.code {
@@ -131,6 +169,7 @@ pub fn handle_key_event_or_break(
             KeyCode::Esc => app.on_esc(),
             KeyCode::Enter => app.on_enter(),
             KeyCode::Tab => app.on_tab(),
+            KeyCode::BackTab => app.on_back_tab(),
             KeyCode::Backspace => app.on_backspace(),
             KeyCode::Delete => app.on_delete(),
             KeyCode::F(1) => app.toggle_ignore_case(),
@@ -172,7 +211,9 @@ pub fn handle_key_event_or_break(
                 }
                 KeyCode::Char('a') => app.skip_cursor_beginning(),
                 KeyCode::Char('e') => app.skip_cursor_end(),
-                KeyCode::Char('u') if app.is_in_search_widget() => app.clear_search(),
+                KeyCode::Char('u') if app.is_in_search_widget() || app.is_in_table_search() => {
+                    app.clear_search()
+                }
                 KeyCode::Char('w') => app.clear_previous_word(),
                 KeyCode::Char('h') => app.on_backspace(),
                 KeyCode::Char('d') => app.scroll_half_page_down(),
@@ -192,6 +233,7 @@ pub fn handle_key_event_or_break(
                 KeyCode::Right => app.move_widget_selection(&WidgetDirection::Right),
                 KeyCode::Up => app.move_widget_selection(&WidgetDirection::Up),
                 KeyCode::Down => app.move_widget_selection(&WidgetDirection::Down),
+                KeyCode::BackTab => app.on_back_tab(),
                 KeyCode::Char(caught_char) => app.on_char_key(caught_char),
                 _ => {}
             }
@@ -201,6 +243,50 @@ pub fn handle_key_event_or_break(
     false
 }
 
+/// Handles the playback controls while replaying a session, returning whether the key was used.
+fn handle_replay_key(
+    caught_char: char, app: &mut App, control_sender: &Sender<CollectionThreadEvent>,
+) -> bool {
+    if app.is_in_search_widget()
+        || app.is_in_table_search()
+        || app.help_dialog_state.is_showing_help
+        || app.delete_dialog_state.is_showing_dd
+    {
+        return false;
+    }
+
+    let Some(replay_state) = &mut app.replay_state else {
+        return false;
+    };
+
+    let event = match caught_char {
+        ' ' => {
+            replay_state.is_paused = !replay_state.is_paused;
+            CollectionThreadEvent::SetPaused(replay_state.is_paused)
+        }
+        '>' => {
+            if !replay_state.speed_up() {
+                return true;
+            }
+            CollectionThreadEvent::SetSpeed(replay_state.speed())
+        }
+        '<' => {
+            if !replay_state.slow_down() {
+                return true;
+            }
+            CollectionThreadEvent::SetSpeed(replay_state.speed())
+        }
+        ']' => CollectionThreadEvent::SeekForward(SEEK_STEP),
+        '[' => CollectionThreadEvent::SeekBackward(SEEK_STEP),
+        '}' => CollectionThreadEvent::SeekForward(LONG_SEEK_STEP),
+        '{' => CollectionThreadEvent::SeekBackward(LONG_SEEK_STEP),
+        _ => return false,
+    };
+
+    let _ = control_sender.send(event);
+    true
+}
+
 pub fn read_config(config_location: Option<&String>) -> error::Result<Option<PathBuf>> {
     let config_path = if let Some(conf_loc) = config_location {
         Some(PathBuf::from(conf_loc.as_str()))
@@ -366,6 +452,33 @@ pub fn update_data(app: &mut App) {
             }
         }
     }
+    {
+        let data = &app.converted_data.net_interface_data;
+        for net_table in app.states.net_table_state.widget_states.values_mut() {
+            if net_table.force_update_data {
+                net_table.ingest_data(data);
+                net_table.force_update_data = false;
+            }
+        }
+    }
+    {
+        let data = &app.converted_data.mem_details_data;
+        for mem_table in app.states.mem_table_state.widget_states.values_mut() {
+            if mem_table.force_update_data {
+                mem_table.ingest_data(data);
+                mem_table.force_update_data = false;
+            }
+        }
+    }
+    {
+        let data = &app.converted_data.cgroup_data;
+        for cgroup in app.states.cgroup_state.widget_states.values_mut() {
+            if cgroup.force_update_data {
+                cgroup.ingest_data(data);
+                cgroup.force_update_data = false;
+            }
+        }
+    }
     {
         let data = &app.converted_data.disk_data;
         for disk in app.states.disk_state.widget_states.values_mut() {
@@ -384,6 +497,8 @@ pub fn update_data(app: &mut App) {
             app.converted_data.cache_data = convert_cache_data_points(data_source);
         }
         app.converted_data.swap_data = convert_swap_data_points(data_source);
+        app.converted_data.ingest_paging_data(data_source);
+        app.converted_data.ingest_memory_extras_data(data_source);
         #[cfg(feature = "zfs")]
         {
             app.converted_data.arc_data = convert_arc_data_points(data_source);
@@ -396,9 +511,31 @@ pub fn update_data(app: &mut App) {
         app.states.mem_state.force_update = None;
     }
 
+    if app.states.pressure_state.force_update.is_some() {
+        app.converted_data.cpu_pressure_data = convert_timed_data_points(data_source, |data| {
+            data.pressure_data.cpu.map(|cpu| cpu.some.avg10)
+        });
+        app.converted_data.mem_pressure_data = convert_timed_data_points(data_source, |data| {
+            data.pressure_data.memory.map(|memory| memory.some.avg10)
+        });
+        app.converted_data.io_pressure_data = convert_timed_data_points(data_source, |data| {
+            data.pressure_data.io.map(|io| io.some.avg10)
+        });
+        app.states.pressure_state.force_update = None;
+    }
+
+    if app.states.disk_io_state.force_update.is_some() {
+        app.converted_data.disk_io_data = convert_disk_io_data_points(
+            data_source,
+            app.states.disk_io_state.selected_disk.as_deref(),
+        );
+        app.states.disk_io_state.force_update = None;
+    }
+
     if app.states.net_state.force_update.is_some() {
         let (rx, tx) = get_rx_tx_data_points(
             data_source,
+            app.states.net_state.selected_interface.as_deref(),
             &app.app_config_fields.network_scale_type,
             &app.app_config_fields.network_unit_type,
             app.app_config_fields.network_use_binary_prefix,
@@ -484,6 +621,8 @@ pub fn create_collection_thread(
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let unnormalized_cpu = app_config_fields.unnormalized_cpu;
     let show_average_cpu = app_config_fields.show_average_cpu;
+    #[cfg(target_os = "linux")]
+    let show_threads = app_config_fields.show_threads;
     let update_time = app_config_fields.update_rate;
 
     thread::spawn(move || {
@@ -494,6 +633,8 @@ pub fn create_collection_thread(
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_unnormalized_cpu(unnormalized_cpu);
         data_state.set_show_average_cpu(show_average_cpu);
+        #[cfg(target_os = "linux")]
+        data_state.set_collect_threads(show_threads);
 
         data_state.init();
 
@@ -512,6 +653,16 @@ pub fn create_collection_thread(
                     CollectionThreadEvent::Reset => {
                         data_state.data.cleanup();
                     }
+                    #[cfg(target_os = "linux")]
+                    CollectionThreadEvent::SetCollectThreads(collect_threads) => {
+                        data_state.set_collect_threads(collect_threads);
+                    }
+                    #[cfg(not(target_os = "linux"))]
+                    CollectionThreadEvent::SetCollectThreads(_) => {}
+                    CollectionThreadEvent::SetPaused(_)
+                    | CollectionThreadEvent::SetSpeed(_)
+                    | CollectionThreadEvent::SeekForward(_)
+                    | CollectionThreadEvent::SeekBackward(_) => {}
                 }
             }
 
@@ -8,6 +8,7 @@ pub mod config;
 
 use std::{
     convert::TryInto,
+    path::{Path, PathBuf},
     str::FromStr,
     time::{Duration, Instant},
 };
@@ -21,12 +22,25 @@ use regex::Regex;
 #[cfg(feature = "battery")]
 use starship_battery::Manager;
 
-use self::config::{layout::Row, IgnoreList, StringOrNum};
+use self::config::{alert::AlertMetricConfig, layout::Row, IgnoreList, StringOrNum};
+#[cfg(feature = "exporter")]
+use crate::exporter::{Exporter, ExporterAddress};
 use crate::{
-    app::{filter::Filter, layout_manager::*, *},
+    app::{
+        alerts::{AlertMetric, AlertRule, Alerts},
+        filter::Filter,
+        layout_manager::*,
+        query::parse_query,
+        *,
+    },
     canvas::{components::time_chart::LegendPosition, styling::CanvasStyling, ColourScheme},
     constants::*,
     data_collection::temperature::TemperatureType,
+    output::{
+        snapshot::{SnapshotFormat, SnapshotOptions},
+        OutputMode,
+    },
+    session::{Recorder, Session},
     utils::{
         data_units::DataUnit,
         error::{self, BottomError},
@@ -77,18 +91,27 @@ pub fn init_app(
     let expanded = is_flag_enabled!(expanded, matches, config);
 
     // For processes
-    let is_grouped = is_flag_enabled!(group_processes, matches, config);
-    let is_case_sensitive = is_flag_enabled!(case_sensitive, matches, config);
-    let is_match_whole_word = is_flag_enabled!(whole_word, matches, config);
-    let is_use_regex = is_flag_enabled!(regex, matches, config);
+    let (proc_mode, proc_table_config, proc_columns) = get_process_table_settings(matches, config);
+
+    // For disks
+    let disk_columns = config
+        .disk
+        .as_ref()
+        .map(|cfg| cfg.columns.clone())
+        .unwrap_or_default();
 
     let mut widget_map = HashMap::new();
     let mut cpu_state_map: HashMap<u64, CpuWidgetState> = HashMap::new();
     let mut mem_state_map: HashMap<u64, MemWidgetState> = HashMap::new();
+    let mut mem_table_state_map: HashMap<u64, MemTableWidget> = HashMap::new();
     let mut net_state_map: HashMap<u64, NetWidgetState> = HashMap::new();
+    let mut net_table_state_map: HashMap<u64, NetTableWidget> = HashMap::new();
     let mut proc_state_map: HashMap<u64, ProcWidgetState> = HashMap::new();
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::new();
     let mut disk_state_map: HashMap<u64, DiskTableWidget> = HashMap::new();
+    let mut cgroup_state_map: HashMap<u64, CgroupTableWidget> = HashMap::new();
+    let mut disk_io_state_map: HashMap<u64, DiskIoWidgetState> = HashMap::new();
+    let mut pressure_state_map: HashMap<u64, PressureWidgetState> = HashMap::new();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::new();
 
     let autohide_timer = if autohide_time {
@@ -102,30 +125,12 @@ pub fn init_app(
     let is_custom_layout = config.row.is_some();
     let mut used_widget_set = HashSet::new();
 
-    let show_memory_as_values = is_flag_enabled!(mem_as_value, matches, config);
-    let is_default_tree = is_flag_enabled!(tree, matches, config);
-    let is_default_command = is_flag_enabled!(process_command, matches, config);
     let is_advanced_kill = !(is_flag_enabled!(disable_advanced_kill, matches, config));
 
     let network_unit_type = get_network_unit_type(matches, config);
     let network_scale_type = get_network_scale_type(matches, config);
     let network_use_binary_prefix = is_flag_enabled!(network_use_binary_prefix, matches, config);
 
-    let proc_columns: Option<IndexSet<ProcWidgetColumn>> = {
-        let columns = config.processes.as_ref().map(|cfg| cfg.columns.clone());
-
-        match columns {
-            Some(columns) => {
-                if columns.is_empty() {
-                    None
-                } else {
-                    Some(IndexSet::from_iter(columns))
-                }
-            }
-            None => None,
-        }
-    };
-
     let network_legend_position = get_network_legend(matches, config)?;
     let memory_legend_position = get_memory_legend(matches, config)?;
 
@@ -140,6 +145,7 @@ pub fn init_app(
         cpu_left_legend: is_flag_enabled!(cpu_left_legend, matches, config),
         use_current_cpu_total: is_flag_enabled!(current_usage, matches, config),
         unnormalized_cpu: is_flag_enabled!(unnormalized_cpu, matches, config),
+        show_threads: is_flag_enabled!(threads, matches, config),
         use_basic_mode,
         default_time_value,
         time_interval: get_time_interval(matches, config, retention_ms)
@@ -151,6 +157,9 @@ pub fn init_app(
         disable_click: is_flag_enabled!(disable_click, matches, config),
         enable_gpu: get_enable_gpu(matches, config),
         enable_cache_memory: get_enable_cache_memory(matches, config),
+        enable_paging_graph: is_flag_enabled!(enable_paging_graph, matches, config),
+        enable_memory_extra_graphs: is_flag_enabled!(enable_memory_extra_graphs, matches, config),
+        enable_hwmon_sensors: is_flag_enabled!(enable_hwmon_sensors, matches, config),
         show_table_scroll_position: is_flag_enabled!(show_table_scroll_position, matches, config),
         is_advanced_kill,
         memory_legend_position,
@@ -161,14 +170,6 @@ pub fn init_app(
         retention_ms,
     };
 
-    let table_config = ProcTableConfig {
-        is_case_sensitive,
-        is_match_whole_word,
-        is_use_regex,
-        show_memory_as_values,
-        is_command: is_default_command,
-    };
-
     for row in &widget_layout.rows {
         for col in &row.children {
             for col_row in &col.children {
@@ -208,7 +209,7 @@ pub fn init_app(
                     used_widget_set.insert(widget.widget_type.clone());
 
                     match widget.widget_type {
-                        Cpu => {
+                        Cpu | CpuHeatmap => {
                             cpu_state_map.insert(
                                 widget.widget_id,
                                 CpuWidgetState::new(
@@ -218,6 +219,11 @@ pub fn init_app(
                                         .as_ref()
                                         .map(|cfg| cfg.default)
                                         .unwrap_or_default(),
+                                    config
+                                        .cpu
+                                        .as_ref()
+                                        .map(|cfg| cfg.grouping)
+                                        .unwrap_or_default(),
                                     default_time_value,
                                     autohide_timer,
                                     styling,
@@ -236,23 +242,19 @@ pub fn init_app(
                                 NetWidgetState::init(default_time_value, autohide_timer),
                             );
                         }
+                        NetTable => {
+                            net_table_state_map.insert(
+                                widget.widget_id,
+                                NetTableWidget::new(&app_config_fields, styling),
+                            );
+                        }
                         Proc => {
-                            let mode = if is_grouped {
-                                ProcWidgetMode::Grouped
-                            } else if is_default_tree {
-                                ProcWidgetMode::Tree {
-                                    collapsed_pids: Default::default(),
-                                }
-                            } else {
-                                ProcWidgetMode::Normal
-                            };
-
                             proc_state_map.insert(
                                 widget.widget_id,
                                 ProcWidgetState::new(
                                     &app_config_fields,
-                                    mode,
-                                    table_config,
+                                    proc_mode.clone(),
+                                    proc_table_config,
                                     styling,
                                     &proc_columns,
                                 ),
@@ -261,7 +263,31 @@ pub fn init_app(
                         Disk => {
                             disk_state_map.insert(
                                 widget.widget_id,
-                                DiskTableWidget::new(&app_config_fields, styling),
+                                DiskTableWidget::new(&app_config_fields, styling, &disk_columns),
+                            );
+                        }
+                        DiskIo => {
+                            disk_io_state_map.insert(
+                                widget.widget_id,
+                                DiskIoWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        Pressure => {
+                            pressure_state_map.insert(
+                                widget.widget_id,
+                                PressureWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        MemTable => {
+                            mem_table_state_map.insert(
+                                widget.widget_id,
+                                MemTableWidget::new(&app_config_fields, styling),
+                            );
+                        }
+                        Cgroup => {
+                            cgroup_state_map.insert(
+                                widget.widget_id,
+                                CgroupTableWidget::new(&app_config_fields, styling),
                             );
                         }
                         Temp => {
@@ -306,35 +332,109 @@ pub fn init_app(
         None
     };
 
-    let use_mem = used_widget_set.get(&Mem).is_some() || used_widget_set.get(&BasicMem).is_some();
-    let used_widgets = UsedWidgets {
-        use_cpu: used_widget_set.get(&Cpu).is_some() || used_widget_set.get(&BasicCpu).is_some(),
+    let use_mem = used_widget_set.get(&Mem).is_some()
+        || used_widget_set.get(&MemTable).is_some()
+        || used_widget_set.get(&BasicMem).is_some();
+    let record_path = matches.get_one::<String>("record").map(PathBuf::from);
+    #[cfg(feature = "exporter")]
+    let exporter_address =
+        get_exporter_address(matches, config).context("Update 'exporter' in your config file.")?;
+
+    let mut used_widgets = UsedWidgets {
+        use_cpu: used_widget_set.get(&Cpu).is_some()
+            || used_widget_set.get(&CpuHeatmap).is_some()
+            || used_widget_set.get(&BasicCpu).is_some(),
         use_mem,
         use_cache: use_mem && get_enable_cache_memory(matches, config),
         use_gpu: get_enable_gpu(matches, config),
-        use_net: used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some(),
+        use_net: used_widget_set.get(&Net).is_some()
+            || used_widget_set.get(&BasicNet).is_some()
+            || used_widget_set.get(&NetTable).is_some(),
         use_proc: used_widget_set.get(&Proc).is_some(),
-        use_disk: used_widget_set.get(&Disk).is_some(),
+        use_disk: used_widget_set.get(&Disk).is_some() || used_widget_set.get(&DiskIo).is_some(),
+        use_disk_io: used_widget_set.get(&DiskIo).is_some(),
         use_temp: used_widget_set.get(&Temp).is_some(),
+        use_sensors: used_widget_set.get(&Temp).is_some()
+            && is_flag_enabled!(enable_hwmon_sensors, matches, config),
         use_battery: used_widget_set.get(&Battery).is_some(),
+        use_cgroup: used_widget_set.get(&Cgroup).is_some(),
+        // The CPU and memory widgets also show pressure, in their titles or as a gauge.
+        use_pressure: used_widget_set.get(&Pressure).is_some()
+            || used_widget_set.get(&Cpu).is_some()
+            || used_widget_set.get(&BasicCpu).is_some()
+            || used_widget_set.get(&Mem).is_some()
+            || used_widget_set.get(&BasicMem).is_some(),
     };
 
+    // The exporter and recordings report everything we can collect, regardless of which widgets
+    // are shown. This way, a recording can also be replayed with a different layout.
+    #[cfg(feature = "exporter")]
+    let collect_everything = record_path.is_some() || exporter_address.is_some();
+    #[cfg(not(feature = "exporter"))]
+    let collect_everything = record_path.is_some();
+
+    if collect_everything {
+        used_widgets = UsedWidgets {
+            use_cpu: true,
+            use_mem: true,
+            use_cache: get_enable_cache_memory(matches, config),
+            use_net: true,
+            use_disk: true,
+            use_temp: true,
+            use_sensors: true,
+            use_battery: true,
+            use_pressure: true,
+            // Processes are only needed to replay the process widget.
+            use_proc: used_widgets.use_proc || record_path.is_some(),
+            use_cgroup: used_widgets.use_cgroup || record_path.is_some(),
+            ..used_widgets
+        };
+    }
+
+    // Alerts need the data they watch, even if no widget shows it.
+    let alert_rules = get_alert_rules(config).context("Update 'alerts' in your config file.")?;
+    for rule in &alert_rules {
+        match rule.metric {
+            AlertMetric::Cpu(_) => used_widgets.use_cpu = true,
+            AlertMetric::Memory | AlertMetric::Swap => used_widgets.use_mem = true,
+            AlertMetric::Disk(_) => used_widgets.use_disk = true,
+            AlertMetric::Temperature(_) => used_widgets.use_temp = true,
+            AlertMetric::Battery(_) => used_widgets.use_battery = true,
+            AlertMetric::Processes(..) => used_widgets.use_proc = true,
+        }
+    }
+
     let disk_filter =
         get_ignore_list(&config.disk_filter).context("Update 'disk_filter' in your config file")?;
     let mount_filter = get_ignore_list(&config.mount_filter)
         .context("Update 'mount_filter' in your config file")?;
+    let fs_type_filter = get_ignore_list(&config.fs_type_filter)
+        .context("Update 'fs_type_filter' in your config file")?;
+    let mount_options_filter = get_ignore_list(&config.mount_options_filter)
+        .context("Update 'mount_options_filter' in your config file")?;
+    let device_filter = get_ignore_list(&config.device_filter)
+        .context("Update 'device_filter' in your config file")?;
     let temp_filter =
         get_ignore_list(&config.temp_filter).context("Update 'temp_filter' in your config file")?;
+    let sensor_filter = get_ignore_list(&config.sensor_filter)
+        .context("Update 'sensor_filter' in your config file")?;
     let net_filter =
         get_ignore_list(&config.net_filter).context("Update 'net_filter' in your config file")?;
+    let cgroup_filter = get_ignore_list(&config.cgroup_filter)
+        .context("Update 'cgroup_filter' in your config file")?;
 
     let states = AppWidgetStates {
         cpu_state: CpuState::init(cpu_state_map),
         mem_state: MemState::init(mem_state_map),
+        mem_table_state: MemTableState::init(mem_table_state_map),
         net_state: NetState::init(net_state_map),
+        net_table_state: NetTableState::init(net_table_state_map),
         proc_state: ProcState::init(proc_state_map),
         temp_state: TempState::init(temp_state_map),
         disk_state: DiskState::init(disk_state_map),
+        disk_io_state: DiskIoState::init(disk_io_state_map),
+        cgroup_state: CgroupState::init(cgroup_state_map),
+        pressure_state: PressureState::init(pressure_state_map),
         battery_state: BatteryState::init(battery_state_map),
         basic_table_widget_state,
     };
@@ -343,12 +443,17 @@ pub fn init_app(
     let filters = DataFilters {
         disk_filter,
         mount_filter,
+        fs_type_filter,
+        mount_options_filter,
+        device_filter,
         temp_filter,
+        sensor_filter,
         net_filter,
+        cgroup_filter,
     };
     let is_expanded = expanded && !use_basic_mode;
 
-    Ok(App::new(
+    let mut app = App::new(
         app_config_fields,
         states,
         widget_map,
@@ -356,7 +461,70 @@ pub fn init_app(
         used_widgets,
         filters,
         is_expanded,
-    ))
+    );
+    app.alerts = Alerts::new(alert_rules);
+
+    #[cfg(feature = "exporter")]
+    if let Some(address) = exporter_address {
+        let exporter = Exporter::start(&address, app.app_config_fields.temperature_type)
+            .with_context(|| format!("Failed to start the exporter on '{address}'."))?;
+        app.exporter = Some(exporter);
+    }
+
+    if let Some(path) = record_path {
+        let recorder = Recorder::create(&path).with_context(|| {
+            format!("Unable to create the recording file '{}'.", path.display())
+        })?;
+        app.recorder = Some(recorder);
+    }
+
+    Ok(app)
+}
+
+/// Returns the initial mode, table settings, and columns used for process widgets.
+fn get_process_table_settings(
+    matches: &ArgMatches, config: &Config,
+) -> (
+    ProcWidgetMode,
+    ProcTableConfig,
+    Option<IndexSet<ProcWidgetColumn>>,
+) {
+    let mode = if is_flag_enabled!(group_by_cgroup, matches, config) {
+        ProcWidgetMode::Grouped(ProcGrouping::Cgroup)
+    } else if is_flag_enabled!(group_processes, matches, config) {
+        ProcWidgetMode::Grouped(ProcGrouping::Name)
+    } else if is_flag_enabled!(tree, matches, config) {
+        ProcWidgetMode::Tree {
+            collapsed_pids: Default::default(),
+        }
+    } else {
+        ProcWidgetMode::Normal
+    };
+
+    let table_config = ProcTableConfig {
+        is_case_sensitive: is_flag_enabled!(case_sensitive, matches, config),
+        is_match_whole_word: is_flag_enabled!(whole_word, matches, config),
+        is_use_regex: is_flag_enabled!(regex, matches, config),
+        show_memory_as_values: is_flag_enabled!(mem_as_value, matches, config),
+        is_command: is_flag_enabled!(process_command, matches, config),
+    };
+
+    let columns: Option<IndexSet<ProcWidgetColumn>> = {
+        let columns = config.processes.as_ref().map(|cfg| cfg.columns.clone());
+
+        match columns {
+            Some(columns) => {
+                if columns.is_empty() {
+                    None
+                } else {
+                    Some(IndexSet::from_iter(columns))
+                }
+            }
+            None => None,
+        }
+    };
+
+    (mode, table_config, columns)
 }
 
 pub fn get_widget_layout(
@@ -730,6 +898,109 @@ pub fn get_color_scheme(matches: &ArgMatches, config: &Config) -> error::Result<
     Ok(ColourScheme::Default)
 }
 
+pub fn get_output_mode(matches: &ArgMatches, config: &Config) -> Option<OutputMode> {
+    if let Some(format) = matches.get_one::<String>("snapshot") {
+        let format = match format.as_str() {
+            "csv" => SnapshotFormat::Csv,
+            "json" => SnapshotFormat::Json,
+            _ => SnapshotFormat::Text,
+        };
+        let (mode, table_config, columns) = get_process_table_settings(matches, config);
+
+        return Some(OutputMode::Snapshot(SnapshotOptions {
+            format,
+            query: matches.get_one::<String>("query").cloned(),
+            mode,
+            table_config,
+            columns,
+        }));
+    }
+
+    #[cfg(feature = "exporter")]
+    if matches.get_flag("headless") {
+        return Some(OutputMode::Headless);
+    }
+
+    match matches.get_one::<String>("output").map(String::as_str) {
+        Some("ndjson") => Some(OutputMode::Ndjson),
+        _ => None,
+    }
+}
+
+/// Loads the session to replay, if there is one.
+pub fn get_replay_session(matches: &ArgMatches) -> Result<Option<Session>> {
+    matches
+        .get_one::<String>("replay")
+        .map(|path| Session::load(Path::new(path)))
+        .transpose()
+}
+
+#[cfg(feature = "exporter")]
+fn get_exporter_address(
+    matches: &ArgMatches, config: &Config,
+) -> error::Result<Option<ExporterAddress>> {
+    // Snapshots and NDJSON already print their data, so they don't bind the exporter's address.
+    let prints_data = matches.get_one::<String>("snapshot").is_some()
+        || matches.get_one::<String>("output").map(String::as_str) == Some("ndjson");
+    if prints_data {
+        return Ok(None);
+    }
+
+    let address = if let Some(address) = matches.get_one::<String>("exporter") {
+        Some(address.as_str())
+    } else if let Some(flags) = &config.flags {
+        flags.exporter.as_deref()
+    } else {
+        None
+    };
+
+    address
+        .map(|address| address.parse().map_err(BottomError::ConfigError))
+        .transpose()
+}
+
+fn get_alert_rules(config: &Config) -> error::Result<Vec<AlertRule>> {
+    let Some(alerts) = &config.alerts else {
+        return Ok(Vec::new());
+    };
+
+    alerts
+        .iter()
+        .map(|alert| {
+            let metric = match &alert.metric {
+                AlertMetricConfig::Cpu { core } => AlertMetric::Cpu(*core),
+                AlertMetricConfig::Memory => AlertMetric::Memory,
+                AlertMetricConfig::Swap => AlertMetric::Swap,
+                AlertMetricConfig::Disk { mount } => AlertMetric::Disk(mount.clone()),
+                AlertMetricConfig::Temperature { sensor } => {
+                    AlertMetric::Temperature(sensor.clone())
+                }
+                AlertMetricConfig::Battery { battery } => AlertMetric::Battery(*battery),
+                AlertMetricConfig::Process { query } => {
+                    AlertMetric::Processes(parse_query(query, false, true, false)?, query.clone())
+                }
+            };
+
+            let duration = match &alert.duration {
+                Some(StringOrNum::String(s)) => try_parse_ms(s)?,
+                Some(StringOrNum::Num(n)) => *n,
+                None => 0,
+            };
+
+            Ok(AlertRule {
+                name: alert.name.clone(),
+                metric,
+                threshold: alert.threshold,
+                below: alert.below,
+                duration: Duration::from_millis(duration),
+                highlight: alert.highlight.unwrap_or(true),
+                banner: alert.banner.unwrap_or(true),
+                command: alert.command.clone(),
+            })
+        })
+        .collect()
+}
+
 fn get_network_unit_type(matches: &ArgMatches, config: &Config) -> DataUnit {
     if matches.get_flag("network_use_bytes") {
         return DataUnit::Byte;
@@ -999,6 +1270,35 @@ mod test {
         assert_eq!(get_retention(&matches, &config), Ok(600000));
     }
 
+    #[cfg(feature = "exporter")]
+    #[test]
+    fn config_exporter_address() {
+        let config = Config {
+            flags: Some(ConfigFlags {
+                exporter: Some("127.0.0.1:9184".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let app = crate::args::build_app();
+        let matches = app.get_matches_from(["btm"]);
+        assert!(matches!(
+            super::get_exporter_address(&matches, &config),
+            Ok(Some(_))
+        ));
+
+        // Modes that print their data don't start the exporter.
+        for args in [["btm", "--snapshot", "text"], ["btm", "--output", "ndjson"]] {
+            let app = crate::args::build_app();
+            let matches = app.get_matches_from(args);
+            assert!(matches!(
+                super::get_exporter_address(&matches, &config),
+                Ok(None)
+            ));
+        }
+    }
+
     fn create_app(config: Config, matches: ArgMatches) -> App {
         let (layout, id, ty) = get_widget_layout(&matches, &config).unwrap();
         let styling =
@@ -1022,7 +1322,8 @@ mod test {
         };
 
         // Skip battery since it's tricky to test depending on the platform/features we're testing with.
-        let skip = ["help", "version", "celsius", "battery"];
+        // Headless only affects the output mode and can't be used without an exporter address.
+        let skip = ["help", "version", "celsius", "battery", "headless"];
 
         for arg in app.get_arguments().collect::<Vec<_>>() {
             let arg_name = arg
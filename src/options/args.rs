@@ -280,6 +280,15 @@ fn process_args(cmd: Command) -> Command {
         .action(ArgAction::SetTrue)
         .help("Groups processes with the same name by default.");
 
+    let group_by_cgroup = Arg::new("group_by_cgroup")
+        .long("group_by_cgroup")
+        .action(ArgAction::SetTrue)
+        .help("Groups processes by their container, unit, or cgroup by default.")
+        .long_help(
+            "Groups processes by their container, systemd unit or pod, or cgroup by default. This \
+            takes precedence over --group_processes, and is only supported on Linux.",
+        );
+
     let mem_as_value = Arg::new("mem_as_value")
         .long("mem_as_value")
         .action(ArgAction::SetTrue)
@@ -303,6 +312,15 @@ fn process_args(cmd: Command) -> Command {
         .action(ArgAction::SetTrue)
         .help("Makes the process widget use tree mode by default.");
 
+    let threads = Arg::new("threads")
+        .long("threads")
+        .action(ArgAction::SetTrue)
+        .help("Shows the threads of each process in the process widget by default.")
+        .long_help(
+            "Shows the threads of each process in the process widget by default. This is only \
+            supported on Linux.",
+        );
+
     let unnormalized_cpu = Arg::new("unnormalized_cpu")
         .short('n')
         .long("unnormalized_cpu")
@@ -319,10 +337,12 @@ fn process_args(cmd: Command) -> Command {
         case_sensitive,
         current_usage,
         disable_advanced_kill,
+        group_by_cgroup,
         group_processes,
         mem_as_value,
         process_command,
         regex,
+        threads,
         tree,
         unnormalized_cpu,
         whole_word,
@@ -353,13 +373,18 @@ fn temperature_args(cmd: Command) -> Command {
         .action(ArgAction::SetTrue)
         .help("Use Kelvin as the temperature unit.");
 
+    let enable_hwmon_sensors = Arg::new("enable_hwmon_sensors")
+        .long("enable_hwmon_sensors")
+        .action(ArgAction::SetTrue)
+        .help("Also show fan, voltage, power, current, and energy sensors in the temperature widget. Only supported on Linux.");
+
     let temperature_group = ArgGroup::new("TEMPERATURE_TYPE").args([
         celsius.get_id(),
         fahrenheit.get_id(),
         kelvin.get_id(),
     ]);
 
-    cmd.args(args![celsius, fahrenheit, kelvin])
+    cmd.args(args![celsius, fahrenheit, kelvin, enable_hwmon_sensors])
         .group(temperature_group)
 }
 
@@ -385,6 +410,16 @@ fn cpu_args(cmd: Command) -> Command {
 fn mem_args(cmd: Command) -> Command {
     let cmd = cmd.next_help_heading("Memory Options");
 
+    let enable_paging_graph = Arg::new("enable_paging_graph")
+        .long("enable_paging_graph")
+        .action(ArgAction::SetTrue)
+        .help("Show swap and paging activity in the memory widget. Only supported on Linux.");
+
+    let enable_memory_extra_graphs = Arg::new("enable_memory_extra_graphs")
+        .long("enable_memory_extra_graphs")
+        .action(ArgAction::SetTrue)
+        .help("Also graph zram, zswap, and the ZFS ARC hit ratio in the memory widget.");
+
     let memory_legend = Arg::new("memory_legend")
         .long("memory_legend")
         .action(ArgAction::Set)
@@ -400,11 +435,20 @@ fn mem_args(cmd: Command) -> Command {
             .action(ArgAction::SetTrue)
             .help("Enable collecting and displaying cache and buffer memory.");
 
-        cmd.args(args![enable_cache_memory, memory_legend])
+        cmd.args(args![
+            enable_cache_memory,
+            enable_paging_graph,
+            enable_memory_extra_graphs,
+            memory_legend
+        ])
     }
     #[cfg(target_os = "windows")]
     {
-        cmd.arg(memory_legend)
+        cmd.args(args![
+            enable_paging_graph,
+            enable_memory_extra_graphs,
+            memory_legend
+        ])
     }
 }
 
@@ -485,6 +529,106 @@ fn gpu_args(cmd: Command) -> Command {
     cmd.arg(enable_gpu)
 }
 
+#[cfg(feature = "exporter")]
+fn exporter_args(cmd: Command) -> Command {
+    let cmd = cmd.next_help_heading("Exporter Options");
+
+    let exporter = Arg::new("exporter")
+        .long("exporter")
+        .action(ArgAction::Set)
+        .value_name("ADDRESS")
+        .conflicts_with_all(["output", "snapshot"])
+        .help("Serves collected data as OpenMetrics text at the given address.")
+        .long_help(
+            "Serves collected data as OpenMetrics text at /metrics on the given address, such as \
+            '127.0.0.1:9184', or on a Unix socket with 'unix:/path/to/socket'. While enabled, all \
+            data is collected regardless of which widgets are shown. The exporter isn't started with \
+            --snapshot or --output.",
+        );
+
+    let headless = Arg::new("headless")
+        .long("headless")
+        .action(ArgAction::SetTrue)
+        .requires("exporter")
+        .conflicts_with_all(["record", "replay"])
+        .help("Runs the exporter without the interface.");
+
+    cmd.args([exporter, headless])
+}
+
+fn output_args(cmd: Command) -> Command {
+    let cmd = cmd.next_help_heading("Output Options");
+
+    let output = Arg::new("output")
+        .long("output")
+        .action(ArgAction::Set)
+        .value_name("FORMAT")
+        .value_parser(["ndjson"])
+        .help("Prints collected data to stdout instead of running the interface.")
+        .long_help(
+            "Prints collected data to stdout instead of running the interface. With 'ndjson', one JSON \
+            document is printed per line on every update, containing the same data that would be shown. \
+            Filters and the widgets in the layout are still used to determine what is collected."
+        );
+
+    let snapshot = Arg::new("snapshot")
+        .long("snapshot")
+        .action(ArgAction::Set)
+        .value_name("FORMAT")
+        .value_parser(["text", "csv", "json"])
+        .conflicts_with("output")
+        .help("Prints the process table once and exits.")
+        .long_help(
+            "Collects process data once, prints the process table to stdout, and exits. The table can \
+            be printed as 'text', 'csv', or 'json'. The columns, sorting, and mode are the same as the \
+            process widget's, so options like --tree, --group_processes, --process_command, and the \
+            configured columns are respected."
+        );
+
+    let query = Arg::new("query")
+        .long("query")
+        .action(ArgAction::Set)
+        .value_name("QUERY")
+        .requires("snapshot")
+        .help("Only shows processes matching a search query in a snapshot.")
+        .long_help(
+            "Only shows processes matching a search query in a snapshot. This uses the same syntax as \
+            searching in the process widget, e.g. \"cpu > 5 and user root\"."
+        );
+
+    cmd.args([output, snapshot, query])
+}
+
+fn session_args(cmd: Command) -> Command {
+    let cmd = cmd.next_help_heading("Session Options");
+
+    let record = Arg::new("record")
+        .long("record")
+        .action(ArgAction::Set)
+        .value_name("FILE")
+        .conflicts_with_all(["output", "snapshot"])
+        .help("Records the session to a file, so it can be replayed later.")
+        .long_help(
+            "Records every update to the given file while the interface is running, so the session can \
+            be replayed later with --replay. While recording, all data is collected regardless of which \
+            widgets are shown, so a recording can be replayed with any layout.",
+        );
+
+    let replay = Arg::new("replay")
+        .long("replay")
+        .action(ArgAction::Set)
+        .value_name("FILE")
+        .conflicts_with_all(["output", "snapshot", "record"])
+        .help("Replays a recorded session instead of collecting data.")
+        .long_help(
+            "Replays a session recorded with --record instead of collecting data. Press space to pause or \
+            resume, '[' and ']' to seek backwards and forwards by 10 seconds, '{' and '}' to seek by a \
+            minute, and '<' and '>' to change the playback speed.",
+        );
+
+    cmd.args([record, replay])
+}
+
 fn style_args(cmd: Command) -> Command {
     let cmd = cmd.next_help_heading("Style Options");
 
@@ -574,6 +718,10 @@ pub fn build_app() -> Command {
         battery_args,
         #[cfg(feature = "gpu")]
         gpu_args,
+        #[cfg(feature = "exporter")]
+        exporter_args,
+        output_args,
+        session_args,
         style_args,
         other_args,
     ]
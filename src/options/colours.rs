@@ -8,11 +8,15 @@ pub struct ConfigColours {
     pub all_cpu_color: Option<Cow<'static, str>>,
     pub avg_cpu_color: Option<Cow<'static, str>>,
     pub cpu_core_colors: Option<Vec<Cow<'static, str>>>,
+    pub cpu_heatmap_colors: Option<Vec<Cow<'static, str>>>,
     pub ram_color: Option<Cow<'static, str>>,
     #[cfg(not(target_os = "windows"))]
     pub cache_color: Option<Cow<'static, str>>,
     pub swap_color: Option<Cow<'static, str>>,
     pub arc_color: Option<Cow<'static, str>>,
+    pub arc_hit_color: Option<Cow<'static, str>>,
+    pub zram_color: Option<Cow<'static, str>>,
+    pub zswap_color: Option<Cow<'static, str>>,
     pub gpu_core_colors: Option<Vec<Cow<'static, str>>>,
     pub rx_color: Option<Cow<'static, str>>,
     pub tx_color: Option<Cow<'static, str>>,
@@ -1,4 +1,6 @@
+pub mod alert;
 pub mod cpu;
+pub mod disk;
 mod ignore_list;
 pub mod layout;
 pub mod process_columns;
@@ -6,7 +8,10 @@ pub mod process_columns;
 use serde::{Deserialize, Serialize};
 
 pub use self::ignore_list::IgnoreList;
-use self::{cpu::CpuConfig, layout::Row, process_columns::ProcessConfig};
+use self::{
+    alert::AlertConfig, cpu::CpuConfig, disk::DiskConfig, layout::Row,
+    process_columns::ProcessConfig,
+};
 
 use super::ConfigColours;
 
@@ -17,10 +22,17 @@ pub struct Config {
     pub(crate) row: Option<Vec<Row>>,
     pub(crate) disk_filter: Option<IgnoreList>,
     pub(crate) mount_filter: Option<IgnoreList>,
+    pub(crate) fs_type_filter: Option<IgnoreList>,
+    pub(crate) mount_options_filter: Option<IgnoreList>,
+    pub(crate) device_filter: Option<IgnoreList>,
     pub(crate) temp_filter: Option<IgnoreList>,
+    pub(crate) sensor_filter: Option<IgnoreList>,
     pub(crate) net_filter: Option<IgnoreList>,
+    pub(crate) cgroup_filter: Option<IgnoreList>,
     pub(crate) processes: Option<ProcessConfig>,
     pub(crate) cpu: Option<CpuConfig>,
+    pub(crate) disk: Option<DiskConfig>,
+    pub(crate) alerts: Option<Vec<AlertConfig>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -52,6 +64,7 @@ pub(crate) struct ConfigFlags {
     pub(crate) current_usage: Option<bool>,
     pub(crate) unnormalized_cpu: Option<bool>,
     pub(crate) group_processes: Option<bool>,
+    pub(crate) group_by_cgroup: Option<bool>,
     pub(crate) case_sensitive: Option<bool>,
     pub(crate) whole_word: Option<bool>,
     pub(crate) regex: Option<bool>,
@@ -74,6 +87,7 @@ pub(crate) struct ConfigFlags {
     pub(crate) color: Option<String>,
     pub(crate) mem_as_value: Option<bool>,
     pub(crate) tree: Option<bool>,
+    pub(crate) threads: Option<bool>,
     pub(crate) show_table_scroll_position: Option<bool>,
     pub(crate) process_command: Option<bool>,
     pub(crate) disable_advanced_kill: Option<bool>,
@@ -82,5 +96,9 @@ pub(crate) struct ConfigFlags {
     pub(crate) network_use_binary_prefix: Option<bool>,
     pub(crate) enable_gpu: Option<bool>,
     pub(crate) enable_cache_memory: Option<bool>,
+    pub(crate) enable_paging_graph: Option<bool>,
+    pub(crate) enable_memory_extra_graphs: Option<bool>,
+    pub(crate) enable_hwmon_sensors: Option<bool>,
     pub(crate) retention: Option<StringOrNum>,
+    pub(crate) exporter: Option<String>,
 }
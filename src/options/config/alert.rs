@@ -0,0 +1,118 @@
+use serde::Deserialize;
+
+use super::StringOrNum;
+
+/// What an alert watches.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "metric", rename_all = "lowercase")]
+pub enum AlertMetricConfig {
+    /// CPU usage as a percentage, either the average or of a single core.
+    Cpu { core: Option<usize> },
+
+    /// Memory usage as a percentage.
+    Memory,
+
+    /// Swap usage as a percentage.
+    Swap,
+
+    /// Disk usage as a percentage, for the disk at a mount point.
+    Disk { mount: String },
+
+    /// The temperature of a sensor, in the configured temperature unit.
+    Temperature { sensor: String },
+
+    /// The charge of a battery as a percentage.
+    Battery { battery: Option<usize> },
+
+    /// The number of processes matching a search query.
+    Process { query: String },
+}
+
+/// A single `[[alerts]]` entry.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AlertConfig {
+    pub(crate) name: Option<String>,
+    #[serde(flatten)]
+    pub(crate) metric: AlertMetricConfig,
+    pub(crate) threshold: f64,
+
+    /// Whether the alert is for values below the threshold, rather than above it.
+    #[serde(default)]
+    pub(crate) below: bool,
+
+    /// How long the threshold must be crossed for before the alert fires.
+    pub(crate) duration: Option<StringOrNum>,
+
+    pub(crate) highlight: Option<bool>,
+    pub(crate) banner: Option<bool>,
+    pub(crate) command: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Alerts {
+        alerts: Vec<AlertConfig>,
+    }
+
+    #[test]
+    fn alert_settings() {
+        let config = r#"
+            [[alerts]]
+            metric = "cpu"
+            threshold = 90
+            duration = "30s"
+
+            [[alerts]]
+            name = "Root is full"
+            metric = "disk"
+            mount = "/"
+            threshold = 95.5
+            banner = false
+            command = "notify-send full"
+
+            [[alerts]]
+            metric = "battery"
+            threshold = 10
+            below = true
+        "#;
+
+        let generated: Alerts = toml_edit::de::from_str(config).unwrap();
+        let alerts = generated.alerts;
+
+        assert_eq!(alerts[0].metric, AlertMetricConfig::Cpu { core: None });
+        assert_eq!(alerts[0].threshold, 90.0);
+        assert!(
+            matches!(&alerts[0].duration, Some(StringOrNum::String(duration)) if duration == "30s")
+        );
+
+        assert_eq!(alerts[1].name.as_deref(), Some("Root is full"));
+        assert_eq!(
+            alerts[1].metric,
+            AlertMetricConfig::Disk {
+                mount: "/".to_string()
+            }
+        );
+        assert_eq!(alerts[1].banner, Some(false));
+        assert_eq!(alerts[1].command.as_deref(), Some("notify-send full"));
+
+        assert_eq!(
+            alerts[2].metric,
+            AlertMetricConfig::Battery { battery: None }
+        );
+        assert!(alerts[2].below);
+    }
+
+    #[test]
+    fn missing_metric_settings() {
+        let config = r#"
+            [[alerts]]
+            metric = "disk"
+            threshold = 90
+        "#;
+
+        assert!(toml_edit::de::from_str::<Alerts>(config).is_err());
+    }
+}
@@ -10,11 +10,43 @@ pub enum CpuDefault {
     Average,
 }
 
+/// How the CPU widget groups its entries by topology.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CpuGrouping {
+    /// Every logical CPU is listed on its own.
+    #[default]
+    None,
+    /// Group by physical package.
+    #[serde(alias = "package")]
+    Socket,
+    /// Group by NUMA node.
+    #[serde(alias = "numa")]
+    Node,
+    /// Group SMT siblings by their physical core.
+    Core,
+}
+
+impl CpuGrouping {
+    /// The next grouping to cycle to.
+    pub fn next(self) -> Self {
+        match self {
+            CpuGrouping::None => CpuGrouping::Socket,
+            CpuGrouping::Socket => CpuGrouping::Node,
+            CpuGrouping::Node => CpuGrouping::Core,
+            CpuGrouping::Core => CpuGrouping::None,
+        }
+    }
+}
+
 /// Process column settings.
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct CpuConfig {
     #[serde(default)]
     pub default: CpuDefault,
+
+    #[serde(default)]
+    pub grouping: CpuGrouping,
 }
 
 #[cfg(test)]
@@ -76,4 +108,16 @@ mod test {
             CpuDefault::Average => {}
         }
     }
+
+    #[test]
+    fn cpu_grouping() {
+        let generated: CpuConfig = toml_edit::de::from_str("").unwrap();
+        assert_eq!(generated.grouping, CpuGrouping::None);
+
+        let generated: CpuConfig = toml_edit::de::from_str(r#"grouping = "numa""#).unwrap();
+        assert_eq!(generated.grouping, CpuGrouping::Node);
+
+        let generated: CpuConfig = toml_edit::de::from_str(r#"grouping = "core""#).unwrap();
+        assert_eq!(generated.grouping, CpuGrouping::Core);
+    }
 }
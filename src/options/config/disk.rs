@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+use crate::widgets::DiskWidgetColumn;
+
+/// Disk column settings.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DiskConfig {
+    #[serde(default)]
+    pub columns: Vec<DiskWidgetColumn>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::DiskConfig;
+    use crate::widgets::DiskWidgetColumn;
+
+    #[test]
+    fn empty_column_setting() {
+        let generated: DiskConfig = toml_edit::de::from_str("").unwrap();
+        assert!(generated.columns.is_empty());
+    }
+
+    #[test]
+    fn disk_column_settings() {
+        let config = r#"
+            columns = ["Disk", "mount", "R/s", "write", "RIOPS", "wiops", "Await", "busy%", "util%", "queue", "aqu-sz"]
+        "#;
+
+        let generated: DiskConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.columns,
+            vec![
+                DiskWidgetColumn::Disk,
+                DiskWidgetColumn::Mount,
+                DiskWidgetColumn::IoRead,
+                DiskWidgetColumn::IoWrite,
+                DiskWidgetColumn::ReadOps,
+                DiskWidgetColumn::WriteOps,
+                DiskWidgetColumn::Await,
+                DiskWidgetColumn::BusyPercent,
+                DiskWidgetColumn::BusyPercent,
+                DiskWidgetColumn::QueueSize,
+                DiskWidgetColumn::QueueSize,
+            ],
+        );
+
+        let config =
+            r#"columns = ["IUsed", "ifree", "Inodes", "IUse%", "Type", "Options", "Device"]"#;
+        let generated: DiskConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.columns,
+            vec![
+                DiskWidgetColumn::UsedInodes,
+                DiskWidgetColumn::FreeInodes,
+                DiskWidgetColumn::TotalInodes,
+                DiskWidgetColumn::UsedInodesPercent,
+                DiskWidgetColumn::FsType,
+                DiskWidgetColumn::MountOptions,
+                DiskWidgetColumn::Device,
+            ],
+        );
+
+        let config = r#"columns = ["Disk", "iops"]"#;
+        toml_edit::de::from_str::<DiskConfig>(config).expect_err("Should error out!");
+    }
+}
@@ -70,4 +70,58 @@ mod test {
         let generated: ProcessConfig = toml_edit::de::from_str(config).unwrap();
         assert_eq!(generated.columns, vec![ProcWidgetColumn::WritePerSecond; 3]);
     }
+
+    #[test]
+    fn process_memory_column_settings() {
+        let config =
+            r#"columns = ["RSS", "virt", "virtual", "shr", "Shared", "priv", "private", "swap"]"#;
+        let generated: ProcessConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.columns,
+            vec![
+                ProcWidgetColumn::Rss,
+                ProcWidgetColumn::Virtual,
+                ProcWidgetColumn::Virtual,
+                ProcWidgetColumn::Shared,
+                ProcWidgetColumn::Shared,
+                ProcWidgetColumn::Private,
+                ProcWidgetColumn::Private,
+                ProcWidgetColumn::Swap,
+            ],
+        );
+    }
+
+    #[test]
+    fn process_scheduling_column_settings() {
+        let config =
+            r#"columns = ["threads", "Nice", "prio", "policy", "psr", "MinFlt/s", "majflt"]"#;
+        let generated: ProcessConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.columns,
+            vec![
+                ProcWidgetColumn::Threads,
+                ProcWidgetColumn::Nice,
+                ProcWidgetColumn::Priority,
+                ProcWidgetColumn::Policy,
+                ProcWidgetColumn::Processor,
+                ProcWidgetColumn::MinorFaults,
+                ProcWidgetColumn::MajorFaults,
+            ],
+        );
+    }
+
+    #[test]
+    fn process_cgroup_column_settings() {
+        let config = r#"columns = ["cgroup", "Container", "unit", "pod"]"#;
+        let generated: ProcessConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.columns,
+            vec![
+                ProcWidgetColumn::Cgroup,
+                ProcWidgetColumn::Container,
+                ProcWidgetColumn::Unit,
+                ProcWidgetColumn::Unit,
+            ],
+        );
+    }
 }
@@ -0,0 +1,89 @@
+//! Non-interactive output modes, which print collected data to stdout rather than
+//! running the interface.
+
+pub mod ndjson;
+pub mod snapshot;
+
+use std::{
+    io,
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Condvar, Mutex,
+    },
+};
+
+use anyhow::Result;
+
+use self::snapshot::SnapshotOptions;
+use crate::{app::App, create_collection_thread, BottomEvent};
+
+/// How collected data is printed.
+pub enum OutputMode {
+    /// Newline-delimited JSON, with one document per update.
+    Ndjson,
+
+    /// A single process table, printed once.
+    Snapshot(SnapshotOptions),
+
+    /// Nothing is printed, and data is only collected for the exporter.
+    #[cfg(feature = "exporter")]
+    Headless,
+}
+
+/// Runs the given output mode until it is either finished or terminated.
+pub fn run(output_mode: OutputMode, app: &App) -> Result<()> {
+    match output_mode {
+        OutputMode::Ndjson => with_collection_thread(app, |receiver| {
+            ndjson::write_documents(receiver, io::stdout().lock())
+        }),
+        OutputMode::Snapshot(options) => snapshot::print(options, app),
+        #[cfg(feature = "exporter")]
+        OutputMode::Headless => with_collection_thread(app, |receiver| {
+            while let Ok(event) = receiver.recv() {
+                match event {
+                    BottomEvent::Update(data) => {
+                        if let Some(exporter) = &app.exporter {
+                            exporter.update(&data);
+                        }
+                    }
+                    BottomEvent::Terminate => break,
+                    _ => {}
+                }
+            }
+
+            Ok(())
+        }),
+    }
+}
+
+/// Starts the collection thread and passes its events to `f`, stopping the thread once `f`
+/// returns. Ctrl-C sends a [`BottomEvent::Terminate`].
+fn with_collection_thread(
+    app: &App, f: impl FnOnce(&Receiver<BottomEvent>) -> Result<()>,
+) -> Result<()> {
+    let termination_lock = Arc::new(Mutex::new(false));
+    let termination_cvar = Arc::new(Condvar::new());
+
+    let (sender, receiver) = mpsc::channel();
+    let (_collection_thread_ctrl_sender, collection_thread_ctrl_receiver) = mpsc::channel();
+    let _collection_thread = create_collection_thread(
+        sender.clone(),
+        collection_thread_ctrl_receiver,
+        termination_lock.clone(),
+        termination_cvar.clone(),
+        &app.app_config_fields,
+        app.filters.clone(),
+        app.used_widgets,
+    );
+
+    ctrlc::set_handler(move || {
+        let _ = sender.send(BottomEvent::Terminate);
+    })?;
+
+    let result = f(&receiver);
+
+    *termination_lock.lock().unwrap() = true;
+    termination_cvar.notify_all();
+
+    result
+}
@@ -0,0 +1,102 @@
+//! Streams collected data as newline-delimited JSON.
+
+use std::{
+    io::{self, ErrorKind, Write},
+    sync::mpsc::Receiver,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{data_collection::Data, BottomEvent};
+
+/// A single line of output.
+#[derive(Serialize)]
+struct Document<'a> {
+    /// When the data was collected, in milliseconds since the Unix epoch.
+    timestamp: u64,
+    #[serde(flatten)]
+    data: &'a Data,
+}
+
+impl<'a> Document<'a> {
+    fn new(data: &'a Data) -> Self {
+        let timestamp = SystemTime::now()
+            .checked_sub(data.collection_time.elapsed())
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+
+        Self { timestamp, data }
+    }
+}
+
+/// Prints every update to `out` as a line of JSON, until either terminated or `out` is closed.
+pub(super) fn write_documents(receiver: &Receiver<BottomEvent>, mut out: impl Write) -> Result<()> {
+    while let Ok(event) = receiver.recv() {
+        match event {
+            BottomEvent::Update(data) => {
+                match write_document(&mut out, &Document::new(&data)) {
+                    Ok(()) => {}
+                    // Whatever we were piped into has stopped reading (e.g. `head`), so just stop.
+                    Err(err) if err.kind() == ErrorKind::BrokenPipe => break,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            BottomEvent::Terminate => break,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn write_document(out: &mut impl Write, document: &Document<'_>) -> io::Result<()> {
+    serde_json::to_writer(&mut *out, document)?;
+    writeln!(out)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::data_collection::memory::MemHarvest;
+
+    #[test]
+    fn writes_one_document_per_update() {
+        let (sender, receiver) = mpsc::channel();
+
+        let first = Data {
+            memory: Some(MemHarvest {
+                used_bytes: 1,
+                total_bytes: 2,
+                use_percent: Some(50.0),
+            }),
+            ..Default::default()
+        };
+        sender.send(BottomEvent::Update(Box::new(first))).unwrap();
+        sender.send(BottomEvent::Clean).unwrap();
+        sender.send(BottomEvent::Update(Box::default())).unwrap();
+        sender.send(BottomEvent::Terminate).unwrap();
+        sender.send(BottomEvent::Update(Box::default())).unwrap();
+
+        let mut out = Vec::new();
+        write_documents(&receiver, &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert!(first["timestamp"].as_u64().unwrap() > 0);
+        assert_eq!(first["memory"]["used_bytes"], 1);
+        assert_eq!(first["memory"]["total_bytes"], 2);
+        assert!(first["processes"].is_null());
+
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert!(second["memory"].is_null());
+    }
+}
@@ -0,0 +1,402 @@
+//! Prints the process table once, then exits.
+
+use std::io::{self, ErrorKind, Write};
+
+use anyhow::{bail, Result};
+use indexmap::IndexSet;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::{Number, Value};
+
+use crate::{
+    app::{data_farmer::DataCollection, layout_manager::UsedWidgets, App},
+    canvas::{
+        components::data_table::{ColumnHeader, DataTableColumn},
+        styling::CanvasStyling,
+    },
+    data_collection::DataCollector,
+    widgets::{
+        MemUsage, ProcColumn, ProcTableConfig, ProcWidgetColumn, ProcWidgetData, ProcWidgetMode,
+        ProcWidgetState,
+    },
+};
+
+/// The format a snapshot is printed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// An aligned, human-readable table.
+    Text,
+
+    /// Comma-separated values, with a header row.
+    Csv,
+
+    /// A JSON array, with one object per row.
+    Json,
+}
+
+/// What a snapshot shows, matching how the process widget would be set up.
+pub struct SnapshotOptions {
+    pub format: SnapshotFormat,
+
+    /// An optional search query, using the same syntax as the process widget's search.
+    pub query: Option<String>,
+
+    pub mode: ProcWidgetMode,
+    pub table_config: ProcTableConfig,
+    pub columns: Option<IndexSet<ProcWidgetColumn>>,
+}
+
+/// Collects process data once and prints it as a table.
+pub fn print(options: SnapshotOptions, app: &App) -> Result<()> {
+    let mut state = ProcWidgetState::new(
+        &app.app_config_fields,
+        options.mode,
+        options.table_config,
+        &CanvasStyling::default(),
+        &options.columns,
+    );
+
+    if let Some(query) = options.query {
+        state.proc_search.search_state.current_search_query = query;
+        state.update_query();
+
+        let search_state = &state.proc_search.search_state;
+        if search_state.is_invalid_search {
+            bail!(
+                "Invalid query: {}",
+                search_state.error_message.as_deref().unwrap_or_default()
+            );
+        }
+    }
+
+    state.ingest_data(&collect(app));
+
+    match write_snapshot(&state, options.format, io::stdout().lock()) {
+        Err(err) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result.map_err(Into::into),
+    }
+}
+
+/// Harvests process data a single time.
+fn collect(app: &App) -> DataCollection {
+    let mut collector = DataCollector::new(app.filters.clone());
+    collector.set_data_collection(UsedWidgets {
+        use_proc: true,
+        use_gpu: app.used_widgets.use_gpu,
+        ..Default::default()
+    });
+    collector.set_use_current_cpu_total(app.app_config_fields.use_current_cpu_total);
+    collector.set_unnormalized_cpu(app.app_config_fields.unnormalized_cpu);
+
+    // Initializing does an update and then waits a bit, so usage values from the next update
+    // are calculated over a reasonable interval.
+    collector.init();
+    collector.update_data();
+
+    let mut data_collection = DataCollection::default();
+    data_collection.eat_data(Box::new(collector.data));
+    data_collection
+}
+
+fn write_snapshot(
+    state: &ProcWidgetState, format: SnapshotFormat, mut out: impl Write,
+) -> io::Result<()> {
+    let columns = state
+        .table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden)
+        .map(|column| column.inner())
+        .collect::<Vec<_>>();
+    let rows = state.table.data();
+
+    match format {
+        SnapshotFormat::Text => write_text(&mut out, &columns, rows)?,
+        SnapshotFormat::Csv => write_csv(&mut out, &columns, rows)?,
+        SnapshotFormat::Json => write_json(&mut out, &columns, rows)?,
+    }
+
+    out.flush()
+}
+
+fn write_text(
+    out: &mut impl Write, columns: &[&ProcColumn], rows: &[ProcWidgetData],
+) -> io::Result<()> {
+    let cells = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| text_value(row, column))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let headers = columns
+        .iter()
+        .map(|column| column.text())
+        .collect::<Vec<_>>();
+
+    let widths = headers
+        .iter()
+        .enumerate()
+        .map(|(index, header)| {
+            cells
+                .iter()
+                .map(|row| row[index].chars().count())
+                .chain(std::iter::once(header.chars().count()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let mut write_row = |row: &mut dyn Iterator<Item = &str>| -> io::Result<()> {
+        let line = row
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        writeln!(out, "{}", line.trim_end())
+    };
+
+    write_row(&mut headers.iter().map(|header| header.as_ref()))?;
+    for row in &cells {
+        write_row(&mut row.iter().map(String::as_str))?;
+    }
+
+    Ok(())
+}
+
+fn write_csv(
+    out: &mut impl Write, columns: &[&ProcColumn], rows: &[ProcWidgetData],
+) -> io::Result<()> {
+    let headers = columns
+        .iter()
+        .map(|column| csv_field(&column.text()))
+        .collect::<Vec<_>>();
+    writeln!(out, "{}", headers.join(","))?;
+
+    for row in rows {
+        let fields = columns
+            .iter()
+            .map(|column| match raw_value(row, column) {
+                Value::String(value) => csv_field(&value),
+                value => value.to_string(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+
+    Ok(())
+}
+
+fn write_json(
+    out: &mut impl Write, columns: &[&ProcColumn], rows: &[ProcWidgetData],
+) -> io::Result<()> {
+    let rows = rows
+        .iter()
+        .map(|row| JsonRow { columns, row })
+        .collect::<Vec<_>>();
+
+    serde_json::to_writer_pretty(&mut *out, &rows)?;
+    writeln!(out)
+}
+
+/// A row serialized as a JSON object, keeping the keys in column order.
+struct JsonRow<'a> {
+    columns: &'a [&'a ProcColumn],
+    row: &'a ProcWidgetData,
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for column in self.columns {
+            map.serialize_entry(&column.text(), &raw_value(self.row, column))?;
+        }
+        map.end()
+    }
+}
+
+/// The value of a cell as it would be shown in the process widget, but with the full process
+/// state rather than a single character.
+fn text_value(row: &ProcWidgetData, column: &ProcColumn) -> String {
+    match column {
+        ProcColumn::State => row.process_state.clone(),
+        _ => row.to_string(column),
+    }
+}
+
+/// The unformatted value of a cell. Sizes are in bytes, rates are in bytes per second,
+/// and times are in seconds.
+fn raw_value(row: &ProcWidgetData, column: &ProcColumn) -> Value {
+    match column {
+        ProcColumn::CpuPercent => float(row.cpu_usage_percent.into()),
+        ProcColumn::MemoryVal | ProcColumn::MemoryPercent => mem_value(&row.mem_usage),
+        ProcColumn::Rss => row.rss.into(),
+        ProcColumn::Virtual => row.virt.into(),
+        ProcColumn::Shared => row.shared.into(),
+        ProcColumn::Private => row.private.into(),
+        ProcColumn::Swap => row.swap.into(),
+        ProcColumn::Threads => row.threads.into(),
+        ProcColumn::Nice => row.nice.into(),
+        ProcColumn::Priority => row.priority.into(),
+        ProcColumn::Policy => row.policy.as_str().into(),
+        ProcColumn::Processor => row.processor.into(),
+        ProcColumn::MinorFaults => row.minflt.into(),
+        ProcColumn::MajorFaults => row.majflt.into(),
+        ProcColumn::Pid => row.pid.into(),
+        ProcColumn::Count => row.num_similar.into(),
+        ProcColumn::Name | ProcColumn::Command => row.id.as_str().into(),
+        ProcColumn::ReadPerSecond => row.rps.into(),
+        ProcColumn::WritePerSecond => row.wps.into(),
+        ProcColumn::TotalRead => row.total_read.into(),
+        ProcColumn::TotalWrite => row.total_write.into(),
+        ProcColumn::State => row.process_state.as_str().into(),
+        ProcColumn::User => row.user.as_str().into(),
+        ProcColumn::Time => float(row.time.as_secs_f64()),
+        ProcColumn::Cgroup => row.cgroup.as_str().into(),
+        ProcColumn::Container => row.container.as_str().into(),
+        ProcColumn::Unit => row.unit.as_str().into(),
+        #[cfg(feature = "gpu")]
+        ProcColumn::GpuMem | ProcColumn::GpuMemPercent => mem_value(&row.gpu_mem_usage),
+        #[cfg(feature = "gpu")]
+        ProcColumn::GpuUtilPercent => row.gpu_usage.into(),
+    }
+}
+
+fn mem_value(mem_usage: &MemUsage) -> Value {
+    match mem_usage {
+        MemUsage::Percent(percent) => float((*percent).into()),
+        MemUsage::Bytes(bytes) => (*bytes).into(),
+    }
+}
+
+/// Converts a float to a JSON value, rounded to avoid printing noise from `f32` precision.
+fn float(value: f64) -> Value {
+    Number::from_f64((value * 1000.0).round() / 1000.0)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        app::AppConfigFields,
+        data_collection::{processes::ProcessHarvest, Data},
+        Pid,
+    };
+
+    fn process(pid: Pid, name: &str, cpu_usage_percent: f32) -> ProcessHarvest {
+        ProcessHarvest {
+            pid,
+            cpu_usage_percent,
+            mem_usage_bytes: 1024,
+            virtual_bytes: pid as u64 * 1024 * 1024,
+            num_threads: pid as u64 / 10 + 1,
+            nice: if pid == 300 { -5 } else { 0 },
+            name: name.to_string(),
+            command: format!("/usr/bin/{name} --flag"),
+            process_state: ("Running".to_string(), 'R'),
+            user: "root".into(),
+            time: Duration::from_secs(90),
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(query: &str, format: SnapshotFormat) -> String {
+        let columns = [
+            ProcWidgetColumn::PidOrCount,
+            ProcWidgetColumn::ProcNameOrCommand,
+            ProcWidgetColumn::Cpu,
+            ProcWidgetColumn::State,
+        ];
+        let mut state = ProcWidgetState::new(
+            &AppConfigFields::default(),
+            ProcWidgetMode::Normal,
+            ProcTableConfig::default(),
+            &CanvasStyling::default(),
+            &Some(columns.into_iter().collect()),
+        );
+        state.proc_search.search_state.current_search_query = query.to_string();
+        state.update_query();
+
+        let mut data_collection = DataCollection::default();
+        data_collection.eat_data(Box::new(Data {
+            list_of_processes: Some(vec![
+                process(1, "init", 0.5),
+                process(20, "bash, the shell", 12.25),
+                process(300, "cargo", 3.0),
+            ]),
+            ..Default::default()
+        }));
+        state.ingest_data(&data_collection);
+
+        let mut out = Vec::new();
+        write_snapshot(&state, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn text_snapshot() {
+        assert_eq!(
+            snapshot("", SnapshotFormat::Text),
+            "PID  Name             CPU%   State\n\
+             20   bash, the shell  12.2%  Running\n\
+             300  cargo            3.0%   Running\n\
+             1    init             0.5%   Running\n"
+        );
+    }
+
+    #[test]
+    fn csv_snapshot_with_query() {
+        assert_eq!(
+            snapshot("cpu > 1", SnapshotFormat::Csv),
+            "PID,Name,CPU%,State\n\
+             20,\"bash, the shell\",12.25,Running\n\
+             300,cargo,3.0,Running\n"
+        );
+    }
+
+    #[test]
+    fn csv_snapshot_with_memory_query() {
+        assert_eq!(
+            snapshot("virt > 100 mib", SnapshotFormat::Csv),
+            "PID,Name,CPU%,State\n\
+             300,cargo,3.0,Running\n"
+        );
+    }
+
+    #[test]
+    fn csv_snapshot_with_scheduling_query() {
+        assert_eq!(
+            snapshot("threads > 2 and nice >= 0", SnapshotFormat::Csv),
+            "PID,Name,CPU%,State\n\
+             20,\"bash, the shell\",12.25,Running\n"
+        );
+    }
+
+    #[test]
+    fn json_snapshot() {
+        let json: Value = serde_json::from_str(&snapshot("init", SnapshotFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{ "PID": 1, "Name": "init", "CPU%": 0.5, "State": "Running" }])
+        );
+    }
+}
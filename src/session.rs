@@ -0,0 +1,294 @@
+//! Recording collected data to a session file, so it can be replayed later.
+//!
+//! A session file starts with a JSON header on its own line. Every update after it is stored as
+//! a frame: how many milliseconds after the first update it was collected and the length of its
+//! data, both little-endian, followed by the update as deflate-compressed JSON. Frames are only
+//! indexed when a session is loaded, and are read from the file as they are needed.
+
+pub mod replay;
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use serde::{Deserialize, Serialize};
+
+use crate::data_collection::Data;
+
+/// The version of the session format, which should be bumped on incompatible changes.
+const SESSION_VERSION: u32 = 1;
+
+/// The header is a single short line, so anything longer is not a session.
+const MAX_HEADER_LENGTH: u64 = 1024;
+
+/// The size of the fields that come before each frame's data.
+const FRAME_PREFIX_LENGTH: u64 = 12;
+
+/// The first line of a session file.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    bottom_session: u32,
+}
+
+/// Where a single update is in a session file.
+struct FrameIndex {
+    /// How long after the first update this was collected.
+    elapsed: Duration,
+    offset: u64,
+    length: u32,
+}
+
+/// Anything a session can be read from.
+trait Source: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> Source for T {}
+
+/// A recorded session. Only where each update is gets kept in memory; the updates themselves are
+/// read when they are needed.
+pub struct Session {
+    source: BufReader<Box<dyn Source>>,
+    frames: Vec<FrameIndex>,
+}
+
+impl Session {
+    /// Loads a session from a file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("Unable to open the session file '{}'.", path.display()))?;
+
+        Self::read(file)
+            .with_context(|| format!("Unable to read the session file '{}'.", path.display()))
+    }
+
+    /// Reads a session. A partially written last frame, such as from a recording that was
+    /// interrupted, is ignored.
+    fn read(source: impl Read + Seek + Send + 'static) -> Result<Self> {
+        let mut source = BufReader::new(Box::new(source) as Box<dyn Source>);
+        let end = source.seek(SeekFrom::End(0))?;
+        source.rewind()?;
+
+        let mut line = String::new();
+        source
+            .by_ref()
+            .take(MAX_HEADER_LENGTH)
+            .read_line(&mut line)?;
+        match serde_json::from_str::<Header>(&line) {
+            Ok(header) if header.bottom_session == SESSION_VERSION => {}
+            Ok(header) => bail!(
+                "Unsupported session version {}, expected {SESSION_VERSION}.",
+                header.bottom_session
+            ),
+            Err(_) => bail!("This is not a session recorded by bottom."),
+        }
+
+        let mut frames: Vec<FrameIndex> = Vec::new();
+        let mut prefix = [0; FRAME_PREFIX_LENGTH as usize];
+        loop {
+            let offset = source.stream_position()? + FRAME_PREFIX_LENGTH;
+            if offset > end {
+                break;
+            }
+
+            source.read_exact(&mut prefix)?;
+            let (elapsed_ms, length) = prefix.split_at(8);
+            let elapsed_ms = u64::from_le_bytes(elapsed_ms.try_into()?);
+            let length = u32::from_le_bytes(length.try_into()?);
+
+            if offset + u64::from(length) > end {
+                break;
+            }
+            source.seek_relative(i64::from(length))?;
+
+            // Keep frames in order, even if the clock went backwards while recording.
+            let elapsed = Duration::from_millis(elapsed_ms)
+                .max(frames.last().map(|last| last.elapsed).unwrap_or_default());
+
+            frames.push(FrameIndex {
+                elapsed,
+                offset,
+                length,
+            });
+        }
+
+        if frames.is_empty() {
+            bail!("The session does not contain any recorded data.");
+        }
+
+        Ok(Self { source, frames })
+    }
+
+    /// Reads the update at the given index.
+    fn frame(&mut self, index: usize) -> Result<Data> {
+        let frame = &self.frames[index];
+        self.source.seek(SeekFrom::Start(frame.offset))?;
+
+        let data = DeflateDecoder::new(self.source.by_ref().take(u64::from(frame.length)));
+        serde_json::from_reader(data).with_context(|| format!("Invalid data in update {index}."))
+    }
+
+    /// How long the session lasts, from the first update to the last.
+    pub fn duration(&self) -> Duration {
+        self.frames
+            .last()
+            .map(|frame| frame.elapsed)
+            .unwrap_or_default()
+    }
+}
+
+/// Writes every update it is given to a session.
+pub struct Recorder<W: Write = BufWriter<File>> {
+    out: W,
+    first_collection_time: Option<Instant>,
+}
+
+impl Recorder {
+    /// Creates a session file at the given path, replacing it if it already exists.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Recorder::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Starts a session, writing its header to `out`.
+    pub fn new(mut out: W) -> io::Result<Self> {
+        serde_json::to_writer(
+            &mut out,
+            &Header {
+                bottom_session: SESSION_VERSION,
+            },
+        )?;
+        writeln!(out)?;
+        out.flush()?;
+
+        Ok(Self {
+            out,
+            first_collection_time: None,
+        })
+    }
+
+    /// Adds an update to the session. Each update is flushed right away, so a session that is
+    /// interrupted can still be replayed.
+    pub fn record(&mut self, data: &Data) -> io::Result<()> {
+        let first_collection_time = *self
+            .first_collection_time
+            .get_or_insert(data.collection_time);
+        let elapsed = data
+            .collection_time
+            .saturating_duration_since(first_collection_time);
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, data)?;
+        let compressed = encoder.finish()?;
+        let length = u32::try_from(compressed.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "update is too large"))?;
+
+        self.out
+            .write_all(&(elapsed.as_millis() as u64).to_le_bytes())?;
+        self.out.write_all(&length.to_le_bytes())?;
+        self.out.write_all(&compressed)?;
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_collection::memory::MemHarvest;
+
+    fn data(collection_time: Instant, used_bytes: u64) -> Data {
+        Data {
+            collection_time,
+            memory: Some(MemHarvest {
+                used_bytes,
+                total_bytes: 100,
+                use_percent: Some(used_bytes as f64),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn record() -> Vec<u8> {
+        let start = Instant::now();
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+
+        recorder.record(&data(start, 10)).unwrap();
+        recorder
+            .record(&data(start + Duration::from_millis(1500), 20))
+            .unwrap();
+
+        recorder.out
+    }
+
+    fn read(session: Vec<u8>) -> Result<Session> {
+        Session::read(io::Cursor::new(session))
+    }
+
+    #[test]
+    fn replays_what_was_recorded() {
+        let mut session = read(record()).unwrap();
+
+        assert_eq!(session.frames.len(), 2);
+        assert_eq!(session.frames[0].elapsed, Duration::ZERO);
+        assert_eq!(session.frames[1].elapsed, Duration::from_millis(1500));
+        assert_eq!(session.duration(), Duration::from_millis(1500));
+
+        let data = session.frame(1).unwrap();
+        let memory = data.memory.as_ref().unwrap();
+        assert_eq!(memory.used_bytes, 20);
+        assert_eq!(memory.total_bytes, 100);
+
+        // Frames can be read in any order.
+        let data = session.frame(0).unwrap();
+        assert_eq!(data.memory.unwrap().used_bytes, 10);
+    }
+
+    #[test]
+    fn compresses_updates() {
+        let start = Instant::now();
+        let data = Data {
+            collection_time: start,
+            list_of_processes: Some(vec![Default::default(); 100]),
+            ..Default::default()
+        };
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+        recorder.record(&data).unwrap();
+
+        assert!(recorder.out.len() * 10 < serde_json::to_vec(&data).unwrap().len());
+    }
+
+    #[test]
+    fn ignores_an_interrupted_last_frame() {
+        let complete = record();
+
+        let mut session = complete.clone();
+        session.extend_from_slice(&3000u64.to_le_bytes());
+        assert_eq!(read(session).unwrap().frames.len(), 2);
+
+        let mut session = complete.clone();
+        session.truncate(session.len() - 1);
+        assert_eq!(read(session).unwrap().frames.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_sessions() {
+        assert!(read(b"".to_vec()).is_err());
+        assert!(read(b"{\"timestamp\":1}\n".to_vec()).is_err());
+        assert!(read(b"{\"bottom_session\":1}\n".to_vec()).is_err());
+        assert!(read(b"{\"bottom_session\":99}\n{}\n".to_vec()).is_err());
+        assert!(read(vec![b'a'; 4096]).is_err());
+
+        let mut session = record();
+        session.extend_from_slice(&3000u64.to_le_bytes());
+        session.extend_from_slice(&8u32.to_le_bytes());
+        session.extend_from_slice(b"garbage\n");
+
+        let mut session = read(session).unwrap();
+        assert_eq!(session.frames.len(), 3);
+        assert!(session.frame(2).is_err());
+    }
+}
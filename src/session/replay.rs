@@ -0,0 +1,382 @@
+//! Plays back a recorded session in place of the collection thread.
+
+use std::{
+    sync::{
+        mpsc::{Receiver, RecvTimeoutError, SendError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use super::Session;
+use crate::{data_collection::Data, BottomEvent, CollectionThreadEvent};
+
+/// The playback speeds that can be switched between.
+const SPEEDS: [f64; 7] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0];
+
+/// The index of the default playback speed in [`SPEEDS`].
+const DEFAULT_SPEED_INDEX: usize = 2;
+
+/// How far a seek moves.
+pub const SEEK_STEP: Duration = Duration::from_secs(10);
+
+/// How far a long seek moves.
+pub const LONG_SEEK_STEP: Duration = Duration::from_secs(60);
+
+/// Events sent from the replay thread to the main thread.
+#[derive(Debug)]
+pub enum ReplayEvent {
+    /// Playback moved to a different point in the session, so any existing data should be
+    /// replaced with the data leading up to it.
+    Seeked(Vec<Data>),
+
+    /// The position of the update that follows.
+    Position(Duration),
+}
+
+/// The state of a replay, as shown in the interface.
+pub struct ReplayState {
+    pub position: Duration,
+    pub duration: Duration,
+    pub is_paused: bool,
+    speed_index: usize,
+}
+
+impl ReplayState {
+    pub fn new(session: &Session) -> Self {
+        Self {
+            position: Duration::ZERO,
+            duration: session.duration(),
+            is_paused: false,
+            speed_index: DEFAULT_SPEED_INDEX,
+        }
+    }
+
+    /// The current playback speed, as a multiple of the recorded speed.
+    pub fn speed(&self) -> f64 {
+        SPEEDS[self.speed_index]
+    }
+
+    /// Doubles the playback speed, up to a limit. Returns whether the speed changed.
+    pub fn speed_up(&mut self) -> bool {
+        if self.speed_index + 1 < SPEEDS.len() {
+            self.speed_index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Halves the playback speed, down to a limit. Returns whether the speed changed.
+    pub fn slow_down(&mut self) -> bool {
+        if self.speed_index > 0 {
+            self.speed_index -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether playback has reached the end of the session.
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.duration
+    }
+}
+
+/// Sends the recorded updates of a session as if they were being collected, while also listening
+/// for playback controls. Any history needed to fill the graphs after a seek is sent all at once.
+pub fn create_replay_thread(
+    sender: Sender<BottomEvent>, control_receiver: Receiver<CollectionThreadEvent>,
+    termination_lock: Arc<Mutex<bool>>, session: Session, retention_ms: u64,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut player = Player::new(session, Duration::from_millis(retention_ms));
+
+        if player.show(0, &sender).is_err() {
+            return;
+        }
+
+        loop {
+            if let Ok(is_terminated) = termination_lock.try_lock() {
+                if *is_terminated {
+                    drop(is_terminated);
+                    break;
+                }
+            }
+
+            // Wait for either the next update to be due, or for a control event.
+            let message = match player.next_due() {
+                Some(due) => {
+                    control_receiver.recv_timeout(due.saturating_duration_since(Instant::now()))
+                }
+                None => control_receiver
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            let result = match message {
+                Ok(event) => player.handle(event, &sender),
+                Err(RecvTimeoutError::Timeout) => player.show(player.current + 1, &sender),
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+
+            if result.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+struct Player {
+    session: Session,
+    retention: Duration,
+
+    /// The index of the frame that was last shown.
+    current: usize,
+    is_paused: bool,
+    speed: f64,
+
+    /// When the last frame was shown, in real time.
+    last_shown: Instant,
+
+    /// The collection time given to the last frame that was shown. This only ever moves forwards,
+    /// even when seeking backwards, so the data always looks like it's in order.
+    clock: Instant,
+}
+
+impl Player {
+    fn new(session: Session, retention: Duration) -> Self {
+        let now = Instant::now();
+
+        Self {
+            session,
+            retention,
+            current: 0,
+            is_paused: false,
+            speed: SPEEDS[DEFAULT_SPEED_INDEX],
+            last_shown: now,
+            clock: now,
+        }
+    }
+
+    /// When the next frame should be shown, if playing.
+    fn next_due(&self) -> Option<Instant> {
+        if self.is_paused {
+            return None;
+        }
+
+        let next = self.session.frames.get(self.current + 1)?;
+        let gap = next.elapsed - self.position();
+
+        Some(self.last_shown + gap.div_f64(self.speed))
+    }
+
+    fn handle(
+        &mut self, event: CollectionThreadEvent, sender: &Sender<BottomEvent>,
+    ) -> Result<(), SendError<BottomEvent>> {
+        match event {
+            CollectionThreadEvent::Reset => self.seek(self.position(), sender)?,
+            CollectionThreadEvent::SetPaused(is_paused) => {
+                self.is_paused = is_paused;
+                self.last_shown = Instant::now();
+            }
+            CollectionThreadEvent::SetSpeed(speed) => self.speed = speed,
+            CollectionThreadEvent::SeekForward(amount) => {
+                self.seek(self.position().saturating_add(amount), sender)?
+            }
+            CollectionThreadEvent::SeekBackward(amount) => {
+                self.seek(self.position().saturating_sub(amount), sender)?
+            }
+            CollectionThreadEvent::SetCollectThreads(_) => {}
+        }
+
+        Ok(())
+    }
+
+    fn position(&self) -> Duration {
+        self.session.frames[self.current].elapsed
+    }
+
+    /// Sends the frame at the given index as an update collected at `collection_time`. A frame
+    /// that can't be read is skipped, as if nothing was collected then.
+    fn send_frame(
+        &mut self, index: usize, collection_time: Instant, sender: &Sender<BottomEvent>,
+    ) -> Result<(), SendError<BottomEvent>> {
+        match self.session.frame(index) {
+            Ok(data) => sender.send(BottomEvent::Update(Box::new(Data {
+                collection_time,
+                ..data
+            }))),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Shows the frame at the given index.
+    fn show(
+        &mut self, index: usize, sender: &Sender<BottomEvent>,
+    ) -> Result<(), SendError<BottomEvent>> {
+        let Some(elapsed) = self.session.frames.get(index).map(|frame| frame.elapsed) else {
+            return Ok(());
+        };
+
+        if index > 0 {
+            self.clock += elapsed.saturating_sub(self.position());
+        }
+
+        self.current = index;
+        self.last_shown = Instant::now();
+
+        sender.send(BottomEvent::Replay(ReplayEvent::Position(elapsed)))?;
+        self.send_frame(index, self.clock, sender)
+    }
+
+    /// Jumps to the last frame at or before `target`, along with any history that is still
+    /// within the retention period.
+    fn seek(
+        &mut self, target: Duration, sender: &Sender<BottomEvent>,
+    ) -> Result<(), SendError<BottomEvent>> {
+        let frames = &self.session.frames;
+        let index = frames
+            .partition_point(|frame| frame.elapsed <= target)
+            .saturating_sub(1);
+        let elapsed = frames[index].elapsed;
+        let start =
+            frames.partition_point(|frame| frame.elapsed.saturating_add(self.retention) < elapsed);
+
+        let history_start = frames[start].elapsed;
+        let history = (start..index)
+            .filter_map(|history_index| {
+                let collection_time =
+                    self.clock + (self.session.frames[history_index].elapsed - history_start);
+
+                self.session.frame(history_index).ok().map(|data| Data {
+                    collection_time,
+                    ..data
+                })
+            })
+            .collect();
+
+        sender.send(BottomEvent::Replay(ReplayEvent::Seeked(history)))?;
+
+        // Start the shown frame from the end of the history, rather than from the old position.
+        self.clock += elapsed - history_start;
+        self.current = index;
+        self.last_shown = Instant::now();
+
+        sender.send(BottomEvent::Replay(ReplayEvent::Position(elapsed)))?;
+        self.send_frame(index, self.clock, sender)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{io::Cursor, sync::mpsc};
+
+    use super::*;
+    use crate::session::Recorder;
+
+    fn player(seconds: &[u64], retention: Duration) -> Player {
+        let start = Instant::now();
+        let mut recorder = Recorder::new(Vec::new()).unwrap();
+        for &second in seconds {
+            recorder
+                .record(&Data {
+                    collection_time: start + Duration::from_secs(second),
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+
+        Player::new(Session::read(Cursor::new(recorder.out)).unwrap(), retention)
+    }
+
+    /// Returns the positions sent, and the size of any history sent after a seek.
+    fn events(receiver: &Receiver<BottomEvent>) -> Vec<(Option<usize>, Duration)> {
+        let mut events = Vec::new();
+        let mut history = None;
+
+        while let Ok(event) = receiver.try_recv() {
+            match event {
+                BottomEvent::Replay(ReplayEvent::Seeked(data)) => history = Some(data.len()),
+                BottomEvent::Replay(ReplayEvent::Position(position)) => {
+                    events.push((history.take(), position))
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    #[test]
+    fn plays_frames_in_order() {
+        let (sender, receiver) = mpsc::channel();
+        let mut player = player(&[0, 1, 3], Duration::from_secs(600));
+
+        player.show(0, &sender).unwrap();
+        player.show(1, &sender).unwrap();
+        player.show(2, &sender).unwrap();
+        player.show(3, &sender).unwrap();
+
+        assert_eq!(
+            events(&receiver),
+            vec![
+                (None, Duration::from_secs(0)),
+                (None, Duration::from_secs(1)),
+                (None, Duration::from_secs(3)),
+            ]
+        );
+        assert!(player.next_due().is_none());
+    }
+
+    #[test]
+    fn waits_according_to_speed() {
+        let mut player = player(&[0, 4], Duration::from_secs(600));
+        let start = player.last_shown;
+
+        assert_eq!(player.next_due(), Some(start + Duration::from_secs(4)));
+
+        player.speed = 2.0;
+        assert_eq!(player.next_due(), Some(start + Duration::from_secs(2)));
+
+        player.is_paused = true;
+        assert_eq!(player.next_due(), None);
+    }
+
+    #[test]
+    fn seeks_with_history() {
+        let (sender, receiver) = mpsc::channel();
+        let mut player = player(&[0, 1, 2, 3, 4, 5, 6], Duration::from_secs(2));
+
+        player
+            .handle(
+                CollectionThreadEvent::SeekForward(Duration::from_secs(5)),
+                &sender,
+            )
+            .unwrap();
+        player
+            .handle(
+                CollectionThreadEvent::SeekForward(Duration::from_secs(60)),
+                &sender,
+            )
+            .unwrap();
+        let clock = player.clock;
+        player
+            .handle(
+                CollectionThreadEvent::SeekBackward(Duration::from_secs(60)),
+                &sender,
+            )
+            .unwrap();
+
+        assert_eq!(
+            events(&receiver),
+            vec![
+                (Some(2), Duration::from_secs(5)),
+                (Some(2), Duration::from_secs(6)),
+                (Some(0), Duration::from_secs(0)),
+            ]
+        );
+        assert!(player.clock >= clock);
+    }
+}
@@ -1,16 +1,26 @@
 pub mod battery_info;
+pub mod cgroup_table;
 pub mod cpu_graph;
+pub mod disk_io_graph;
 pub mod disk_table;
 pub mod mem_graph;
+pub mod mem_table;
 pub mod net_graph;
+pub mod net_table;
+pub mod pressure_graph;
 pub mod process_table;
 pub mod temperature_table;
 
 pub use battery_info::*;
+pub use cgroup_table::*;
 pub use cpu_graph::*;
+pub use disk_io_graph::*;
 pub use disk_table::*;
 pub use mem_graph::*;
+pub use mem_table::*;
 pub use net_graph::*;
+pub use net_table::*;
+pub use pressure_graph::*;
 pub use process_table::*;
 pub use temperature_table::*;
 use tui::{layout::Rect, Frame};
@@ -0,0 +1,328 @@
+use std::{borrow::Cow, cmp::max, num::NonZeroU16};
+
+use kstring::KString;
+use tui::text::Text;
+
+use crate::{
+    app::{
+        query::{ColumnKind, QueryColumn, QueryRow},
+        AppConfigFields, SearchState,
+    },
+    canvas::{
+        components::data_table::{
+            ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
+            SortColumn, SortDataTable, SortDataTableProps, SortOrder, SortsRow,
+        },
+        styling::CanvasStyling,
+    },
+    data_conversion::{binary_byte_string, dec_bytes_per_second_string},
+    utils::general::{sort_partial_fn, truncate_to_text},
+};
+
+#[derive(Clone, Debug)]
+pub struct CgroupWidgetData {
+    pub path: KString,
+    pub cpu_usage_percent: f64,
+    pub memory_bytes: Option<u64>,
+    pub memory_max_bytes: Option<u64>,
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+    pub pids: Option<u64>,
+}
+
+impl CgroupWidgetData {
+    pub fn memory(&self) -> KString {
+        match self.memory_bytes {
+            Some(bytes) => binary_byte_string(bytes).into(),
+            None => "N/A".into(),
+        }
+    }
+
+    pub fn memory_max(&self) -> KString {
+        match self.memory_max_bytes {
+            Some(bytes) => binary_byte_string(bytes).into(),
+            None => "max".into(),
+        }
+    }
+
+    /// The memory used as a percentage of the limit, if there is one.
+    pub fn memory_percent(&self) -> Option<f64> {
+        match (self.memory_bytes, self.memory_max_bytes) {
+            (Some(used), Some(max)) if max > 0 => Some(used as f64 / max as f64 * 100_f64),
+            _ => None,
+        }
+    }
+
+    pub fn memory_percent_string(&self) -> KString {
+        match self.memory_percent() {
+            Some(val) => format!("{val:.1}%").into(),
+            None => "N/A".into(),
+        }
+    }
+
+    pub fn pids_string(&self) -> KString {
+        match self.pids {
+            Some(pids) => pids.to_string().into(),
+            None => "N/A".into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CgroupWidgetColumn {
+    Cgroup,
+    Cpu,
+    Mem,
+    MemMax,
+    MemPercent,
+    IoRead,
+    IoWrite,
+    Pids,
+}
+
+impl ColumnHeader for CgroupWidgetColumn {
+    fn text(&self) -> Cow<'static, str> {
+        match self {
+            CgroupWidgetColumn::Cgroup => "Cgroup(n)",
+            CgroupWidgetColumn::Cpu => "CPU%(c)",
+            CgroupWidgetColumn::Mem => "Mem(m)",
+            CgroupWidgetColumn::MemMax => "Limit",
+            CgroupWidgetColumn::MemPercent => "Mem%(p)",
+            CgroupWidgetColumn::IoRead => "R/s(r)",
+            CgroupWidgetColumn::IoWrite => "W/s(w)",
+            CgroupWidgetColumn::Pids => "PIDs",
+        }
+        .into()
+    }
+}
+
+impl QueryRow for CgroupWidgetData {
+    type Column = CgroupWidgetColumn;
+
+    const QUERY_COLUMNS: &'static [QueryColumn<CgroupWidgetColumn>] = &[
+        QueryColumn::new(
+            &["cgroup", "path"],
+            CgroupWidgetColumn::Cgroup,
+            ColumnKind::Text,
+        ),
+        QueryColumn::new(
+            &["cpu%", "cpu"],
+            CgroupWidgetColumn::Cpu,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["mem", "memory"],
+            CgroupWidgetColumn::Mem,
+            ColumnKind::Bytes,
+        ),
+        QueryColumn::new(&["limit"], CgroupWidgetColumn::MemMax, ColumnKind::Bytes),
+        QueryColumn::new(
+            &["mem%"],
+            CgroupWidgetColumn::MemPercent,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["r/s", "read"],
+            CgroupWidgetColumn::IoRead,
+            ColumnKind::Bytes,
+        ),
+        QueryColumn::new(
+            &["w/s", "write"],
+            CgroupWidgetColumn::IoWrite,
+            ColumnKind::Bytes,
+        ),
+        QueryColumn::new(&["pids"], CgroupWidgetColumn::Pids, ColumnKind::Number),
+    ];
+
+    const NAME_COLUMNS: &'static [CgroupWidgetColumn] = &[CgroupWidgetColumn::Cgroup];
+
+    fn text(&self, column: &CgroupWidgetColumn) -> Cow<'_, str> {
+        match column {
+            CgroupWidgetColumn::Cgroup => self.path.as_str().into(),
+            _ => "".into(),
+        }
+    }
+
+    fn value(&self, column: &CgroupWidgetColumn) -> Option<f64> {
+        match column {
+            CgroupWidgetColumn::Cgroup => None,
+            CgroupWidgetColumn::Cpu => Some(self.cpu_usage_percent),
+            CgroupWidgetColumn::Mem => self.memory_bytes.map(|bytes| bytes as f64),
+            CgroupWidgetColumn::MemMax => self.memory_max_bytes.map(|bytes| bytes as f64),
+            CgroupWidgetColumn::MemPercent => self.memory_percent(),
+            CgroupWidgetColumn::IoRead => Some(self.read_bytes_per_sec as f64),
+            CgroupWidgetColumn::IoWrite => Some(self.write_bytes_per_sec as f64),
+            CgroupWidgetColumn::Pids => self.pids.map(|pids| pids as f64),
+        }
+    }
+}
+
+impl DataToCell<CgroupWidgetColumn> for CgroupWidgetData {
+    fn to_cell(
+        &self, column: &CgroupWidgetColumn, calculated_width: NonZeroU16,
+    ) -> Option<Text<'_>> {
+        let calculated_width = calculated_width.get();
+        let text = match column {
+            CgroupWidgetColumn::Cgroup => truncate_to_text(&self.path, calculated_width),
+            CgroupWidgetColumn::Cpu => {
+                truncate_to_text(&format!("{:.1}%", self.cpu_usage_percent), calculated_width)
+            }
+            CgroupWidgetColumn::Mem => truncate_to_text(&self.memory(), calculated_width),
+            CgroupWidgetColumn::MemMax => truncate_to_text(&self.memory_max(), calculated_width),
+            CgroupWidgetColumn::MemPercent => {
+                truncate_to_text(&self.memory_percent_string(), calculated_width)
+            }
+            CgroupWidgetColumn::IoRead => truncate_to_text(
+                &dec_bytes_per_second_string(self.read_bytes_per_sec),
+                calculated_width,
+            ),
+            CgroupWidgetColumn::IoWrite => truncate_to_text(
+                &dec_bytes_per_second_string(self.write_bytes_per_sec),
+                calculated_width,
+            ),
+            CgroupWidgetColumn::Pids => truncate_to_text(&self.pids_string(), calculated_width),
+        };
+
+        Some(text)
+    }
+
+    fn column_widths<C: DataTableColumn<CgroupWidgetColumn>>(
+        data: &[Self], _columns: &[C],
+    ) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        let mut widths = vec![0; 8];
+
+        data.iter().for_each(|row| {
+            widths[0] = max(widths[0], row.path.len() as u16);
+        });
+
+        widths
+    }
+}
+
+impl SortsRow for CgroupWidgetColumn {
+    type DataType = CgroupWidgetData;
+
+    fn sort_data(&self, data: &mut [Self::DataType], descending: bool) {
+        match self {
+            CgroupWidgetColumn::Cgroup => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.path, &b.path));
+            }
+            CgroupWidgetColumn::Cpu => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.cpu_usage_percent, b.cpu_usage_percent)
+                });
+            }
+            CgroupWidgetColumn::Mem => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.memory_bytes, b.memory_bytes));
+            }
+            CgroupWidgetColumn::MemMax => {
+                // No limit sorts as the largest limit.
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(
+                        a.memory_max_bytes.unwrap_or(u64::MAX),
+                        b.memory_max_bytes.unwrap_or(u64::MAX),
+                    )
+                });
+            }
+            CgroupWidgetColumn::MemPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.memory_percent(), b.memory_percent())
+                });
+            }
+            CgroupWidgetColumn::IoRead => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.read_bytes_per_sec, b.read_bytes_per_sec)
+                });
+            }
+            CgroupWidgetColumn::IoWrite => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.write_bytes_per_sec, b.write_bytes_per_sec)
+                });
+            }
+            CgroupWidgetColumn::Pids => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.pids, b.pids));
+            }
+        }
+    }
+}
+
+pub struct CgroupTableWidget {
+    pub table: SortDataTable<CgroupWidgetData, CgroupWidgetColumn>,
+    pub force_update_data: bool,
+    pub search: SearchState,
+}
+
+impl CgroupTableWidget {
+    pub fn new(config: &AppConfigFields, colours: &CanvasStyling) -> Self {
+        let columns = [
+            SortColumn::soft(CgroupWidgetColumn::Cgroup, Some(0.4)),
+            SortColumn::hard(CgroupWidgetColumn::Cpu, 8).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::Mem, 8).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::MemMax, 8).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::MemPercent, 8).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::IoRead, 10).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::IoWrite, 10).default_descending(),
+            SortColumn::hard(CgroupWidgetColumn::Pids, 6).default_descending(),
+        ];
+
+        let props = SortDataTableProps {
+            inner: DataTableProps {
+                title: Some(" Cgroups ".into()),
+                table_gap: config.table_gap,
+                left_to_right: true,
+                is_basic: config.use_basic_mode,
+                show_table_scroll_position: config.show_table_scroll_position,
+                show_current_entry_when_unfocused: false,
+            },
+            sort_index: 0,
+            order: SortOrder::Ascending,
+        };
+
+        let styling = DataTableStyling::from_colours(colours);
+
+        Self {
+            table: SortDataTable::new_sortable(columns, props, styling),
+            force_update_data: false,
+            search: SearchState::default(),
+        }
+    }
+
+    /// Forces an update of the data stored.
+    #[inline]
+    pub fn force_data_update(&mut self) {
+        self.force_update_data = true;
+    }
+
+    /// Parses the search again after it changes, and filters the table by it.
+    pub fn update_query(&mut self) {
+        self.search.update_table_query::<CgroupWidgetData>();
+        self.table.to_first();
+        self.force_data_update();
+    }
+
+    pub fn ingest_data(&mut self, data: &[CgroupWidgetData]) {
+        let mut data: Vec<_> = match self.search.query() {
+            Some(query) => data
+                .iter()
+                .filter(|cgroup| query.check_row(*cgroup))
+                .cloned()
+                .collect(),
+            None => data.to_vec(),
+        };
+        if let Some(column) = self.table.columns.get(self.table.sort_index()) {
+            column.sort_by(&mut data, self.table.order());
+        }
+        self.table.set_data(data);
+    }
+
+    /// Sorts by the given column.
+    pub fn select_column(&mut self, column: CgroupWidgetColumn) {
+        if let Some(index) = self.table.columns.iter().position(|c| *c.inner() == column) {
+            self.table.set_sort_index(index);
+            self.force_data_update();
+        }
+    }
+}
@@ -1,21 +1,34 @@
-use std::{borrow::Cow, num::NonZeroU16, time::Instant};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashSet},
+    num::NonZeroU16,
+    time::Instant,
+};
 
 use concat_string::concat_string;
 use tui::{style::Style, text::Text, widgets::Row};
 
 use crate::{
-    app::AppConfigFields,
+    app::{
+        query::{ColumnKind, QueryColumn, QueryRow},
+        AppConfigFields, SearchState,
+    },
     canvas::{
-        components::data_table::{
-            Column, ColumnHeader, DataTable, DataTableColumn, DataTableProps, DataTableStyling,
-            DataToCell,
+        components::{
+            data_table::{
+                Column, ColumnHeader, DataTable, DataTableColumn, DataTableProps, DataTableStyling,
+                DataToCell,
+            },
+            time_chart::Point,
         },
         styling::CanvasStyling,
         Painter,
     },
-    data_collection::cpu::CpuDataType,
-    data_conversion::CpuWidgetData,
-    options::config::cpu::CpuDefault,
+    data_collection::cpu::{
+        average_frequency, CpuBreakdown, CpuDataType, CpuFrequency, CpuTopology,
+    },
+    data_conversion::{frequency_string, CpuWidgetData},
+    options::config::cpu::{CpuDefault, CpuGrouping},
     utils::general::truncate_to_text,
 };
 
@@ -45,6 +58,18 @@ impl CpuWidgetStyling {
 pub enum CpuWidgetColumn {
     CPU,
     Use,
+    User,
+    Nice,
+    System,
+    IoWait,
+    Irq,
+    SoftIrq,
+    Steal,
+    Guest,
+    Freq,
+    MinFreq,
+    MaxFreq,
+    Governor,
 }
 
 impl ColumnHeader for CpuWidgetColumn {
@@ -52,15 +77,263 @@ impl ColumnHeader for CpuWidgetColumn {
         match self {
             CpuWidgetColumn::CPU => "CPU".into(),
             CpuWidgetColumn::Use => "Use".into(),
+            CpuWidgetColumn::User => "User".into(),
+            CpuWidgetColumn::Nice => "Nice".into(),
+            CpuWidgetColumn::System => "Sys".into(),
+            CpuWidgetColumn::IoWait => "IOW".into(),
+            CpuWidgetColumn::Irq => "IRQ".into(),
+            CpuWidgetColumn::SoftIrq => "SIRQ".into(),
+            CpuWidgetColumn::Steal => "Steal".into(),
+            CpuWidgetColumn::Guest => "Guest".into(),
+            CpuWidgetColumn::Freq => "Freq".into(),
+            CpuWidgetColumn::MinFreq => "Min".into(),
+            CpuWidgetColumn::MaxFreq => "Max".into(),
+            CpuWidgetColumn::Governor => "Governor".into(),
+        }
+    }
+}
+
+impl CpuWidgetColumn {
+    /// Returns the value of this column from a breakdown, if this is a breakdown column.
+    fn breakdown_value(&self, breakdown: &CpuBreakdown) -> Option<f64> {
+        match self {
+            CpuWidgetColumn::CPU
+            | CpuWidgetColumn::Use
+            | CpuWidgetColumn::Freq
+            | CpuWidgetColumn::MinFreq
+            | CpuWidgetColumn::MaxFreq
+            | CpuWidgetColumn::Governor => None,
+            CpuWidgetColumn::User => Some(breakdown.user),
+            CpuWidgetColumn::Nice => Some(breakdown.nice),
+            CpuWidgetColumn::System => Some(breakdown.system),
+            CpuWidgetColumn::IoWait => Some(breakdown.iowait),
+            CpuWidgetColumn::Irq => Some(breakdown.irq),
+            CpuWidgetColumn::SoftIrq => Some(breakdown.softirq),
+            CpuWidgetColumn::Steal => Some(breakdown.steal),
+            CpuWidgetColumn::Guest => Some(breakdown.guest),
+        }
+    }
+
+    /// Returns the text of this column from a frequency, if this is a frequency column.
+    fn frequency_text(&self, frequency: &CpuFrequency) -> Option<String> {
+        match self {
+            CpuWidgetColumn::Freq => Some(frequency_string(frequency.current_mhz)),
+            CpuWidgetColumn::MinFreq => frequency.min_mhz.map(frequency_string),
+            CpuWidgetColumn::MaxFreq => frequency.max_mhz.map(frequency_string),
+            CpuWidgetColumn::Governor => frequency.governor.clone(),
+            _ => None,
         }
     }
 }
 
+/// What the CPU graph displays for the selected entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuGraphMode {
+    /// The total usage.
+    Usage,
+    /// What the CPU spent its time on, stacked.
+    Breakdown,
+    /// The clock frequency.
+    Frequency,
+}
+
+const COLUMNS: [Column<CpuWidgetColumn>; 2] = [
+    Column::soft(CpuWidgetColumn::CPU, Some(0.5)),
+    Column::soft(CpuWidgetColumn::Use, Some(0.5)),
+];
+
+const BREAKDOWN_COLUMNS: [Column<CpuWidgetColumn>; 10] = [
+    Column::hard(CpuWidgetColumn::CPU, 5),
+    Column::hard(CpuWidgetColumn::Use, 4),
+    Column::hard(CpuWidgetColumn::User, 5),
+    Column::hard(CpuWidgetColumn::Nice, 5),
+    Column::hard(CpuWidgetColumn::System, 5),
+    Column::hard(CpuWidgetColumn::IoWait, 5),
+    Column::hard(CpuWidgetColumn::Irq, 5),
+    Column::hard(CpuWidgetColumn::SoftIrq, 5),
+    Column::hard(CpuWidgetColumn::Steal, 5),
+    Column::hard(CpuWidgetColumn::Guest, 5),
+];
+
+const FREQUENCY_COLUMNS: [Column<CpuWidgetColumn>; 6] = [
+    Column::hard(CpuWidgetColumn::CPU, 5),
+    Column::hard(CpuWidgetColumn::Use, 4),
+    Column::hard(CpuWidgetColumn::Freq, 7),
+    Column::hard(CpuWidgetColumn::MinFreq, 7),
+    Column::hard(CpuWidgetColumn::MaxFreq, 7),
+    Column::hard(CpuWidgetColumn::Governor, 12),
+];
+
+/// A row in the CPU legend, pointing to where its data lives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuLegendRow {
+    /// An entry in the converted CPU data, by index.
+    Entry(usize),
+    /// A topology group, by index into [`CpuWidgetState::groups`].
+    Group(usize),
+}
+
+/// Identifies a topology group. For socket and NUMA node groups, only the first value is used.
+/// For core groups, these are the package, die, and core.
+type CpuGroupKey = (u32, u32, u32);
+
+/// CPUs that share a socket, NUMA node, or physical core, along with their combined data.
+pub struct CpuGroup {
+    pub key: CpuGroupKey,
+    pub name: String,
+    /// The indices of the members in the converted CPU data.
+    pub members: Vec<usize>,
+    pub data: Vec<Point>,
+    pub last_entry: f64,
+    pub breakdown_data: Vec<(f64, CpuBreakdown)>,
+    pub last_breakdown: Option<CpuBreakdown>,
+    pub frequency_data: Vec<Point>,
+    pub last_frequency: Option<CpuFrequency>,
+}
+
+/// Returns the key of the group a CPU belongs to, if its topology has enough information.
+fn group_key(grouping: CpuGrouping, topology: &CpuTopology) -> Option<CpuGroupKey> {
+    match grouping {
+        CpuGrouping::None => None,
+        CpuGrouping::Socket => Some((topology.package?, 0, 0)),
+        CpuGrouping::Node => Some((topology.node?, 0, 0)),
+        CpuGrouping::Core => Some((
+            topology.package.unwrap_or(0),
+            topology.die.unwrap_or(0),
+            topology.core?,
+        )),
+    }
+}
+
+/// Averages time series that were sampled at the same times. If some are shorter, only the most
+/// recent points that all of them have are used.
+fn average_series<T: Copy>(series: &[&[(f64, T)]], average: impl Fn(&[T]) -> T) -> Vec<(f64, T)> {
+    let len = series.iter().map(|s| s.len()).min().unwrap_or(0);
+    let mut values = Vec::with_capacity(series.len());
+
+    (0..len)
+        .map(|k| {
+            values.clear();
+            values.extend(series.iter().map(|s| s[s.len() - len + k].1));
+            (series[0][series[0].len() - len + k].0, average(&values))
+        })
+        .collect()
+}
+
+fn average_values(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn average_breakdowns(breakdowns: &[CpuBreakdown]) -> CpuBreakdown {
+    let average = |get_value: fn(&CpuBreakdown) -> f64| {
+        breakdowns.iter().map(get_value).sum::<f64>() / breakdowns.len() as f64
+    };
+
+    CpuBreakdown {
+        user: average(|b| b.user),
+        nice: average(|b| b.nice),
+        system: average(|b| b.system),
+        idle: average(|b| b.idle),
+        iowait: average(|b| b.iowait),
+        irq: average(|b| b.irq),
+        softirq: average(|b| b.softirq),
+        steal: average(|b| b.steal),
+        guest: average(|b| b.guest),
+    }
+}
+
+/// Groups the CPUs in `data` by `grouping`. CPUs without enough topology information are left
+/// out of all groups.
+fn build_groups(grouping: CpuGrouping, data: &[CpuWidgetData]) -> Vec<CpuGroup> {
+    let mut groups: BTreeMap<CpuGroupKey, Vec<usize>> = BTreeMap::new();
+    for (index, entry) in data.iter().enumerate() {
+        if let CpuWidgetData::Entry {
+            data_type: CpuDataType::Cpu(_),
+            topology: Some(topology),
+            ..
+        } = entry
+        {
+            if let Some(key) = group_key(grouping, topology) {
+                groups.entry(key).or_default().push(index);
+            }
+        }
+    }
+
+    let multiple_packages = groups
+        .keys()
+        .map(|(package, _, _)| package)
+        .collect::<HashSet<_>>()
+        .len()
+        > 1;
+
+    groups
+        .into_iter()
+        .map(|(key, members)| {
+            let name = match grouping {
+                CpuGrouping::None | CpuGrouping::Socket => format!("S{}", key.0),
+                CpuGrouping::Node => format!("N{}", key.0),
+                CpuGrouping::Core if multiple_packages => format!("S{}C{}", key.0, key.2),
+                CpuGrouping::Core => format!("C{}", key.2),
+            };
+
+            let mut data_series = Vec::with_capacity(members.len());
+            let mut breakdown_series = Vec::with_capacity(members.len());
+            let mut frequency_series = Vec::with_capacity(members.len());
+            let mut last_entries = Vec::with_capacity(members.len());
+            let mut last_breakdowns = Vec::with_capacity(members.len());
+            let mut last_frequencies = Vec::with_capacity(members.len());
+
+            for index in &members {
+                if let CpuWidgetData::Entry {
+                    data,
+                    last_entry,
+                    breakdown_data,
+                    last_breakdown,
+                    frequency_data,
+                    last_frequency,
+                    ..
+                } = &data[*index]
+                {
+                    data_series.push(&data[..]);
+                    breakdown_series.push(&breakdown_data[..]);
+                    frequency_series.push(&frequency_data[..]);
+                    last_entries.push(*last_entry);
+                    last_breakdowns.extend(*last_breakdown);
+                    last_frequencies.extend(last_frequency.as_ref());
+                }
+            }
+
+            CpuGroup {
+                key,
+                name,
+                members,
+                data: average_series(&data_series, average_values),
+                last_entry: average_values(&last_entries),
+                breakdown_data: average_series(&breakdown_series, average_breakdowns),
+                last_breakdown: (!last_breakdowns.is_empty())
+                    .then(|| average_breakdowns(&last_breakdowns)),
+                frequency_data: average_series(&frequency_series, average_values),
+                last_frequency: average_frequency(&last_frequencies),
+            }
+        })
+        .collect()
+}
+
 pub enum CpuWidgetTableData {
     All,
     Entry {
         data_type: CpuDataType,
         last_entry: f64,
+        last_breakdown: Option<CpuBreakdown>,
+        last_frequency: Option<CpuFrequency>,
+    },
+    Group {
+        name: String,
+        index: usize,
+        is_expanded: bool,
+        last_entry: f64,
+        last_breakdown: Option<CpuBreakdown>,
+        last_frequency: Option<CpuFrequency>,
     },
 }
 
@@ -72,14 +345,162 @@ impl CpuWidgetTableData {
                 data_type,
                 data: _,
                 last_entry,
+                breakdown_data: _,
+                last_breakdown,
+                frequency_data: _,
+                last_frequency,
+                topology: _,
             } => CpuWidgetTableData::Entry {
                 data_type: *data_type,
                 last_entry: *last_entry,
+                last_breakdown: *last_breakdown,
+                last_frequency: last_frequency.clone(),
             },
         }
     }
 }
 
+impl QueryRow for CpuWidgetTableData {
+    type Column = CpuWidgetColumn;
+
+    const QUERY_COLUMNS: &'static [QueryColumn<CpuWidgetColumn>] = &[
+        QueryColumn::new(
+            &["cpu", "cpu%", "use", "use%", "usage"],
+            CpuWidgetColumn::Use,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(&["user"], CpuWidgetColumn::User, ColumnKind::Number),
+        QueryColumn::new(&["nice"], CpuWidgetColumn::Nice, ColumnKind::Number),
+        QueryColumn::new(
+            &["sys", "system"],
+            CpuWidgetColumn::System,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["iow", "iowait"],
+            CpuWidgetColumn::IoWait,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(&["irq"], CpuWidgetColumn::Irq, ColumnKind::Number),
+        QueryColumn::new(
+            &["sirq", "softirq"],
+            CpuWidgetColumn::SoftIrq,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(&["steal"], CpuWidgetColumn::Steal, ColumnKind::Number),
+        QueryColumn::new(&["guest"], CpuWidgetColumn::Guest, ColumnKind::Number),
+        QueryColumn::new(&["freq"], CpuWidgetColumn::Freq, ColumnKind::Number),
+        QueryColumn::new(&["min"], CpuWidgetColumn::MinFreq, ColumnKind::Number),
+        QueryColumn::new(&["max"], CpuWidgetColumn::MaxFreq, ColumnKind::Number),
+        QueryColumn::new(
+            &["governor", "gov"],
+            CpuWidgetColumn::Governor,
+            ColumnKind::Text,
+        ),
+    ];
+
+    const NAME_COLUMNS: &'static [CpuWidgetColumn] = &[CpuWidgetColumn::CPU];
+
+    fn text(&self, column: &CpuWidgetColumn) -> Cow<'_, str> {
+        match (self, column) {
+            (CpuWidgetTableData::All, CpuWidgetColumn::CPU) => "All".into(),
+            (
+                CpuWidgetTableData::Entry {
+                    data_type: CpuDataType::Avg,
+                    ..
+                },
+                CpuWidgetColumn::CPU,
+            ) => "AVG".into(),
+            (
+                CpuWidgetTableData::Entry {
+                    data_type: CpuDataType::Cpu(index),
+                    ..
+                },
+                CpuWidgetColumn::CPU,
+            ) => concat_string!("CPU", index.to_string()).into(),
+            (CpuWidgetTableData::Group { name, .. }, CpuWidgetColumn::CPU) => name.as_str().into(),
+            (
+                CpuWidgetTableData::Entry { last_frequency, .. }
+                | CpuWidgetTableData::Group { last_frequency, .. },
+                CpuWidgetColumn::Governor,
+            ) => last_frequency
+                .as_ref()
+                .and_then(|frequency| frequency.governor.as_deref())
+                .unwrap_or_default()
+                .into(),
+            _ => "".into(),
+        }
+    }
+
+    fn value(&self, column: &CpuWidgetColumn) -> Option<f64> {
+        let (last_entry, last_breakdown, last_frequency) = match self {
+            CpuWidgetTableData::All => return None,
+            CpuWidgetTableData::Entry {
+                last_entry,
+                last_breakdown,
+                last_frequency,
+                ..
+            }
+            | CpuWidgetTableData::Group {
+                last_entry,
+                last_breakdown,
+                last_frequency,
+                ..
+            } => (*last_entry, last_breakdown, last_frequency),
+        };
+
+        match column {
+            CpuWidgetColumn::Use => Some(last_entry),
+            CpuWidgetColumn::Freq => last_frequency.as_ref().map(|f| f.current_mhz as f64),
+            CpuWidgetColumn::MinFreq => last_frequency
+                .as_ref()
+                .and_then(|f| f.min_mhz)
+                .map(|mhz| mhz as f64),
+            CpuWidgetColumn::MaxFreq => last_frequency
+                .as_ref()
+                .and_then(|f| f.max_mhz)
+                .map(|mhz| mhz as f64),
+            _ => last_breakdown
+                .as_ref()
+                .and_then(|breakdown| column.breakdown_value(breakdown)),
+        }
+    }
+}
+
+/// Returns the cell for any column other than the CPU column.
+fn value_cell<'a>(
+    column: &CpuWidgetColumn, last_entry: f64, last_breakdown: &Option<CpuBreakdown>,
+    last_frequency: &Option<CpuFrequency>, calculated_width: u16,
+) -> Text<'a> {
+    match column {
+        CpuWidgetColumn::CPU | CpuWidgetColumn::Use => {
+            truncate_to_text(&format!("{:.0}%", last_entry.round()), calculated_width)
+        }
+        CpuWidgetColumn::Freq
+        | CpuWidgetColumn::MinFreq
+        | CpuWidgetColumn::MaxFreq
+        | CpuWidgetColumn::Governor => {
+            let text = last_frequency
+                .as_ref()
+                .and_then(|frequency| column.frequency_text(frequency))
+                .unwrap_or_else(|| "N/A".to_string());
+
+            truncate_to_text(&text, calculated_width)
+        }
+        _ => {
+            let text = match last_breakdown
+                .as_ref()
+                .and_then(|breakdown| column.breakdown_value(breakdown))
+            {
+                Some(value) => format!("{:.0}%", value.round()),
+                None => "N/A".to_string(),
+            };
+
+            truncate_to_text(&text, calculated_width)
+        }
+    }
+}
+
 impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
     fn to_cell(&self, column: &CpuWidgetColumn, calculated_width: NonZeroU16) -> Option<Text<'_>> {
         const CPU_TRUNCATE_BREAKPOINT: u16 = 5;
@@ -96,11 +517,13 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
         match &self {
             CpuWidgetTableData::All => match column {
                 CpuWidgetColumn::CPU => Some("All".into()),
-                CpuWidgetColumn::Use => None,
+                _ => None,
             },
             CpuWidgetTableData::Entry {
                 data_type,
                 last_entry,
+                last_breakdown,
+                last_frequency,
             } => {
                 if calculated_width == 0 {
                     None
@@ -122,8 +545,40 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
                                 Some(text)
                             }
                         },
-                        CpuWidgetColumn::Use => Some(truncate_to_text(
-                            &format!("{:.0}%", last_entry.round()),
+                        _ => Some(value_cell(
+                            column,
+                            *last_entry,
+                            last_breakdown,
+                            last_frequency,
+                            calculated_width,
+                        )),
+                    }
+                }
+            }
+            CpuWidgetTableData::Group {
+                name,
+                index: _,
+                is_expanded,
+                last_entry,
+                last_breakdown,
+                last_frequency,
+            } => {
+                if calculated_width == 0 {
+                    None
+                } else {
+                    match column {
+                        CpuWidgetColumn::CPU => {
+                            let marker = if *is_expanded { "-" } else { "+" };
+                            Some(truncate_to_text(
+                                &concat_string!(marker, name),
+                                calculated_width,
+                            ))
+                        }
+                        _ => Some(value_cell(
+                            column,
+                            *last_entry,
+                            last_breakdown,
+                            last_frequency,
                             calculated_width,
                         )),
                     }
@@ -139,6 +594,8 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
             CpuWidgetTableData::Entry {
                 data_type,
                 last_entry: _,
+                last_breakdown: _,
+                last_frequency: _,
             } => match data_type {
                 CpuDataType::Avg => painter.colours.avg_colour_style,
                 CpuDataType::Cpu(index) => {
@@ -146,6 +603,9 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
                         [index % painter.colours.cpu_colour_styles.len()]
                 }
             },
+            CpuWidgetTableData::Group { index, .. } => {
+                painter.colours.cpu_colour_styles[index % painter.colours.cpu_colour_styles.len()]
+            }
         };
 
         row.style(style)
@@ -165,21 +625,25 @@ pub struct CpuWidgetState {
     pub current_display_time: u64,
     pub is_legend_hidden: bool,
     pub show_avg: bool,
+    pub graph_mode: CpuGraphMode,
+    pub grouping: CpuGrouping,
+    /// The topology groups, if grouping is enabled and the topology is known.
+    pub groups: Vec<CpuGroup>,
+    /// What each row of the legend shows.
+    pub rows: Vec<CpuLegendRow>,
+    expanded_groups: HashSet<CpuGroupKey>,
     pub autohide_timer: Option<Instant>,
     pub table: DataTable<CpuWidgetTableData, CpuWidgetColumn>,
     pub styling: CpuWidgetStyling,
+    /// The legend's search. The "All" row is always shown.
+    pub search: SearchState,
 }
 
 impl CpuWidgetState {
     pub fn new(
-        config: &AppConfigFields, default_selection: CpuDefault, current_display_time: u64,
-        autohide_timer: Option<Instant>, colours: &CanvasStyling,
+        config: &AppConfigFields, default_selection: CpuDefault, grouping: CpuGrouping,
+        current_display_time: u64, autohide_timer: Option<Instant>, colours: &CanvasStyling,
     ) -> Self {
-        const COLUMNS: [Column<CpuWidgetColumn>; 2] = [
-            Column::soft(CpuWidgetColumn::CPU, Some(0.5)),
-            Column::soft(CpuWidgetColumn::Use, Some(0.5)),
-        ];
-
         let props = DataTableProps {
             title: None,
             table_gap: config.table_gap,
@@ -203,17 +667,277 @@ impl CpuWidgetState {
             current_display_time,
             is_legend_hidden: false,
             show_avg: config.show_average_cpu,
+            graph_mode: CpuGraphMode::Usage,
+            grouping,
+            groups: vec![],
+            rows: vec![],
+            expanded_groups: HashSet::new(),
             autohide_timer,
             table,
             styling: CpuWidgetStyling::from_colours(colours),
+            search: SearchState::default(),
+        }
+    }
+
+    /// Toggles between showing the total usage and the given mode. The legend gains columns
+    /// for the mode's details when it is not showing total usage.
+    pub fn toggle_graph_mode(&mut self, mode: CpuGraphMode) {
+        self.graph_mode = if self.graph_mode == mode {
+            CpuGraphMode::Usage
+        } else {
+            mode
+        };
+
+        match self.graph_mode {
+            CpuGraphMode::Usage => {
+                self.table.columns = COLUMNS.into();
+                self.table.props.left_to_right = false;
+            }
+            CpuGraphMode::Breakdown => {
+                self.table.columns = BREAKDOWN_COLUMNS.into();
+                self.table.props.left_to_right = true;
+            }
+            CpuGraphMode::Frequency => {
+                self.table.columns = FREQUENCY_COLUMNS.into();
+                self.table.props.left_to_right = true;
+            }
+        }
+    }
+
+    /// Switches to the next way of grouping CPUs, and collapses all groups.
+    pub fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        self.expanded_groups.clear();
+        self.table.to_first();
+    }
+
+    /// Expands or collapses the selected group, if a group is selected.
+    pub fn toggle_selected_group(&mut self) {
+        if let Some(CpuLegendRow::Group(index)) = self.rows.get(self.table.current_index()) {
+            if let Some(group) = self.groups.get(*index) {
+                if !self.expanded_groups.remove(&group.key) {
+                    self.expanded_groups.insert(group.key);
+                }
+            }
         }
     }
 
+    /// Parses the legend's search again after it changes.
+    pub fn update_query(&mut self) {
+        self.search.update_table_query::<CpuWidgetTableData>();
+        self.table.to_first();
+    }
+
     pub fn update_table(&mut self, data: &[CpuWidgetData]) {
-        self.table.set_data(
-            data.iter()
-                .map(CpuWidgetTableData::from_cpu_widget_data)
-                .collect(),
+        self.groups = build_groups(self.grouping, data);
+        self.rows = if self.groups.is_empty() {
+            (0..data.len()).map(CpuLegendRow::Entry).collect()
+        } else {
+            let grouped: HashSet<usize> = self
+                .groups
+                .iter()
+                .flat_map(|group| group.members.iter().copied())
+                .collect();
+
+            // "All" and the average come first, and any CPUs that could not be grouped come last.
+            let (ungrouped_cpus, other): (Vec<usize>, Vec<usize>) = (0..data.len())
+                .filter(|index| !grouped.contains(index))
+                .partition(|index| {
+                    matches!(
+                        data[*index],
+                        CpuWidgetData::Entry {
+                            data_type: CpuDataType::Cpu(_),
+                            ..
+                        }
+                    )
+                });
+
+            let mut rows: Vec<CpuLegendRow> = other.into_iter().map(CpuLegendRow::Entry).collect();
+            for (index, group) in self.groups.iter().enumerate() {
+                rows.push(CpuLegendRow::Group(index));
+                if self.expanded_groups.contains(&group.key) {
+                    rows.extend(group.members.iter().copied().map(CpuLegendRow::Entry));
+                }
+            }
+            rows.extend(ungrouped_cpus.into_iter().map(CpuLegendRow::Entry));
+
+            rows
+        };
+
+        let query = self.search.query();
+        let (rows, table_data) = self
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let table_row = match row {
+                    CpuLegendRow::Entry(index) => data
+                        .get(*index)
+                        .map(CpuWidgetTableData::from_cpu_widget_data),
+                    CpuLegendRow::Group(index) => {
+                        self.groups
+                            .get(*index)
+                            .map(|group| CpuWidgetTableData::Group {
+                                name: group.name.clone(),
+                                index: *index,
+                                is_expanded: self.expanded_groups.contains(&group.key),
+                                last_entry: group.last_entry,
+                                last_breakdown: group.last_breakdown,
+                                last_frequency: group.last_frequency.clone(),
+                            })
+                    }
+                }?;
+
+                let is_shown = matches!(table_row, CpuWidgetTableData::All)
+                    || query.map_or(true, |query| query.check_row(&table_row));
+
+                is_shown.then_some((*row, table_row))
+            })
+            .unzip();
+
+        self.rows = rows;
+        self.table.set_data(table_data);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(index: usize, usage: f64, topology: Option<CpuTopology>) -> CpuWidgetData {
+        CpuWidgetData::Entry {
+            data_type: CpuDataType::Cpu(index),
+            data: vec![(0.0, usage)],
+            last_entry: usage,
+            breakdown_data: vec![],
+            last_breakdown: None,
+            frequency_data: vec![],
+            last_frequency: None,
+            topology,
+        }
+    }
+
+    fn topology(package: u32, core: u32, node: u32) -> Option<CpuTopology> {
+        Some(CpuTopology {
+            package: Some(package),
+            die: Some(0),
+            core: Some(core),
+            node: Some(node),
+        })
+    }
+
+    #[test]
+    fn cpu_groups() {
+        let data = vec![
+            CpuWidgetData::All,
+            entry(0, 10.0, topology(0, 0, 0)),
+            entry(1, 30.0, topology(0, 0, 0)),
+            entry(2, 50.0, topology(1, 0, 1)),
+            entry(3, 70.0, None),
+        ];
+
+        assert!(build_groups(CpuGrouping::None, &data).is_empty());
+
+        let sockets = build_groups(CpuGrouping::Socket, &data);
+        assert_eq!(sockets.len(), 2);
+        assert_eq!(sockets[0].name, "S0");
+        assert_eq!(sockets[0].members, vec![1, 2]);
+        assert_eq!(sockets[0].last_entry, 20.0);
+        assert_eq!(sockets[0].data, vec![(0.0, 20.0)]);
+        assert_eq!(sockets[1].members, vec![3]);
+
+        let nodes = build_groups(CpuGrouping::Node, &data);
+        assert_eq!(
+            nodes.iter().map(|g| g.name.as_str()).collect::<Vec<_>>(),
+            vec!["N0", "N1"]
+        );
+
+        // Cores are only unique within a package.
+        let cores = build_groups(CpuGrouping::Core, &data);
+        assert_eq!(
+            cores.iter().map(|g| g.name.as_str()).collect::<Vec<_>>(),
+            vec!["S0C0", "S1C0"]
+        );
+    }
+
+    fn legend(state: &mut CpuWidgetState, data: &[CpuWidgetData], query: &str) -> Vec<String> {
+        state.search.search_state.current_search_query = query.to_string();
+        state.update_query();
+        state.update_table(data);
+
+        state
+            .table
+            .data()
+            .iter()
+            .map(|row| row.text(&CpuWidgetColumn::CPU).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn cpu_legend_rows() {
+        let config = AppConfigFields::default();
+        let mut state = CpuWidgetState::new(
+            &config,
+            CpuDefault::All,
+            CpuGrouping::None,
+            60000,
+            None,
+            &Default::default(),
+        );
+        let data = vec![
+            CpuWidgetData::All,
+            entry(0, 10.0, None),
+            entry(1, 50.0, None),
+            entry(2, 30.0, None),
+            entry(3, 70.0, None),
+        ];
+
+        assert_eq!(legend(&mut state, &data, "").len(), 5);
+
+        // "All" is always kept.
+        assert_eq!(
+            legend(&mut state, &data, "use > 40"),
+            vec!["All", "CPU1", "CPU3"]
+        );
+
+        // Rows still point at their entries in the unfiltered data, so selecting a filtered row
+        // selects the right CPU.
+        assert_eq!(
+            state.rows,
+            vec![
+                CpuLegendRow::Entry(0),
+                CpuLegendRow::Entry(2),
+                CpuLegendRow::Entry(4)
+            ]
+        );
+        state.table.set_position(2);
+        assert_eq!(
+            state.rows[state.table.state.current_index],
+            CpuLegendRow::Entry(4)
+        );
+
+        // Groups are searched by their averages.
+        state.grouping = CpuGrouping::Socket;
+        let data = vec![
+            CpuWidgetData::All,
+            entry(0, 10.0, topology(0, 0, 0)),
+            entry(1, 30.0, topology(0, 1, 0)),
+            entry(2, 70.0, topology(1, 0, 1)),
+        ];
+        assert_eq!(legend(&mut state, &data, "use > 40"), vec!["All", "S1"]);
+        assert_eq!(
+            state.rows,
+            vec![CpuLegendRow::Entry(0), CpuLegendRow::Group(1)]
+        );
+    }
+
+    #[test]
+    fn cpu_average_series() {
+        let a = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+        let b = [(1.0, 5.0), (2.0, 7.0)];
+
+        assert_eq!(
+            average_series(&[&a, &b], average_values),
+            vec![(1.0, 4.0), (2.0, 6.0)]
         );
     }
 }
@@ -0,0 +1,15 @@
+use std::time::Instant;
+
+pub struct DiskIoWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl DiskIoWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        DiskIoWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
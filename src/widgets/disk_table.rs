@@ -1,10 +1,14 @@
 use std::{borrow::Cow, cmp::max, num::NonZeroU16};
 
 use kstring::KString;
+use serde::{de::Error, Deserialize};
 use tui::text::Text;
 
 use crate::{
-    app::AppConfigFields,
+    app::{
+        query::{ColumnKind, QueryColumn, QueryRow},
+        AppConfigFields, SearchState,
+    },
     canvas::{
         components::data_table::{
             ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
@@ -12,7 +16,8 @@ use crate::{
         },
         styling::CanvasStyling,
     },
-    utils::general::{get_decimal_bytes, sort_partial_fn, truncate_to_text},
+    data_collection::disks::IoRates,
+    utils::general::{get_decimal_bytes, get_decimal_prefix, sort_partial_fn, truncate_to_text},
 };
 
 #[derive(Clone, Debug)]
@@ -25,6 +30,30 @@ pub struct DiskWidgetData {
     pub summed_total_bytes: Option<u64>,
     pub io_read: KString,
     pub io_write: KString,
+    /// The read and write rates behind `io_read` and `io_write`.
+    pub io_bytes_per_sec: Option<(u64, u64)>,
+    pub io_rates: Option<IoRates>,
+    pub free_inodes: Option<u64>,
+    pub used_inodes: Option<u64>,
+    pub total_inodes: Option<u64>,
+    pub fs_type: KString,
+    pub mount_options: KString,
+    pub device: KString,
+}
+
+/// Formats an inode count, like `1.5M`.
+fn inode_string(inodes: Option<u64>) -> KString {
+    match inodes {
+        Some(inodes) => {
+            let (value, unit) = get_decimal_prefix(inodes, "");
+            if unit.is_empty() {
+                format!("{value:.0}").into()
+            } else {
+                format!("{value:.1}{unit}").into()
+            }
+        }
+        None => "N/A".into(),
+    }
 }
 
 impl DiskWidgetData {
@@ -96,8 +125,49 @@ impl DiskWidgetData {
             None => "N/A".into(),
         }
     }
+
+    pub fn used_inodes_percent(&self) -> Option<f64> {
+        match (self.used_inodes, self.total_inodes) {
+            (Some(used_inodes), Some(total_inodes)) if total_inodes > 0 => {
+                Some(used_inodes as f64 / total_inodes as f64 * 100_f64)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn used_inodes_percent_string(&self) -> KString {
+        match self.used_inodes_percent() {
+            Some(val) => format!("{val:.1}%").into(),
+            None => "N/A".into(),
+        }
+    }
+
+    /// Returns the value of an I/O rate column, if this disk reports it.
+    pub fn io_rate(&self, column: &DiskWidgetColumn) -> Option<f64> {
+        let rates = self.io_rates.as_ref()?;
+
+        match column {
+            DiskWidgetColumn::ReadOps => Some(rates.read_ops_per_sec),
+            DiskWidgetColumn::WriteOps => Some(rates.write_ops_per_sec),
+            DiskWidgetColumn::Await => Some(rates.await_ms.unwrap_or_default()),
+            DiskWidgetColumn::BusyPercent => Some(rates.busy_percent),
+            DiskWidgetColumn::QueueSize => Some(rates.queue_size),
+            _ => None,
+        }
+    }
+
+    pub fn io_rate_string(&self, column: &DiskWidgetColumn) -> KString {
+        match (column, self.io_rate(column)) {
+            (DiskWidgetColumn::Await, Some(val)) => format!("{val:.1}ms").into(),
+            (DiskWidgetColumn::BusyPercent, Some(val)) => format!("{val:.1}%").into(),
+            (DiskWidgetColumn::QueueSize, Some(val)) => format!("{val:.2}").into(),
+            (_, Some(val)) => format!("{val:.0}").into(),
+            (_, None) => "N/A".into(),
+        }
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DiskWidgetColumn {
     Disk,
     Mount,
@@ -108,6 +178,148 @@ pub enum DiskWidgetColumn {
     FreePercent,
     IoRead,
     IoWrite,
+    ReadOps,
+    WriteOps,
+    Await,
+    BusyPercent,
+    QueueSize,
+    UsedInodes,
+    FreeInodes,
+    TotalInodes,
+    UsedInodesPercent,
+    FsType,
+    MountOptions,
+    Device,
+}
+
+impl<'de> Deserialize<'de> for DiskWidgetColumn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Columns are named the same way in the config file as in searches.
+        let value = String::deserialize(deserializer)?;
+        DiskWidgetData::QUERY_COLUMNS
+            .iter()
+            .find(|column| column.matches(&value))
+            .map(|column| column.column)
+            .ok_or_else(|| Error::custom("doesn't match any column type"))
+    }
+}
+
+impl QueryRow for DiskWidgetData {
+    type Column = DiskWidgetColumn;
+
+    const QUERY_COLUMNS: &'static [QueryColumn<DiskWidgetColumn>] = &[
+        QueryColumn::new(&["disk"], DiskWidgetColumn::Disk, ColumnKind::Text),
+        QueryColumn::new(&["mount"], DiskWidgetColumn::Mount, ColumnKind::Text),
+        QueryColumn::new(&["used"], DiskWidgetColumn::Used, ColumnKind::Bytes),
+        QueryColumn::new(&["free"], DiskWidgetColumn::Free, ColumnKind::Bytes),
+        QueryColumn::new(&["total"], DiskWidgetColumn::Total, ColumnKind::Bytes),
+        QueryColumn::new(
+            &["used%"],
+            DiskWidgetColumn::UsedPercent,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["free%"],
+            DiskWidgetColumn::FreePercent,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["r/s", "read"],
+            DiskWidgetColumn::IoRead,
+            ColumnKind::Bytes,
+        ),
+        QueryColumn::new(
+            &["w/s", "write"],
+            DiskWidgetColumn::IoWrite,
+            ColumnKind::Bytes,
+        ),
+        QueryColumn::new(
+            &["riops", "r/iops", "reads"],
+            DiskWidgetColumn::ReadOps,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["wiops", "w/iops", "writes"],
+            DiskWidgetColumn::WriteOps,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(&["await"], DiskWidgetColumn::Await, ColumnKind::Number),
+        QueryColumn::new(
+            &["busy%", "busy", "util%"],
+            DiskWidgetColumn::BusyPercent,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["queue", "aqu-sz"],
+            DiskWidgetColumn::QueueSize,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["iused", "used_inodes"],
+            DiskWidgetColumn::UsedInodes,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["ifree", "free_inodes"],
+            DiskWidgetColumn::FreeInodes,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["inodes", "total_inodes"],
+            DiskWidgetColumn::TotalInodes,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["iuse%", "iused%"],
+            DiskWidgetColumn::UsedInodesPercent,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(
+            &["type", "fs_type", "filesystem"],
+            DiskWidgetColumn::FsType,
+            ColumnKind::Text,
+        ),
+        QueryColumn::new(
+            &["options", "mount_options"],
+            DiskWidgetColumn::MountOptions,
+            ColumnKind::Text,
+        ),
+        QueryColumn::new(&["device"], DiskWidgetColumn::Device, ColumnKind::Text),
+    ];
+
+    const NAME_COLUMNS: &'static [DiskWidgetColumn] =
+        &[DiskWidgetColumn::Disk, DiskWidgetColumn::Mount];
+
+    fn text(&self, column: &DiskWidgetColumn) -> Cow<'_, str> {
+        match column {
+            DiskWidgetColumn::Disk => self.name.as_str().into(),
+            DiskWidgetColumn::Mount => self.mount_point.as_str().into(),
+            DiskWidgetColumn::FsType => self.fs_type.as_str().into(),
+            DiskWidgetColumn::MountOptions => self.mount_options.as_str().into(),
+            DiskWidgetColumn::Device => self.device.as_str().into(),
+            _ => "".into(),
+        }
+    }
+
+    fn value(&self, column: &DiskWidgetColumn) -> Option<f64> {
+        match column {
+            DiskWidgetColumn::Used => self.used_bytes.map(|bytes| bytes as f64),
+            DiskWidgetColumn::Free => self.free_bytes.map(|bytes| bytes as f64),
+            DiskWidgetColumn::Total => self.total_bytes.map(|bytes| bytes as f64),
+            DiskWidgetColumn::UsedPercent => self.used_percent(),
+            DiskWidgetColumn::FreePercent => self.free_percent(),
+            DiskWidgetColumn::IoRead => self.io_bytes_per_sec.map(|(read, _)| read as f64),
+            DiskWidgetColumn::IoWrite => self.io_bytes_per_sec.map(|(_, write)| write as f64),
+            DiskWidgetColumn::UsedInodes => self.used_inodes.map(|inodes| inodes as f64),
+            DiskWidgetColumn::FreeInodes => self.free_inodes.map(|inodes| inodes as f64),
+            DiskWidgetColumn::TotalInodes => self.total_inodes.map(|inodes| inodes as f64),
+            DiskWidgetColumn::UsedInodesPercent => self.used_inodes_percent(),
+            _ => self.io_rate(column),
+        }
+    }
 }
 
 impl ColumnHeader for DiskWidgetColumn {
@@ -122,6 +334,18 @@ impl ColumnHeader for DiskWidgetColumn {
             DiskWidgetColumn::Total => "Total(t)",
             DiskWidgetColumn::IoRead => "R/s(r)",
             DiskWidgetColumn::IoWrite => "W/s(w)",
+            DiskWidgetColumn::ReadOps => "RIOPS",
+            DiskWidgetColumn::WriteOps => "WIOPS",
+            DiskWidgetColumn::Await => "Await",
+            DiskWidgetColumn::BusyPercent => "Busy%",
+            DiskWidgetColumn::QueueSize => "Queue",
+            DiskWidgetColumn::UsedInodes => "IUsed",
+            DiskWidgetColumn::FreeInodes => "IFree",
+            DiskWidgetColumn::TotalInodes => "Inodes",
+            DiskWidgetColumn::UsedInodesPercent => "IUse%",
+            DiskWidgetColumn::FsType => "Type",
+            DiskWidgetColumn::MountOptions => "Options",
+            DiskWidgetColumn::Device => "Device",
         }
         .into()
     }
@@ -144,22 +368,54 @@ impl DataToCell<DiskWidgetColumn> for DiskWidgetData {
             DiskWidgetColumn::Total => truncate_to_text(&self.total_space(), calculated_width),
             DiskWidgetColumn::IoRead => truncate_to_text(&self.io_read, calculated_width),
             DiskWidgetColumn::IoWrite => truncate_to_text(&self.io_write, calculated_width),
+            DiskWidgetColumn::ReadOps
+            | DiskWidgetColumn::WriteOps
+            | DiskWidgetColumn::Await
+            | DiskWidgetColumn::BusyPercent
+            | DiskWidgetColumn::QueueSize => {
+                truncate_to_text(&self.io_rate_string(column), calculated_width)
+            }
+            DiskWidgetColumn::UsedInodes => {
+                truncate_to_text(&inode_string(self.used_inodes), calculated_width)
+            }
+            DiskWidgetColumn::FreeInodes => {
+                truncate_to_text(&inode_string(self.free_inodes), calculated_width)
+            }
+            DiskWidgetColumn::TotalInodes => {
+                truncate_to_text(&inode_string(self.total_inodes), calculated_width)
+            }
+            DiskWidgetColumn::UsedInodesPercent => {
+                truncate_to_text(&self.used_inodes_percent_string(), calculated_width)
+            }
+            DiskWidgetColumn::FsType => truncate_to_text(&self.fs_type, calculated_width),
+            DiskWidgetColumn::MountOptions => {
+                truncate_to_text(&self.mount_options, calculated_width)
+            }
+            DiskWidgetColumn::Device => truncate_to_text(&self.device, calculated_width),
         };
 
         Some(text)
     }
 
-    fn column_widths<C: DataTableColumn<DiskWidgetColumn>>(
-        data: &[Self], _columns: &[C],
-    ) -> Vec<u16>
+    fn column_widths<C: DataTableColumn<DiskWidgetColumn>>(data: &[Self], columns: &[C]) -> Vec<u16>
     where
         Self: Sized,
     {
-        let mut widths = vec![0; 7];
+        let mut widths = vec![0; columns.len()];
 
         data.iter().for_each(|row| {
-            widths[0] = max(widths[0], row.name.len() as u16);
-            widths[1] = max(widths[1], row.mount_point.len() as u16);
+            for (width, column) in widths.iter_mut().zip(columns) {
+                match column.inner() {
+                    DiskWidgetColumn::Disk => *width = max(*width, row.name.len() as u16),
+                    DiskWidgetColumn::Mount => *width = max(*width, row.mount_point.len() as u16),
+                    DiskWidgetColumn::FsType => *width = max(*width, row.fs_type.len() as u16),
+                    DiskWidgetColumn::MountOptions => {
+                        *width = max(*width, row.mount_options.len() as u16)
+                    }
+                    DiskWidgetColumn::Device => *width = max(*width, row.device.len() as u16),
+                    _ => {}
+                }
+            }
         });
 
         widths
@@ -169,6 +425,7 @@ impl DataToCell<DiskWidgetColumn> for DiskWidgetData {
 pub struct DiskTableWidget {
     pub table: SortDataTable<DiskWidgetData, DiskWidgetColumn>,
     pub force_update_data: bool,
+    pub search: SearchState,
 }
 
 impl SortsRow for DiskWidgetColumn {
@@ -207,22 +464,95 @@ impl SortsRow for DiskWidgetColumn {
             DiskWidgetColumn::IoWrite => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(&a.io_write, &b.io_write));
             }
+            DiskWidgetColumn::ReadOps
+            | DiskWidgetColumn::WriteOps
+            | DiskWidgetColumn::Await
+            | DiskWidgetColumn::BusyPercent
+            | DiskWidgetColumn::QueueSize => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.io_rate(self), &b.io_rate(self))
+                });
+            }
+            DiskWidgetColumn::UsedInodes => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.used_inodes, &b.used_inodes));
+            }
+            DiskWidgetColumn::FreeInodes => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.free_inodes, &b.free_inodes));
+            }
+            DiskWidgetColumn::TotalInodes => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.total_inodes, &b.total_inodes));
+            }
+            DiskWidgetColumn::UsedInodesPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.used_inodes_percent(), &b.used_inodes_percent())
+                });
+            }
+            DiskWidgetColumn::FsType => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.fs_type, &b.fs_type));
+            }
+            DiskWidgetColumn::MountOptions => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.mount_options, &b.mount_options)
+                });
+            }
+            DiskWidgetColumn::Device => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.device, &b.device));
+            }
+        }
+    }
+}
+
+/// The columns shown if none are configured.
+const DEFAULT_COLUMNS: [DiskWidgetColumn; 8] = [
+    DiskWidgetColumn::Disk,
+    DiskWidgetColumn::Mount,
+    DiskWidgetColumn::Used,
+    DiskWidgetColumn::Free,
+    DiskWidgetColumn::Total,
+    DiskWidgetColumn::UsedPercent,
+    DiskWidgetColumn::IoRead,
+    DiskWidgetColumn::IoWrite,
+];
+
+fn make_column(column: DiskWidgetColumn) -> SortColumn<DiskWidgetColumn> {
+    match column {
+        DiskWidgetColumn::Disk | DiskWidgetColumn::Mount => SortColumn::soft(column, Some(0.2)),
+        DiskWidgetColumn::Used | DiskWidgetColumn::Free => {
+            SortColumn::hard(column, 8).default_descending()
+        }
+        DiskWidgetColumn::Total | DiskWidgetColumn::UsedPercent => {
+            SortColumn::hard(column, 9).default_descending()
+        }
+        DiskWidgetColumn::FreePercent => SortColumn::hard(column, 7).default_descending(),
+        DiskWidgetColumn::IoRead => SortColumn::hard(column, 10).default_descending(),
+        DiskWidgetColumn::IoWrite => SortColumn::hard(column, 11).default_descending(),
+        DiskWidgetColumn::ReadOps | DiskWidgetColumn::WriteOps => {
+            SortColumn::hard(column, 7).default_descending()
+        }
+        DiskWidgetColumn::Await => SortColumn::hard(column, 9).default_descending(),
+        DiskWidgetColumn::BusyPercent | DiskWidgetColumn::QueueSize => {
+            SortColumn::hard(column, 7).default_descending()
+        }
+        DiskWidgetColumn::UsedInodes
+        | DiskWidgetColumn::FreeInodes
+        | DiskWidgetColumn::TotalInodes
+        | DiskWidgetColumn::UsedInodesPercent => SortColumn::hard(column, 8).default_descending(),
+        DiskWidgetColumn::FsType => SortColumn::soft(column, Some(0.1)),
+        DiskWidgetColumn::MountOptions | DiskWidgetColumn::Device => {
+            SortColumn::soft(column, Some(0.2))
         }
     }
 }
 
 impl DiskTableWidget {
-    pub fn new(config: &AppConfigFields, colours: &CanvasStyling) -> Self {
-        let columns = [
-            SortColumn::soft(DiskWidgetColumn::Disk, Some(0.2)),
-            SortColumn::soft(DiskWidgetColumn::Mount, Some(0.2)),
-            SortColumn::hard(DiskWidgetColumn::Used, 8).default_descending(),
-            SortColumn::hard(DiskWidgetColumn::Free, 8).default_descending(),
-            SortColumn::hard(DiskWidgetColumn::Total, 9).default_descending(),
-            SortColumn::hard(DiskWidgetColumn::UsedPercent, 9).default_descending(),
-            SortColumn::hard(DiskWidgetColumn::IoRead, 10).default_descending(),
-            SortColumn::hard(DiskWidgetColumn::IoWrite, 11).default_descending(),
-        ];
+    pub fn new(
+        config: &AppConfigFields, colours: &CanvasStyling, config_columns: &[DiskWidgetColumn],
+    ) -> Self {
+        let columns: Vec<_> = if config_columns.is_empty() {
+            DEFAULT_COLUMNS.into_iter().map(make_column).collect()
+        } else {
+            config_columns.iter().copied().map(make_column).collect()
+        };
 
         let props = SortDataTableProps {
             inner: DataTableProps {
@@ -242,6 +572,7 @@ impl DiskTableWidget {
         Self {
             table: SortDataTable::new_sortable(columns, props, styling),
             force_update_data: false,
+            search: SearchState::default(),
         }
     }
 
@@ -251,16 +582,38 @@ impl DiskTableWidget {
         self.force_update_data = true;
     }
 
+    /// Parses the search again after it changes, and filters the table by it.
+    pub fn update_query(&mut self) {
+        self.search.update_table_query::<DiskWidgetData>();
+        self.table.to_first();
+        self.force_data_update();
+    }
+
     pub fn ingest_data(&mut self, data: &[DiskWidgetData]) {
-        let mut data = data.to_vec();
+        let mut data: Vec<_> = match self.search.query() {
+            Some(query) => data
+                .iter()
+                .filter(|disk| query.check_row(*disk))
+                .cloned()
+                .collect(),
+            None => data.to_vec(),
+        };
         if let Some(column) = self.table.columns.get(self.table.sort_index()) {
             column.sort_by(&mut data, self.table.order());
         }
         self.table.set_data(data);
     }
 
-    pub fn set_index(&mut self, index: usize) {
-        self.table.set_sort_index(index);
-        self.force_data_update();
+    /// Returns the name of the currently selected disk, if there is one.
+    pub fn current_disk(&self) -> Option<&str> {
+        self.table.current_item().map(|disk| disk.name.as_str())
+    }
+
+    /// Sorts by the given column, if it is shown.
+    pub fn select_column(&mut self, column: DiskWidgetColumn) {
+        if let Some(index) = self.table.columns.iter().position(|c| *c.inner() == column) {
+            self.table.set_sort_index(index);
+            self.force_data_update();
+        }
     }
 }
@@ -0,0 +1,127 @@
+use std::{borrow::Cow, cmp::max, num::NonZeroU16};
+
+use tui::text::Text;
+
+use crate::{
+    app::AppConfigFields,
+    canvas::{
+        components::data_table::{
+            Column, ColumnHeader, DataTable, DataTableColumn, DataTableProps, DataTableStyling,
+            DataToCell,
+        },
+        styling::CanvasStyling,
+    },
+    data_conversion::binary_byte_string,
+    utils::general::truncate_to_text,
+};
+
+/// One line of the memory breakdown.
+#[derive(Clone, Debug)]
+pub struct MemTableWidgetData {
+    pub name: &'static str,
+    pub bytes: u64,
+    /// The total amount of RAM, which percentages are relative to.
+    pub total_bytes: Option<u64>,
+}
+
+impl MemTableWidgetData {
+    pub fn percent_string(&self) -> String {
+        match self.total_bytes {
+            Some(total) if total > 0 => format!("{:.1}%", self.bytes as f64 / total as f64 * 100.0),
+            _ => "N/A".to_string(),
+        }
+    }
+}
+
+pub enum MemTableWidgetColumn {
+    Name,
+    Size,
+    Percent,
+}
+
+impl ColumnHeader for MemTableWidgetColumn {
+    fn text(&self) -> Cow<'static, str> {
+        match self {
+            MemTableWidgetColumn::Name => "Memory",
+            MemTableWidgetColumn::Size => "Size",
+            MemTableWidgetColumn::Percent => "%RAM",
+        }
+        .into()
+    }
+}
+
+impl DataToCell<MemTableWidgetColumn> for MemTableWidgetData {
+    fn to_cell(
+        &self, column: &MemTableWidgetColumn, calculated_width: NonZeroU16,
+    ) -> Option<Text<'_>> {
+        let calculated_width = calculated_width.get();
+        let text = match column {
+            MemTableWidgetColumn::Name => truncate_to_text(self.name, calculated_width),
+            MemTableWidgetColumn::Size => {
+                truncate_to_text(&binary_byte_string(self.bytes), calculated_width)
+            }
+            MemTableWidgetColumn::Percent => {
+                truncate_to_text(&self.percent_string(), calculated_width)
+            }
+        };
+
+        Some(text)
+    }
+
+    fn column_widths<C: DataTableColumn<MemTableWidgetColumn>>(
+        data: &[Self], _columns: &[C],
+    ) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        let mut widths = vec![0; 3];
+
+        data.iter().for_each(|row| {
+            widths[0] = max(widths[0], row.name.len() as u16);
+        });
+
+        widths
+    }
+}
+
+/// A table showing where memory is going, in a fixed order.
+pub struct MemTableWidget {
+    pub table: DataTable<MemTableWidgetData, MemTableWidgetColumn>,
+    pub force_update_data: bool,
+}
+
+impl MemTableWidget {
+    pub fn new(config: &AppConfigFields, colours: &CanvasStyling) -> Self {
+        const COLUMNS: [Column<MemTableWidgetColumn>; 3] = [
+            Column::soft(MemTableWidgetColumn::Name, Some(0.6)),
+            Column::hard(MemTableWidgetColumn::Size, 9),
+            Column::hard(MemTableWidgetColumn::Percent, 7),
+        ];
+
+        let props = DataTableProps {
+            title: Some(" Memory Details ".into()),
+            table_gap: config.table_gap,
+            left_to_right: true,
+            is_basic: config.use_basic_mode,
+            show_table_scroll_position: config.show_table_scroll_position,
+            show_current_entry_when_unfocused: false,
+        };
+
+        let styling = DataTableStyling::from_colours(colours);
+
+        Self {
+            table: DataTable::new(COLUMNS, props, styling),
+            force_update_data: false,
+        }
+    }
+
+    /// Forces an update of the data stored.
+    #[inline]
+    pub fn force_data_update(&mut self) {
+        self.force_update_data = true;
+    }
+
+    pub fn ingest_data(&mut self, data: &[MemTableWidgetData]) {
+        self.table.set_data(data.to_vec());
+    }
+}
@@ -0,0 +1,242 @@
+use std::{borrow::Cow, cmp::max, num::NonZeroU16};
+
+use kstring::KString;
+use tui::text::Text;
+
+use crate::{
+    app::AppConfigFields,
+    canvas::{
+        components::data_table::{
+            ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
+            SortColumn, SortDataTable, SortDataTableProps, SortOrder, SortsRow,
+        },
+        styling::CanvasStyling,
+    },
+    utils::general::{sort_partial_fn, truncate_to_text},
+};
+
+#[derive(Clone, Debug)]
+pub struct NetInterfaceWidgetData {
+    pub name: KString,
+    pub rx: u64,
+    pub tx: u64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: Option<u64>,
+    pub tx_dropped: Option<u64>,
+    pub rx_display: KString,
+    pub tx_display: KString,
+    pub total_rx_display: KString,
+    pub total_tx_display: KString,
+}
+
+impl NetInterfaceWidgetData {
+    fn dropped_string(dropped: Option<u64>) -> KString {
+        match dropped {
+            Some(dropped) => dropped.to_string().into(),
+            None => "N/A".into(),
+        }
+    }
+}
+
+pub enum NetInterfaceWidgetColumn {
+    Interface,
+    Rx,
+    Tx,
+    TotalRx,
+    TotalTx,
+    RxPackets,
+    TxPackets,
+    RxErrors,
+    TxErrors,
+    RxDropped,
+    TxDropped,
+}
+
+impl ColumnHeader for NetInterfaceWidgetColumn {
+    fn text(&self) -> Cow<'static, str> {
+        match self {
+            NetInterfaceWidgetColumn::Interface => "Interface(n)",
+            NetInterfaceWidgetColumn::Rx => "RX/s(r)",
+            NetInterfaceWidgetColumn::Tx => "TX/s(t)",
+            NetInterfaceWidgetColumn::TotalRx => "Total RX",
+            NetInterfaceWidgetColumn::TotalTx => "Total TX",
+            NetInterfaceWidgetColumn::RxPackets => "RX Pkt/s",
+            NetInterfaceWidgetColumn::TxPackets => "TX Pkt/s",
+            NetInterfaceWidgetColumn::RxErrors => "RX Err",
+            NetInterfaceWidgetColumn::TxErrors => "TX Err",
+            NetInterfaceWidgetColumn::RxDropped => "RX Drop",
+            NetInterfaceWidgetColumn::TxDropped => "TX Drop",
+        }
+        .into()
+    }
+}
+
+impl DataToCell<NetInterfaceWidgetColumn> for NetInterfaceWidgetData {
+    fn to_cell(
+        &self, column: &NetInterfaceWidgetColumn, calculated_width: NonZeroU16,
+    ) -> Option<Text<'_>> {
+        let calculated_width = calculated_width.get();
+        let text = match column {
+            NetInterfaceWidgetColumn::Interface => truncate_to_text(&self.name, calculated_width),
+            NetInterfaceWidgetColumn::Rx => truncate_to_text(&self.rx_display, calculated_width),
+            NetInterfaceWidgetColumn::Tx => truncate_to_text(&self.tx_display, calculated_width),
+            NetInterfaceWidgetColumn::TotalRx => {
+                truncate_to_text(&self.total_rx_display, calculated_width)
+            }
+            NetInterfaceWidgetColumn::TotalTx => {
+                truncate_to_text(&self.total_tx_display, calculated_width)
+            }
+            NetInterfaceWidgetColumn::RxPackets => {
+                truncate_to_text(&self.rx_packets.to_string(), calculated_width)
+            }
+            NetInterfaceWidgetColumn::TxPackets => {
+                truncate_to_text(&self.tx_packets.to_string(), calculated_width)
+            }
+            NetInterfaceWidgetColumn::RxErrors => {
+                truncate_to_text(&self.rx_errors.to_string(), calculated_width)
+            }
+            NetInterfaceWidgetColumn::TxErrors => {
+                truncate_to_text(&self.tx_errors.to_string(), calculated_width)
+            }
+            NetInterfaceWidgetColumn::RxDropped => {
+                truncate_to_text(&Self::dropped_string(self.rx_dropped), calculated_width)
+            }
+            NetInterfaceWidgetColumn::TxDropped => {
+                truncate_to_text(&Self::dropped_string(self.tx_dropped), calculated_width)
+            }
+        };
+
+        Some(text)
+    }
+
+    fn column_widths<C: DataTableColumn<NetInterfaceWidgetColumn>>(
+        data: &[Self], _columns: &[C],
+    ) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        let mut widths = vec![0; 11];
+
+        data.iter().for_each(|row| {
+            widths[0] = max(widths[0], row.name.len() as u16);
+        });
+
+        widths
+    }
+}
+
+impl SortsRow for NetInterfaceWidgetColumn {
+    type DataType = NetInterfaceWidgetData;
+
+    fn sort_data(&self, data: &mut [Self::DataType], descending: bool) {
+        match self {
+            NetInterfaceWidgetColumn::Interface => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.name, &b.name));
+            }
+            NetInterfaceWidgetColumn::Rx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rx, b.rx));
+            }
+            NetInterfaceWidgetColumn::Tx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.tx, b.tx));
+            }
+            NetInterfaceWidgetColumn::TotalRx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.total_rx, b.total_rx));
+            }
+            NetInterfaceWidgetColumn::TotalTx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.total_tx, b.total_tx));
+            }
+            NetInterfaceWidgetColumn::RxPackets => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rx_packets, b.rx_packets));
+            }
+            NetInterfaceWidgetColumn::TxPackets => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.tx_packets, b.tx_packets));
+            }
+            NetInterfaceWidgetColumn::RxErrors => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rx_errors, b.rx_errors));
+            }
+            NetInterfaceWidgetColumn::TxErrors => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.tx_errors, b.tx_errors));
+            }
+            NetInterfaceWidgetColumn::RxDropped => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rx_dropped, b.rx_dropped));
+            }
+            NetInterfaceWidgetColumn::TxDropped => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.tx_dropped, b.tx_dropped));
+            }
+        }
+    }
+}
+
+pub struct NetTableWidget {
+    pub table: SortDataTable<NetInterfaceWidgetData, NetInterfaceWidgetColumn>,
+    pub force_update_data: bool,
+}
+
+impl NetTableWidget {
+    pub fn new(config: &AppConfigFields, colours: &CanvasStyling) -> Self {
+        let columns = [
+            SortColumn::soft(NetInterfaceWidgetColumn::Interface, Some(0.2)),
+            SortColumn::hard(NetInterfaceWidgetColumn::Rx, 11).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::Tx, 11).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::TotalRx, 10).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::TotalTx, 10).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::RxPackets, 10).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::TxPackets, 10).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::RxErrors, 8).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::TxErrors, 8).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::RxDropped, 9).default_descending(),
+            SortColumn::hard(NetInterfaceWidgetColumn::TxDropped, 9).default_descending(),
+        ];
+
+        let props = SortDataTableProps {
+            inner: DataTableProps {
+                title: Some(" Interfaces ".into()),
+                table_gap: config.table_gap,
+                left_to_right: true,
+                is_basic: config.use_basic_mode,
+                show_table_scroll_position: config.show_table_scroll_position,
+                show_current_entry_when_unfocused: false,
+            },
+            sort_index: 0,
+            order: SortOrder::Ascending,
+        };
+
+        let styling = DataTableStyling::from_colours(colours);
+
+        Self {
+            table: SortDataTable::new_sortable(columns, props, styling),
+            force_update_data: false,
+        }
+    }
+
+    /// Forces an update of the data stored.
+    #[inline]
+    pub fn force_data_update(&mut self) {
+        self.force_update_data = true;
+    }
+
+    pub fn ingest_data(&mut self, data: &[NetInterfaceWidgetData]) {
+        let mut data = data.to_vec();
+        if let Some(column) = self.table.columns.get(self.table.sort_index()) {
+            column.sort_by(&mut data, self.table.order());
+        }
+        self.table.set_data(data);
+    }
+
+    pub fn set_index(&mut self, index: usize) {
+        self.table.set_sort_index(index);
+        self.force_data_update();
+    }
+
+    /// Returns the name of the currently selected interface, if there is one.
+    pub fn current_interface(&self) -> Option<&str> {
+        self.table
+            .current_item()
+            .map(|interface| interface.name.as_str())
+    }
+}
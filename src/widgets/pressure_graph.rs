@@ -0,0 +1,15 @@
+use std::time::Instant;
+
+pub struct PressureWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl PressureWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        PressureWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     app::{
         data_farmer::{DataCollection, ProcessData},
         query::*,
-        AppConfigFields, AppSearchState,
+        AppConfigFields, SearchState,
     },
     canvas::{
         components::data_table::{
@@ -29,44 +29,38 @@ use crate::{
     Pid,
 };
 
-/// ProcessSearchState only deals with process' search's current settings and state.
-pub struct ProcessSearchState {
-    pub search_state: AppSearchState,
-    pub is_ignoring_case: bool,
-    pub is_searching_whole_word: bool,
-    pub is_searching_with_regex: bool,
-}
-
-impl Default for ProcessSearchState {
-    fn default() -> Self {
-        ProcessSearchState {
-            search_state: AppSearchState::default(),
-            is_ignoring_case: true,
-            is_searching_whole_word: false,
-            is_searching_with_regex: false,
-        }
-    }
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProcWidgetMode {
+    Tree { collapsed_pids: HashSet<Pid> },
+    Grouped(ProcGrouping),
+    Normal,
 }
 
-impl ProcessSearchState {
-    pub fn search_toggle_ignore_case(&mut self) {
-        self.is_ignoring_case = !self.is_ignoring_case;
-    }
-
-    pub fn search_toggle_whole_word(&mut self) {
-        self.is_searching_whole_word = !self.is_searching_whole_word;
-    }
+/// What processes are grouped by in [`ProcWidgetMode::Grouped`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProcGrouping {
+    /// Processes with the same name, or the same command if showing commands.
+    Name,
 
-    pub fn search_toggle_regex(&mut self) {
-        self.is_searching_with_regex = !self.is_searching_with_regex;
-    }
+    /// Processes in the same container, or failing that the same systemd unit or pod, or
+    /// failing that the same cgroup.
+    Cgroup,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum ProcWidgetMode {
-    Tree { collapsed_pids: HashSet<Pid> },
-    Grouped,
-    Normal,
+/// Returns what a process is grouped under when grouping by cgroup. Processes whose cgroup is
+/// unknown are grouped by their name.
+fn cgroup_group_key(process: &ProcessHarvest, is_using_command: bool) -> &str {
+    process
+        .container
+        .as_deref()
+        .map(short_container_id)
+        .or(process.unit.as_deref())
+        .or(process.cgroup.as_deref())
+        .unwrap_or(if is_using_command {
+            &process.command
+        } else {
+            &process.name
+        })
 }
 
 type ProcessTable = SortDataTable<ProcWidgetData, ProcColumn>;
@@ -80,6 +74,18 @@ fn make_column(column: ProcColumn) -> SortColumn<ProcColumn> {
         CpuPercent => SortColumn::new(CpuPercent).default_descending(),
         MemoryVal => SortColumn::new(MemoryVal).default_descending(),
         MemoryPercent => SortColumn::new(MemoryPercent).default_descending(),
+        Rss => SortColumn::new(Rss).default_descending(),
+        Virtual => SortColumn::new(Virtual).default_descending(),
+        Shared => SortColumn::new(Shared).default_descending(),
+        Private => SortColumn::new(Private).default_descending(),
+        Swap => SortColumn::new(Swap).default_descending(),
+        Threads => SortColumn::new(Threads).default_descending(),
+        Nice => SortColumn::new(Nice),
+        Priority => SortColumn::new(Priority),
+        Policy => SortColumn::new(Policy),
+        Processor => SortColumn::new(Processor),
+        MinorFaults => SortColumn::new(MinorFaults).default_descending(),
+        MajorFaults => SortColumn::new(MajorFaults).default_descending(),
         Pid => SortColumn::new(Pid),
         Count => SortColumn::new(Count),
         Name => SortColumn::soft(Name, Some(0.3)),
@@ -91,6 +97,9 @@ fn make_column(column: ProcColumn) -> SortColumn<ProcColumn> {
         User => SortColumn::soft(User, Some(0.05)),
         State => SortColumn::hard(State, 9),
         Time => SortColumn::new(Time),
+        Cgroup => SortColumn::soft(Cgroup, Some(0.2)),
+        Container => SortColumn::hard(Container, 12),
+        Unit => SortColumn::soft(Unit, Some(0.15)),
         #[cfg(feature = "gpu")]
         GpuMem => SortColumn::new(GpuMem).default_descending(),
         #[cfg(feature = "gpu")]
@@ -116,6 +125,18 @@ pub enum ProcWidgetColumn {
     ProcNameOrCommand,
     Cpu,
     Mem,
+    Rss,
+    Virtual,
+    Shared,
+    Private,
+    Swap,
+    Threads,
+    Nice,
+    Priority,
+    Policy,
+    Processor,
+    MinorFaults,
+    MajorFaults,
     ReadPerSecond,
     WritePerSecond,
     TotalRead,
@@ -123,6 +144,9 @@ pub enum ProcWidgetColumn {
     User,
     State,
     Time,
+    Cgroup,
+    Container,
+    Unit,
     #[cfg(feature = "gpu")]
     GpuMem,
     #[cfg(feature = "gpu")]
@@ -139,6 +163,18 @@ impl<'de> Deserialize<'de> for ProcWidgetColumn {
             "cpu%" => Ok(ProcWidgetColumn::Cpu),
             "mem" => Ok(ProcWidgetColumn::Mem),
             "mem%" => Ok(ProcWidgetColumn::Mem),
+            "rss" => Ok(ProcWidgetColumn::Rss),
+            "virt" | "virtual" => Ok(ProcWidgetColumn::Virtual),
+            "shared" | "shr" => Ok(ProcWidgetColumn::Shared),
+            "private" | "priv" => Ok(ProcWidgetColumn::Private),
+            "swap" => Ok(ProcWidgetColumn::Swap),
+            "threads" => Ok(ProcWidgetColumn::Threads),
+            "nice" | "ni" => Ok(ProcWidgetColumn::Nice),
+            "priority" | "prio" | "pri" => Ok(ProcWidgetColumn::Priority),
+            "policy" | "sched" => Ok(ProcWidgetColumn::Policy),
+            "processor" | "psr" => Ok(ProcWidgetColumn::Processor),
+            "minflt" | "minflt/s" => Ok(ProcWidgetColumn::MinorFaults),
+            "majflt" | "majflt/s" => Ok(ProcWidgetColumn::MajorFaults),
             "pid" => Ok(ProcWidgetColumn::PidOrCount),
             "count" => Ok(ProcWidgetColumn::PidOrCount),
             "name" => Ok(ProcWidgetColumn::ProcNameOrCommand),
@@ -150,6 +186,9 @@ impl<'de> Deserialize<'de> for ProcWidgetColumn {
             "state" => Ok(ProcWidgetColumn::State),
             "user" => Ok(ProcWidgetColumn::User),
             "time" => Ok(ProcWidgetColumn::Time),
+            "cgroup" => Ok(ProcWidgetColumn::Cgroup),
+            "container" => Ok(ProcWidgetColumn::Container),
+            "unit" | "pod" => Ok(ProcWidgetColumn::Unit),
             #[cfg(feature = "gpu")]
             "gmem" | "gmem%" => Ok(ProcWidgetColumn::GpuMem),
             #[cfg(feature = "gpu")]
@@ -165,7 +204,7 @@ pub struct ProcWidgetState {
     pub mode: ProcWidgetMode,
 
     /// The state of the search box.
-    pub proc_search: ProcessSearchState,
+    pub proc_search: SearchState,
 
     /// The state of the main table.
     pub table: ProcessTable,
@@ -234,7 +273,7 @@ impl ProcWidgetState {
         colours: &CanvasStyling, config_columns: &Option<IndexSet<ProcWidgetColumn>>,
     ) -> Self {
         let process_search_state = {
-            let mut pss = ProcessSearchState::default();
+            let mut pss = SearchState::default();
 
             if table_config.is_case_sensitive {
                 // By default it's off.
@@ -253,7 +292,7 @@ impl ProcWidgetState {
         let columns: Vec<SortColumn<ProcColumn>> = {
             use ProcColumn::*;
 
-            let is_count = matches!(mode, ProcWidgetMode::Grouped);
+            let is_count = matches!(mode, ProcWidgetMode::Grouped(_));
             let is_command = table_config.is_command;
             let mem_vals = table_config.show_memory_as_values;
 
@@ -284,6 +323,18 @@ impl ProcWidgetState {
                                     MemoryPercent
                                 }
                             }
+                            ProcWidgetColumn::Rss => Rss,
+                            ProcWidgetColumn::Virtual => Virtual,
+                            ProcWidgetColumn::Shared => Shared,
+                            ProcWidgetColumn::Private => Private,
+                            ProcWidgetColumn::Swap => Swap,
+                            ProcWidgetColumn::Threads => Threads,
+                            ProcWidgetColumn::Nice => Nice,
+                            ProcWidgetColumn::Priority => Priority,
+                            ProcWidgetColumn::Policy => Policy,
+                            ProcWidgetColumn::Processor => Processor,
+                            ProcWidgetColumn::MinorFaults => MinorFaults,
+                            ProcWidgetColumn::MajorFaults => MajorFaults,
                             ProcWidgetColumn::ReadPerSecond => ReadPerSecond,
                             ProcWidgetColumn::WritePerSecond => WritePerSecond,
                             ProcWidgetColumn::TotalRead => TotalRead,
@@ -291,6 +342,9 @@ impl ProcWidgetState {
                             ProcWidgetColumn::User => User,
                             ProcWidgetColumn::State => State,
                             ProcWidgetColumn::Time => Time,
+                            ProcWidgetColumn::Cgroup => Cgroup,
+                            ProcWidgetColumn::Container => Container,
+                            ProcWidgetColumn::Unit => Unit,
                             #[cfg(feature = "gpu")]
                             ProcWidgetColumn::GpuMem => {
                                 if mem_vals {
@@ -334,6 +388,18 @@ impl ProcWidgetState {
                 match col.inner() {
                     CpuPercent => ProcWidgetColumn::Cpu,
                     MemoryVal | MemoryPercent => ProcWidgetColumn::Mem,
+                    Rss => ProcWidgetColumn::Rss,
+                    Virtual => ProcWidgetColumn::Virtual,
+                    Shared => ProcWidgetColumn::Shared,
+                    Private => ProcWidgetColumn::Private,
+                    Swap => ProcWidgetColumn::Swap,
+                    Threads => ProcWidgetColumn::Threads,
+                    Nice => ProcWidgetColumn::Nice,
+                    Priority => ProcWidgetColumn::Priority,
+                    Policy => ProcWidgetColumn::Policy,
+                    Processor => ProcWidgetColumn::Processor,
+                    MinorFaults => ProcWidgetColumn::MinorFaults,
+                    MajorFaults => ProcWidgetColumn::MajorFaults,
                     Pid | Count => ProcWidgetColumn::PidOrCount,
                     Name | Command => ProcWidgetColumn::ProcNameOrCommand,
                     ReadPerSecond => ProcWidgetColumn::ReadPerSecond,
@@ -343,6 +409,9 @@ impl ProcWidgetState {
                     State => ProcWidgetColumn::State,
                     User => ProcWidgetColumn::User,
                     Time => ProcWidgetColumn::Time,
+                    Cgroup => ProcWidgetColumn::Cgroup,
+                    Container => ProcWidgetColumn::Container,
+                    Unit => ProcWidgetColumn::Unit,
                     #[cfg(feature = "gpu")]
                     GpuMem | GpuMemPercent => ProcWidgetColumn::GpuMem,
                     #[cfg(feature = "gpu")]
@@ -425,7 +494,7 @@ impl ProcWidgetState {
     /// call it before this function.
     pub fn ingest_data(&mut self, data_collection: &DataCollection) {
         let data = match &self.mode {
-            ProcWidgetMode::Grouped | ProcWidgetMode::Normal => {
+            ProcWidgetMode::Grouped(_) | ProcWidgetMode::Normal => {
                 self.get_normal_data(&data_collection.process_data.process_harvest)
             }
             ProcWidgetMode::Tree { collapsed_pids } => {
@@ -597,12 +666,16 @@ impl ProcWidgetState {
                 let mut summed_process = process.clone();
 
                 if let Some(children_pids) = filtered_tree.get(&process.pid) {
+                    // Threads are skipped, as their usage is already part of their process.
                     let mut sum_queue = children_pids
                         .iter()
                         .filter_map(|child| {
-                            process_harvest.get(child).map(|p| {
-                                ProcWidgetData::from_data(p, is_using_command, is_mem_percent)
-                            })
+                            process_harvest
+                                .get(child)
+                                .filter(|p| !p.is_thread)
+                                .map(|p| {
+                                    ProcWidgetData::from_data(p, is_using_command, is_mem_percent)
+                                })
                         })
                         .collect_vec();
 
@@ -611,9 +684,16 @@ impl ProcWidgetState {
 
                         if let Some(pids) = filtered_tree.get(&process.pid) {
                             sum_queue.extend(pids.iter().filter_map(|child| {
-                                process_harvest.get(child).map(|p| {
-                                    ProcWidgetData::from_data(p, is_using_command, is_mem_percent)
-                                })
+                                process_harvest
+                                    .get(child)
+                                    .filter(|p| !p.is_thread)
+                                    .map(|p| {
+                                        ProcWidgetData::from_data(
+                                            p,
+                                            is_using_command,
+                                            is_mem_percent,
+                                        )
+                                    })
                             }));
                         }
                     }
@@ -700,40 +780,46 @@ impl ProcWidgetState {
         });
 
         let mut id_pid_map: HashMap<String, Vec<Pid>> = HashMap::default();
-        let mut filtered_data: Vec<ProcWidgetData> = if let ProcWidgetMode::Grouped = self.mode {
-            let mut id_process_mapping: HashMap<&String, ProcessHarvest> = HashMap::default();
-            for process in filtered_iter {
-                let id = if is_using_command {
-                    &process.command
-                } else {
-                    &process.name
+        let mut filtered_data: Vec<ProcWidgetData> = if let ProcWidgetMode::Grouped(grouping) =
+            self.mode
+        {
+            let mut id_process_mapping: HashMap<&str, ProcessHarvest> = HashMap::default();
+
+            // Threads aren't grouped, as their usage is already part of their process.
+            for process in filtered_iter.filter(|process| !process.is_thread) {
+                let id = match grouping {
+                    ProcGrouping::Name if is_using_command => &process.command,
+                    ProcGrouping::Name => &process.name,
+                    ProcGrouping::Cgroup => cgroup_group_key(process, is_using_command),
                 };
                 let pid = process.pid;
 
                 if let Some(entry) = id_pid_map.get_mut(id) {
                     entry.push(pid);
                 } else {
-                    id_pid_map.insert(id.clone(), vec![pid]);
+                    id_pid_map.insert(id.to_string(), vec![pid]);
                 }
 
                 if let Some(grouped_process_harvest) = id_process_mapping.get_mut(id) {
                     grouped_process_harvest.add(process);
                 } else {
                     // FIXME: [PERF] could maybe eliminate an allocation here in the grouped mode... or maybe just avoid the entire transformation step, making an alloc fine.
-                    id_process_mapping.insert(id, process.clone());
+                    let mut grouped_process_harvest = process.clone();
+
+                    // Show the group rather than whichever process happened to be first.
+                    if let ProcGrouping::Cgroup = grouping {
+                        grouped_process_harvest.name = id.to_string();
+                        grouped_process_harvest.command = id.to_string();
+                    }
+
+                    id_process_mapping.insert(id, grouped_process_harvest);
                 }
             }
 
             id_process_mapping
-                .values()
-                .map(|process| {
-                    let id = if is_using_command {
-                        &process.command
-                    } else {
-                        &process.name
-                    };
-
-                    let num_similar = id_pid_map.get(id).map(|val| val.len()).unwrap_or(1) as u64;
+                .iter()
+                .map(|(id, process)| {
+                    let num_similar = id_pid_map.get(*id).map(|val| val.len()).unwrap_or(1) as u64;
 
                     ProcWidgetData::from_data(process, is_using_command, is_mem_percent)
                         .num_similar(num_similar)
@@ -872,7 +958,7 @@ impl ProcWidgetState {
                         if let ColumnWidthBounds::Soft { max_percentage, .. } = col.bounds_mut() {
                             *max_percentage = match self.mode {
                                 ProcWidgetMode::Tree { .. } => Some(0.5),
-                                ProcWidgetMode::Grouped | ProcWidgetMode::Normal => Some(0.3),
+                                ProcWidgetMode::Grouped(_) | ProcWidgetMode::Normal => Some(0.3),
                             };
                         }
                     }
@@ -885,6 +971,11 @@ impl ProcWidgetState {
     }
 
     /// Toggles the appropriate columns/settings when tab is pressed.
+    pub fn toggle_tab(&mut self) {
+        self.toggle_grouping(ProcGrouping::Name);
+    }
+
+    /// Toggles grouping processes by `grouping`.
     ///
     /// If count is enabled, we should set the mode to [`ProcWidgetMode::Grouped`], and switch off the User and State
     /// columns. We should also move the user off of the columns if they were selected, as those columns are now hidden
@@ -892,7 +983,17 @@ impl ProcWidgetState {
     ///
     /// Otherwise, if count is disabled, then if the columns exist, the User and State columns should be re-enabled,
     /// and the mode switched to [`ProcWidgetMode::Normal`].
-    pub fn toggle_tab(&mut self) {
+    ///
+    /// If processes are already grouped some other way, this just switches to grouping by `grouping`.
+    pub fn toggle_grouping(&mut self, grouping: ProcGrouping) {
+        if let ProcWidgetMode::Grouped(current) = self.mode {
+            if current != grouping {
+                self.mode = ProcWidgetMode::Grouped(grouping);
+                self.force_rerender_and_update();
+                return;
+            }
+        }
+
         if !matches!(self.mode, ProcWidgetMode::Tree { .. }) {
             if let Some(index) = self
                 .column_mapping
@@ -907,7 +1008,7 @@ impl ProcWidgetState {
 
                             self.hide_column(ProcWidgetColumn::User);
                             self.hide_column(ProcWidgetColumn::State);
-                            self.mode = ProcWidgetMode::Grouped;
+                            self.mode = ProcWidgetMode::Grouped(grouping);
                         }
                         ProcColumn::Count => {
                             *col = ProcColumn::Pid;
@@ -936,18 +1037,10 @@ impl ProcWidgetState {
             .collect::<Vec<_>>()
     }
 
-    pub fn cursor_char_index(&self) -> usize {
-        self.proc_search.search_state.grapheme_cursor.cur_cursor()
-    }
-
     pub fn is_search_enabled(&self) -> bool {
         self.proc_search.search_state.is_enabled
     }
 
-    pub fn current_search_query(&self) -> &str {
-        &self.proc_search.search_state.current_search_query
-    }
-
     pub fn update_query(&mut self) {
         if self
             .proc_search
@@ -992,14 +1085,6 @@ impl ProcWidgetState {
         self.force_data_update();
     }
 
-    pub fn search_walk_forward(&mut self) {
-        self.proc_search.search_state.walk_forward();
-    }
-
-    pub fn search_walk_back(&mut self) {
-        self.proc_search.search_state.walk_backward();
-    }
-
     /// Returns the number of columns *enabled*. Note this differs from *visible* - a column may be enabled but not
     /// visible (e.g. off screen).
     pub fn num_enabled_columns(&self) -> usize {
@@ -1066,14 +1151,30 @@ mod test {
             wps: 0,
             total_read: 0,
             total_write: 0,
+            rss: 0,
+            virt: 0,
+            shared: 0,
+            private: 0,
+            swap: 0,
+            threads: 1,
+            nice: 0,
+            priority: 20,
+            policy: "Other".to_string(),
+            processor: 0,
+            minflt: 0,
+            majflt: 0,
             process_state: "N/A".to_string(),
             process_char: '?',
             #[cfg(target_family = "unix")]
             user: "root".to_string(),
             #[cfg(not(target_family = "unix"))]
             user: "N/A".to_string(),
+            cgroup: String::new(),
+            container: String::new(),
+            unit: String::new(),
             num_similar: 0,
             disabled: false,
+            is_thread: false,
             time: Duration::from_secs(0),
             #[cfg(feature = "gpu")]
             gpu_mem_usage: MemUsage::Percent(1.1),
@@ -1530,4 +1631,166 @@ mod test {
         state.toggle_command();
         assert_eq!(get_columns(&state.table), original_columns);
     }
+
+    fn threads_data() -> DataCollection {
+        let mut data_collection = DataCollection::default();
+        data_collection.eat_data(Box::new(crate::data_collection::Data {
+            list_of_processes: Some(vec![
+                ProcessHarvest {
+                    pid: 10,
+                    name: "app".to_string(),
+                    cpu_usage_percent: 5.0,
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 11,
+                    parent_pid: Some(10),
+                    is_thread: true,
+                    name: "app".to_string(),
+                    cpu_usage_percent: 3.0,
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 12,
+                    parent_pid: Some(10),
+                    name: "child".to_string(),
+                    cpu_usage_percent: 1.0,
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }));
+
+        data_collection
+    }
+
+    #[test]
+    fn threads_nest_under_process() {
+        let mut state = init_default_state(&[
+            ProcWidgetColumn::PidOrCount,
+            ProcWidgetColumn::ProcNameOrCommand,
+            ProcWidgetColumn::Cpu,
+        ]);
+        state.mode = ProcWidgetMode::Tree {
+            collapsed_pids: Default::default(),
+        };
+        state.ingest_data(&threads_data());
+
+        assert_eq!(
+            state.table.data().iter().map(|d| d.pid).collect::<Vec<_>>(),
+            vec![10, 11, 12]
+        );
+
+        // A collapsed process shouldn't count the usage of its threads twice.
+        state.mode = ProcWidgetMode::Tree {
+            collapsed_pids: [10].into_iter().collect(),
+        };
+        state.ingest_data(&threads_data());
+
+        let data = state.table.data();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].cpu_usage_percent, 6.0);
+    }
+
+    #[test]
+    fn threads_are_not_grouped() {
+        let mut state = init_default_state(&[
+            ProcWidgetColumn::PidOrCount,
+            ProcWidgetColumn::ProcNameOrCommand,
+            ProcWidgetColumn::Cpu,
+        ]);
+        state.mode = ProcWidgetMode::Grouped(ProcGrouping::Name);
+        state.ingest_data(&threads_data());
+
+        let app = state
+            .table
+            .data()
+            .iter()
+            .find(|d| d.id.as_str() == "app")
+            .unwrap();
+        assert_eq!(app.num_similar, 1);
+        assert_eq!(app.cpu_usage_percent, 5.0);
+    }
+
+    #[test]
+    fn groups_by_cgroup() {
+        let container = "8c5fbb7e25d4a9f9e0e4d8bb2ad0cde2c53ff4bd6a2c6d4a90b4b1f5e5c1a0b2";
+        let cgroup = format!("/system.slice/docker-{container}.scope");
+
+        let mut data_collection = DataCollection::default();
+        data_collection.eat_data(Box::new(crate::data_collection::Data {
+            list_of_processes: Some(vec![
+                ProcessHarvest {
+                    pid: 10,
+                    name: "nginx".to_string(),
+                    cpu_usage_percent: 2.0,
+                    cgroup: Some(cgroup.clone()),
+                    container: Some(container.to_string()),
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 11,
+                    name: "worker".to_string(),
+                    cpu_usage_percent: 3.0,
+                    cgroup: Some(cgroup),
+                    container: Some(container.to_string()),
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 20,
+                    name: "sshd".to_string(),
+                    cpu_usage_percent: 1.0,
+                    cgroup: Some("/system.slice/sshd.service".to_string()),
+                    unit: Some("sshd.service".to_string()),
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 30,
+                    name: "kthreadd".to_string(),
+                    cgroup: Some("/".to_string()),
+                    ..Default::default()
+                },
+                ProcessHarvest {
+                    pid: 40,
+                    name: "unknown".to_string(),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }));
+
+        let mut state = init_default_state(&[
+            ProcWidgetColumn::PidOrCount,
+            ProcWidgetColumn::ProcNameOrCommand,
+            ProcWidgetColumn::Cpu,
+        ]);
+        state.toggle_grouping(ProcGrouping::Cgroup);
+        assert_eq!(state.mode, ProcWidgetMode::Grouped(ProcGrouping::Cgroup));
+        state.ingest_data(&data_collection);
+
+        let mut groups = state
+            .table
+            .data()
+            .iter()
+            .map(|d| (d.id.to_string(), d.num_similar, d.cpu_usage_percent))
+            .collect::<Vec<_>>();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(
+            groups,
+            vec![
+                ("/".to_string(), 1, 0.0),
+                ("8c5fbb7e25d4".to_string(), 2, 5.0),
+                ("sshd.service".to_string(), 1, 1.0),
+                ("unknown".to_string(), 1, 0.0),
+            ]
+        );
+        assert_eq!(state.id_pid_map["8c5fbb7e25d4"], vec![10, 11]);
+
+        // Tab switches straight to grouping by name, and then back to not grouping at all.
+        state.toggle_tab();
+        assert_eq!(state.mode, ProcWidgetMode::Grouped(ProcGrouping::Name));
+        state.toggle_tab();
+        assert_eq!(state.mode, ProcWidgetMode::Normal);
+    }
 }
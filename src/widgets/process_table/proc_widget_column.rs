@@ -13,6 +13,18 @@ pub enum ProcColumn {
     CpuPercent,
     MemoryVal,
     MemoryPercent,
+    Rss,
+    Virtual,
+    Shared,
+    Private,
+    Swap,
+    Threads,
+    Nice,
+    Priority,
+    Policy,
+    Processor,
+    MinorFaults,
+    MajorFaults,
     Pid,
     Count,
     Name,
@@ -24,6 +36,9 @@ pub enum ProcColumn {
     State,
     User,
     Time,
+    Cgroup,
+    Container,
+    Unit,
     #[cfg(feature = "gpu")]
     GpuMem,
     #[cfg(feature = "gpu")]
@@ -42,6 +57,18 @@ impl<'de> Deserialize<'de> for ProcColumn {
             "cpu%" => Ok(ProcColumn::CpuPercent),
             "mem" => Ok(ProcColumn::MemoryVal),
             "mem%" => Ok(ProcColumn::MemoryPercent),
+            "rss" => Ok(ProcColumn::Rss),
+            "virt" | "virtual" => Ok(ProcColumn::Virtual),
+            "shared" | "shr" => Ok(ProcColumn::Shared),
+            "private" | "priv" => Ok(ProcColumn::Private),
+            "swap" => Ok(ProcColumn::Swap),
+            "threads" => Ok(ProcColumn::Threads),
+            "nice" | "ni" => Ok(ProcColumn::Nice),
+            "priority" | "prio" | "pri" => Ok(ProcColumn::Priority),
+            "policy" | "sched" => Ok(ProcColumn::Policy),
+            "processor" | "psr" => Ok(ProcColumn::Processor),
+            "minflt" | "minflt/s" => Ok(ProcColumn::MinorFaults),
+            "majflt" | "majflt/s" => Ok(ProcColumn::MajorFaults),
             "pid" => Ok(ProcColumn::Pid),
             "count" => Ok(ProcColumn::Count),
             "name" => Ok(ProcColumn::Name),
@@ -53,6 +80,9 @@ impl<'de> Deserialize<'de> for ProcColumn {
             "state" => Ok(ProcColumn::State),
             "user" => Ok(ProcColumn::User),
             "time" => Ok(ProcColumn::Time),
+            "cgroup" => Ok(ProcColumn::Cgroup),
+            "container" => Ok(ProcColumn::Container),
+            "unit" | "pod" => Ok(ProcColumn::Unit),
             #[cfg(feature = "gpu")]
             "gmem" => Ok(ProcColumn::GpuMem),
             #[cfg(feature = "gpu")]
@@ -79,6 +109,18 @@ impl ColumnHeader for ProcColumn {
             ProcColumn::CpuPercent => "CPU%",
             ProcColumn::MemoryVal => "Mem",
             ProcColumn::MemoryPercent => "Mem%",
+            ProcColumn::Rss => "RSS",
+            ProcColumn::Virtual => "Virt",
+            ProcColumn::Shared => "Shared",
+            ProcColumn::Private => "Private",
+            ProcColumn::Swap => "Swap",
+            ProcColumn::Threads => "Threads",
+            ProcColumn::Nice => "Nice",
+            ProcColumn::Priority => "Prio",
+            ProcColumn::Policy => "Policy",
+            ProcColumn::Processor => "PSR",
+            ProcColumn::MinorFaults => "MinFlt/s",
+            ProcColumn::MajorFaults => "MajFlt/s",
             ProcColumn::Pid => "PID",
             ProcColumn::Count => "Count",
             ProcColumn::Name => "Name",
@@ -90,6 +132,9 @@ impl ColumnHeader for ProcColumn {
             ProcColumn::State => "State",
             ProcColumn::User => "User",
             ProcColumn::Time => "Time",
+            ProcColumn::Cgroup => "Cgroup",
+            ProcColumn::Container => "Container",
+            ProcColumn::Unit => "Unit",
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMem => "GMem",
             #[cfg(feature = "gpu")]
@@ -105,6 +150,18 @@ impl ColumnHeader for ProcColumn {
             ProcColumn::CpuPercent => "CPU%(c)",
             ProcColumn::MemoryVal => "Mem(m)",
             ProcColumn::MemoryPercent => "Mem%(m)",
+            ProcColumn::Rss => "RSS",
+            ProcColumn::Virtual => "Virt",
+            ProcColumn::Shared => "Shared",
+            ProcColumn::Private => "Private",
+            ProcColumn::Swap => "Swap",
+            ProcColumn::Threads => "Threads",
+            ProcColumn::Nice => "Nice",
+            ProcColumn::Priority => "Prio",
+            ProcColumn::Policy => "Policy",
+            ProcColumn::Processor => "PSR",
+            ProcColumn::MinorFaults => "MinFlt/s",
+            ProcColumn::MajorFaults => "MajFlt/s",
             ProcColumn::Pid => "PID(p)",
             ProcColumn::Count => "Count",
             ProcColumn::Name => "Name(n)",
@@ -116,6 +173,9 @@ impl ColumnHeader for ProcColumn {
             ProcColumn::State => "State",
             ProcColumn::User => "User",
             ProcColumn::Time => "Time",
+            ProcColumn::Cgroup => "Cgroup",
+            ProcColumn::Container => "Container",
+            ProcColumn::Unit => "Unit",
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMem => "GMem",
             #[cfg(feature = "gpu")]
@@ -140,6 +200,46 @@ impl SortsRow for ProcColumn {
             ProcColumn::MemoryVal | ProcColumn::MemoryPercent => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(&a.mem_usage, &b.mem_usage));
             }
+            ProcColumn::Rss => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rss, b.rss));
+            }
+            ProcColumn::Virtual => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.virt, b.virt));
+            }
+            ProcColumn::Shared => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.shared, b.shared));
+            }
+            ProcColumn::Private => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.private, b.private));
+            }
+            ProcColumn::Swap => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.swap, b.swap));
+            }
+            ProcColumn::Threads => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.threads, b.threads));
+            }
+            ProcColumn::Nice => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.nice, b.nice));
+            }
+            ProcColumn::Priority => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.priority, b.priority));
+            }
+            ProcColumn::Policy => {
+                if descending {
+                    data.sort_by_cached_key(|pd| Reverse(pd.policy.to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|pd| pd.policy.to_lowercase());
+                }
+            }
+            ProcColumn::Processor => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.processor, b.processor));
+            }
+            ProcColumn::MinorFaults => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.minflt, b.minflt));
+            }
+            ProcColumn::MajorFaults => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.majflt, b.majflt));
+            }
             ProcColumn::Pid => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(a.pid, b.pid));
             }
@@ -182,6 +282,27 @@ impl SortsRow for ProcColumn {
             ProcColumn::Time => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(a.time, b.time));
             }
+            ProcColumn::Cgroup => {
+                if descending {
+                    data.sort_by_cached_key(|pd| Reverse(pd.cgroup.to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|pd| pd.cgroup.to_lowercase());
+                }
+            }
+            ProcColumn::Container => {
+                if descending {
+                    data.sort_by_cached_key(|pd| Reverse(pd.container.to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|pd| pd.container.to_lowercase());
+                }
+            }
+            ProcColumn::Unit => {
+                if descending {
+                    data.sort_by_cached_key(|pd| Reverse(pd.unit.to_lowercase()));
+                } else {
+                    data.sort_by_cached_key(|pd| pd.unit.to_lowercase());
+                }
+            }
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMem | ProcColumn::GpuMemPercent => {
                 data.sort_by(|a, b| {
@@ -20,6 +20,14 @@ use crate::{
     Pid,
 };
 
+/// The length container tools such as Docker shorten container IDs to.
+const SHORT_CONTAINER_ID_LEN: usize = 12;
+
+/// Shortens a container ID to the length most container tools display.
+pub(crate) fn short_container_id(id: &str) -> &str {
+    id.get(..SHORT_CONTAINER_ID_LEN).unwrap_or(id)
+}
+
 #[derive(Clone, Debug)]
 enum IdType {
     Name(String),
@@ -174,6 +182,18 @@ pub struct ProcWidgetData {
     pub id: Id,
     pub cpu_usage_percent: f32,
     pub mem_usage: MemUsage,
+    pub rss: u64,
+    pub virt: u64,
+    pub shared: u64,
+    pub private: u64,
+    pub swap: u64,
+    pub threads: u64,
+    pub nice: i32,
+    pub priority: i32,
+    pub policy: String,
+    pub processor: u32,
+    pub minflt: u64,
+    pub majflt: u64,
     pub rps: u64,
     pub wps: u64,
     pub total_read: u64,
@@ -181,8 +201,12 @@ pub struct ProcWidgetData {
     pub process_state: String,
     pub process_char: char,
     pub user: String,
+    pub cgroup: String,
+    pub container: String,
+    pub unit: String,
     pub num_similar: u64,
     pub disabled: bool,
+    pub is_thread: bool,
     pub time: Duration,
     #[cfg(feature = "gpu")]
     pub gpu_mem_usage: MemUsage,
@@ -213,6 +237,18 @@ impl ProcWidgetData {
             id,
             cpu_usage_percent: process.cpu_usage_percent,
             mem_usage,
+            rss: process.mem_usage_bytes,
+            virt: process.virtual_bytes,
+            shared: process.shared_bytes,
+            private: process.private_bytes,
+            swap: process.swap_bytes,
+            threads: process.num_threads,
+            nice: process.nice,
+            priority: process.priority,
+            policy: process.policy.to_string(),
+            processor: process.processor,
+            minflt: process.minor_faults_per_sec,
+            majflt: process.major_faults_per_sec,
             rps: process.read_bytes_per_sec,
             wps: process.write_bytes_per_sec,
             total_read: process.total_read_bytes,
@@ -220,8 +256,12 @@ impl ProcWidgetData {
             process_state: process.process_state.0.clone(),
             process_char: process.process_state.1,
             user: process.user.to_string(),
+            cgroup: process.cgroup.clone().unwrap_or_default(),
+            container: process.container.clone().unwrap_or_default(),
+            unit: process.unit.clone().unwrap_or_default(),
             num_similar: 1,
             disabled: false,
+            is_thread: process.is_thread,
             time: process.time,
             #[cfg(feature = "gpu")]
             gpu_mem_usage: if is_mem_percent {
@@ -259,6 +299,14 @@ impl ProcWidgetData {
                 unreachable!("trying to add together two different memory usage types!")
             }
         };
+        self.rss += other.rss;
+        self.virt += other.virt;
+        self.shared += other.shared;
+        self.private += other.private;
+        self.swap += other.swap;
+        self.threads += other.threads;
+        self.minflt += other.minflt;
+        self.majflt += other.majflt;
         self.rps += other.rps;
         self.wps += other.wps;
         self.total_read += other.total_read;
@@ -278,10 +326,22 @@ impl ProcWidgetData {
         }
     }
 
-    fn to_string(&self, column: &ProcColumn) -> String {
+    pub(crate) fn to_string(&self, column: &ProcColumn) -> String {
         match column {
             ProcColumn::CpuPercent => format!("{:.1}%", self.cpu_usage_percent),
             ProcColumn::MemoryVal | ProcColumn::MemoryPercent => self.mem_usage.to_string(),
+            ProcColumn::Rss => binary_byte_string(self.rss),
+            ProcColumn::Virtual => binary_byte_string(self.virt),
+            ProcColumn::Shared => binary_byte_string(self.shared),
+            ProcColumn::Private => binary_byte_string(self.private),
+            ProcColumn::Swap => binary_byte_string(self.swap),
+            ProcColumn::Threads => self.threads.to_string(),
+            ProcColumn::Nice => self.nice.to_string(),
+            ProcColumn::Priority => self.priority.to_string(),
+            ProcColumn::Policy => self.policy.clone(),
+            ProcColumn::Processor => self.processor.to_string(),
+            ProcColumn::MinorFaults => self.minflt.to_string(),
+            ProcColumn::MajorFaults => self.majflt.to_string(),
             ProcColumn::Pid => self.pid.to_string(),
             ProcColumn::Count => self.num_similar.to_string(),
             ProcColumn::Name | ProcColumn::Command => self.id.to_prefixed_string(),
@@ -292,6 +352,9 @@ impl ProcWidgetData {
             ProcColumn::State => self.process_char.to_string(),
             ProcColumn::User => self.user.clone(),
             ProcColumn::Time => format_time(self.time),
+            ProcColumn::Cgroup => self.cgroup.clone(),
+            ProcColumn::Container => short_container_id(&self.container).to_string(),
+            ProcColumn::Unit => self.unit.clone(),
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMem | ProcColumn::GpuMemPercent => self.gpu_mem_usage.to_string(),
             #[cfg(feature = "gpu")]
@@ -312,6 +375,18 @@ impl DataToCell<ProcColumn> for ProcWidgetData {
                     format!("{:.1}%", self.cpu_usage_percent)
                 }
                 ProcColumn::MemoryVal | ProcColumn::MemoryPercent => self.mem_usage.to_string(),
+                ProcColumn::Rss => binary_byte_string(self.rss),
+                ProcColumn::Virtual => binary_byte_string(self.virt),
+                ProcColumn::Shared => binary_byte_string(self.shared),
+                ProcColumn::Private => binary_byte_string(self.private),
+                ProcColumn::Swap => binary_byte_string(self.swap),
+                ProcColumn::Threads => self.threads.to_string(),
+                ProcColumn::Nice => self.nice.to_string(),
+                ProcColumn::Priority => self.priority.to_string(),
+                ProcColumn::Policy => self.policy.clone(),
+                ProcColumn::Processor => self.processor.to_string(),
+                ProcColumn::MinorFaults => self.minflt.to_string(),
+                ProcColumn::MajorFaults => self.majflt.to_string(),
                 ProcColumn::Pid => self.pid.to_string(),
                 ProcColumn::Count => self.num_similar.to_string(),
                 ProcColumn::Name | ProcColumn::Command => self.id.to_prefixed_string(),
@@ -328,6 +403,9 @@ impl DataToCell<ProcColumn> for ProcWidgetData {
                 }
                 ProcColumn::User => self.user.clone(),
                 ProcColumn::Time => format_time(self.time),
+                ProcColumn::Cgroup => self.cgroup.clone(),
+                ProcColumn::Container => short_container_id(&self.container).to_string(),
+                ProcColumn::Unit => self.unit.clone(),
                 #[cfg(feature = "gpu")]
                 ProcColumn::GpuMem | ProcColumn::GpuMemPercent => self.gpu_mem_usage.to_string(),
                 #[cfg(feature = "gpu")]
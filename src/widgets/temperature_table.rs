@@ -5,7 +5,10 @@ use kstring::KString;
 use tui::text::Text;
 
 use crate::{
-    app::AppConfigFields,
+    app::{
+        query::{ColumnKind, QueryColumn, QueryRow},
+        AppConfigFields, SearchState,
+    },
     canvas::{
         components::data_table::{
             ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
@@ -13,7 +16,7 @@ use crate::{
         },
         styling::CanvasStyling,
     },
-    data_collection::temperature::TemperatureType,
+    data_collection::temperature::{SensorKind, TemperatureType},
     utils::general::{sort_partial_fn, truncate_to_text},
 };
 
@@ -22,11 +25,16 @@ pub struct TempWidgetData {
     pub sensor: KString,
     pub temperature_value: Option<u64>,
     pub temperature_type: TemperatureType,
+    /// The kind and reading of a sensor that is not a temperature sensor, like a fan.
+    pub reading: Option<(SensorKind, f64)>,
 }
 
 pub enum TempWidgetColumn {
     Sensor,
     Temp,
+    /// Used instead of [`TempWidgetColumn::Temp`] when other sensors are shown.
+    Value,
+    Type,
 }
 
 impl ColumnHeader for TempWidgetColumn {
@@ -34,6 +42,8 @@ impl ColumnHeader for TempWidgetColumn {
         match self {
             TempWidgetColumn::Sensor => "Sensor(s)".into(),
             TempWidgetColumn::Temp => "Temp(t)".into(),
+            TempWidgetColumn::Value => "Value(t)".into(),
+            TempWidgetColumn::Type => "Type".into(),
         }
     }
 }
@@ -52,6 +62,69 @@ impl TempWidgetData {
             None => "N/A".to_string().into(),
         }
     }
+
+    /// The value of the sensor along with its unit, whether or not it is a temperature sensor.
+    pub fn value(&self) -> KString {
+        match self.reading {
+            Some((kind, value)) => {
+                let precision = match kind {
+                    SensorKind::Fan | SensorKind::Energy => 0,
+                    SensorKind::Voltage | SensorKind::Power | SensorKind::Current => 2,
+                };
+                format!("{value:.precision$} {}", kind.unit()).into()
+            }
+            None => self.temperature(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self.reading {
+            Some((kind, _)) => kind.name(),
+            None => "Temperature",
+        }
+    }
+
+    /// What [`TempWidgetColumn::Value`] is sorted by, which keeps each kind of sensor together.
+    fn sort_key(&self) -> (Option<SensorKind>, Option<f64>) {
+        match self.reading {
+            Some((kind, value)) => (Some(kind), Some(value)),
+            None => (None, self.temperature_value.map(|temp| temp as f64)),
+        }
+    }
+}
+
+impl QueryRow for TempWidgetData {
+    type Column = TempWidgetColumn;
+
+    const QUERY_COLUMNS: &'static [QueryColumn<TempWidgetColumn>] = &[
+        QueryColumn::new(&["sensor"], TempWidgetColumn::Sensor, ColumnKind::Text),
+        QueryColumn::new(
+            &["temp", "temperature"],
+            TempWidgetColumn::Temp,
+            ColumnKind::Number,
+        ),
+        QueryColumn::new(&["value"], TempWidgetColumn::Value, ColumnKind::Number),
+        QueryColumn::new(&["type"], TempWidgetColumn::Type, ColumnKind::Text),
+    ];
+
+    const NAME_COLUMNS: &'static [TempWidgetColumn] = &[TempWidgetColumn::Sensor];
+
+    fn text(&self, column: &TempWidgetColumn) -> Cow<'_, str> {
+        match column {
+            TempWidgetColumn::Sensor => self.sensor.as_str().into(),
+            TempWidgetColumn::Type => self.kind().into(),
+            TempWidgetColumn::Temp | TempWidgetColumn::Value => "".into(),
+        }
+    }
+
+    fn value(&self, column: &TempWidgetColumn) -> Option<f64> {
+        match column {
+            TempWidgetColumn::Sensor | TempWidgetColumn::Type => None,
+            // Other sensors have no temperature, so they never match a `temp` search.
+            TempWidgetColumn::Temp => self.temperature_value.map(|temp| temp as f64),
+            TempWidgetColumn::Value => self.sort_key().1,
+        }
+    }
 }
 
 impl DataToCell<TempWidgetColumn> for TempWidgetData {
@@ -59,20 +132,29 @@ impl DataToCell<TempWidgetColumn> for TempWidgetData {
         Some(match column {
             TempWidgetColumn::Sensor => truncate_to_text(&self.sensor, calculated_width.get()),
             TempWidgetColumn::Temp => truncate_to_text(&self.temperature(), calculated_width.get()),
+            TempWidgetColumn::Value => truncate_to_text(&self.value(), calculated_width.get()),
+            TempWidgetColumn::Type => truncate_to_text(self.kind(), calculated_width.get()),
         })
     }
 
     fn column_widths<C: DataTableColumn<TempWidgetColumn>>(
-        data: &[TempWidgetData], _columns: &[C],
+        data: &[TempWidgetData], columns: &[C],
     ) -> Vec<u16>
     where
         Self: Sized,
     {
-        let mut widths = vec![0; 2];
+        let mut widths = vec![0; columns.len()];
 
         data.iter().for_each(|row| {
-            widths[0] = max(widths[0], row.sensor.len() as u16);
-            widths[1] = max(widths[1], row.temperature().len() as u16);
+            for (width, column) in widths.iter_mut().zip(columns) {
+                let len = match column.inner() {
+                    TempWidgetColumn::Sensor => row.sensor.len(),
+                    TempWidgetColumn::Temp => row.temperature().len(),
+                    TempWidgetColumn::Value => row.value().len(),
+                    TempWidgetColumn::Type => row.kind().len(),
+                };
+                *width = max(*width, len as u16);
+            }
         });
 
         widths
@@ -92,6 +174,12 @@ impl SortsRow for TempWidgetColumn {
                     sort_partial_fn(descending)(a.temperature_value, b.temperature_value)
                 });
             }
+            TempWidgetColumn::Value => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.sort_key(), b.sort_key()));
+            }
+            TempWidgetColumn::Type => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.kind(), b.kind()));
+            }
         }
     }
 }
@@ -99,18 +187,31 @@ impl SortsRow for TempWidgetColumn {
 pub struct TempWidgetState {
     pub table: SortDataTable<TempWidgetData, TempWidgetColumn>,
     pub force_update_data: bool,
+    pub search: SearchState,
 }
 
 impl TempWidgetState {
     pub fn new(config: &AppConfigFields, colours: &CanvasStyling) -> Self {
-        let columns = [
-            SortColumn::soft(TempWidgetColumn::Sensor, Some(0.8)),
-            SortColumn::soft(TempWidgetColumn::Temp, None).default_descending(),
-        ];
+        let columns = if config.enable_hwmon_sensors {
+            vec![
+                SortColumn::soft(TempWidgetColumn::Sensor, Some(0.6)),
+                SortColumn::soft(TempWidgetColumn::Value, None).default_descending(),
+                SortColumn::soft(TempWidgetColumn::Type, None),
+            ]
+        } else {
+            vec![
+                SortColumn::soft(TempWidgetColumn::Sensor, Some(0.8)),
+                SortColumn::soft(TempWidgetColumn::Temp, None).default_descending(),
+            ]
+        };
 
         let props = SortDataTableProps {
             inner: DataTableProps {
-                title: Some(" Temperatures ".into()),
+                title: Some(if config.enable_hwmon_sensors {
+                    " Sensors ".into()
+                } else {
+                    " Temperatures ".into()
+                }),
                 table_gap: config.table_gap,
                 left_to_right: false,
                 is_basic: config.use_basic_mode,
@@ -126,6 +227,7 @@ impl TempWidgetState {
         Self {
             table: SortDataTable::new_sortable(columns, props, styling),
             force_update_data: false,
+            search: SearchState::default(),
         }
     }
 
@@ -135,11 +237,50 @@ impl TempWidgetState {
         self.force_update_data = true;
     }
 
+    /// Parses the search again after it changes, and filters the table by it.
+    pub fn update_query(&mut self) {
+        self.search.update_table_query::<TempWidgetData>();
+        self.table.to_first();
+        self.force_data_update();
+    }
+
     pub fn ingest_data(&mut self, data: &[TempWidgetData]) {
-        let mut data = data.to_vec();
+        let mut data: Vec<_> = match self.search.query() {
+            Some(query) => data
+                .iter()
+                .filter(|temp| query.check_row(*temp))
+                .cloned()
+                .collect(),
+            None => data.to_vec(),
+        };
         if let Some(column) = self.table.columns.get(self.table.sort_index()) {
             column.sort_by(&mut data, self.table.order());
         }
         self.table.set_data(data);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sensor(
+        name: &str, temperature: Option<u64>, reading: Option<(SensorKind, f64)>,
+    ) -> TempWidgetData {
+        TempWidgetData {
+            sensor: name.to_string().into(),
+            temperature_value: temperature,
+            temperature_type: TemperatureType::Celsius,
+            reading,
+        }
+    }
+
+    #[test]
+    fn temperature_columns_are_numeric() {
+        // The temperature is only searched as a number, so "85" doesn't match its text.
+        let row = sensor("CPU", Some(85), None);
+        assert_eq!(row.text(&TempWidgetColumn::Temp), "");
+        assert_eq!(row.text(&TempWidgetColumn::Value), "");
+        assert_eq!(QueryRow::value(&row, &TempWidgetColumn::Temp), Some(85.0));
+    }
+}